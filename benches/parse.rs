@@ -0,0 +1,69 @@
+use std::hint::black_box;
+
+use criterion::{Criterion, Throughput, criterion_group, criterion_main};
+use parse_frequency::{Frequency, parse_frequency, parse_many};
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parse");
+
+    for (name, input) in [
+        ("integer", "2400 MHz"),
+        ("integer_no_space", "48kHz"),
+        ("decimal", "2.437 GHz"),
+        ("padded", "  100 Hz  "),
+        ("exponent", "1e3 kHz"),
+    ] {
+        group.bench_function(name, |b| {
+            b.iter(|| black_box(input).parse::<Frequency>());
+        });
+    }
+
+    group.finish();
+}
+
+fn batch(c: &mut Criterion) {
+    // A mix of notations like a column of a CSV dump, with a few bad rows
+    let inputs: Vec<String> = (0..100_000_u64)
+        .map(|i| match i % 10 {
+            0 => format!("{}.{:03} GHz", i % 6, i % 1_000),
+            1..=6 => format!("{} MHz", 2_400 + i % 100),
+            7 | 8 => format!("{i} kHz"),
+            _ => format!("{i} furlongs"),
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("batch");
+    group.throughput(Throughput::Elements(inputs.len() as u64));
+
+    group.bench_function("per_string", |b| {
+        b.iter(|| {
+            let mut parsed = Vec::with_capacity(inputs.len());
+            for input in black_box(&inputs) {
+                parsed.push(parse_frequency(input));
+            }
+            parsed
+        });
+    });
+    group.bench_function("parse_many", |b| {
+        b.iter(|| parse_many(black_box(&inputs)).collect::<Vec<_>>());
+    });
+    #[cfg(feature = "rayon")]
+    group.bench_function("par_parse_many", |b| {
+        use rayon::iter::ParallelIterator;
+
+        b.iter(|| parse_frequency::par_parse_many(black_box(&inputs)).collect::<Vec<_>>());
+    });
+
+    group.finish();
+}
+
+fn format(c: &mut Criterion) {
+    let freq = Frequency::from_hz(2_437_000_000);
+    c.bench_function("format/display", |b| b.iter(|| black_box(freq).to_string()));
+    c.bench_function("format/exact", |b| {
+        b.iter(|| black_box(freq).to_exact_string())
+    });
+}
+
+criterion_group!(benches, parse, batch, format);
+criterion_main!(benches);
@@ -0,0 +1,135 @@
+use std::{f64::consts::TAU, fmt::Display, str::FromStr};
+
+use crate::{Error, Frequency, Result, parse_suffixed_f64};
+
+/// Represents an angular frequency (ω) in radians per second
+///
+/// Angular frequency relates to an ordinary [`Frequency`] through `ω = 2πf`. Keeping it as a
+/// separate type makes it impossible to accidentally pass ω where f is expected (or vice versa).
+///
+/// Because `2π` is irrational, the value is stored as an `f64`. Converting back to a
+/// [`Frequency`] rounds to the nearest hertz.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{AngularFrequency, Frequency};
+///
+/// let omega = Frequency::from_hz(50).as_angular();
+/// assert!((omega.as_rad_per_sec() - 314.159_265).abs() < 1e-6);
+/// assert_eq!(omega.to_string(), "314.16 rad/s");
+///
+/// let omega: AngularFrequency = "314.16 rad/s".parse().unwrap();
+/// assert_eq!(omega.to_frequency(), Frequency::from_hz(50));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct AngularFrequency(pub f64);
+
+impl AngularFrequency {
+    /// Equivalent to `0 rad/s`
+    pub const ZERO: Self = Self(0.0);
+
+    #[must_use]
+    #[doc(alias = "from_radians_per_second")]
+    pub fn from_rad_per_sec(rad_per_sec: f64) -> Self {
+        Self(rad_per_sec)
+    }
+
+    #[must_use]
+    #[doc(alias = "as_radians_per_second")]
+    pub fn as_rad_per_sec(&self) -> f64 {
+        self.0
+    }
+
+    /// Converts the angular frequency to an ordinary frequency in hertz (`f = ω / 2π`).
+    ///
+    /// The result is rounded to the nearest hertz. Negative or non-finite values result in
+    /// [`Frequency::ZERO`].
+    ///
+    /// ```rust
+    /// use parse_frequency::{AngularFrequency, Frequency};
+    ///
+    /// let omega = AngularFrequency::from_rad_per_sec(std::f64::consts::TAU * 1000.0);
+    /// assert_eq!(omega.to_frequency(), Frequency::from_khz(1));
+    /// ```
+    #[must_use]
+    pub fn to_frequency(&self) -> Frequency {
+        if !self.0.is_finite() || self.0 <= 0.0 {
+            return Frequency::ZERO;
+        }
+
+        // It is OK to lose sign and precision here
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let hz = (self.0 / TAU).round() as u64;
+        Frequency(hz)
+    }
+}
+
+impl Frequency {
+    /// Converts the frequency to an angular frequency in radians per second (`ω = 2πf`).
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let omega = Frequency::from_hz(1).as_angular();
+    /// assert_eq!(omega.as_rad_per_sec(), std::f64::consts::TAU);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_angular(&self) -> AngularFrequency {
+        AngularFrequency(self.0 as f64 * TAU)
+    }
+
+    /// Shorthand for `self.as_angular().as_rad_per_sec()`.
+    #[must_use]
+    #[doc(alias = "as_radians_per_second")]
+    pub fn as_rad_per_sec(&self) -> f64 {
+        self.as_angular().as_rad_per_sec()
+    }
+
+    /// Creates a frequency from an angular frequency in radians per second, rounding to the
+    /// nearest hertz.
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let freq = Frequency::from_rad_per_sec(std::f64::consts::TAU * 60.0);
+    /// assert_eq!(freq, Frequency::from_hz(60));
+    /// ```
+    #[must_use]
+    #[doc(alias = "from_radians_per_second")]
+    pub fn from_rad_per_sec(rad_per_sec: f64) -> Self {
+        AngularFrequency(rad_per_sec).to_frequency()
+    }
+}
+
+impl From<Frequency> for AngularFrequency {
+    fn from(freq: Frequency) -> Self {
+        freq.as_angular()
+    }
+}
+
+impl From<AngularFrequency> for Frequency {
+    fn from(omega: AngularFrequency) -> Self {
+        omega.to_frequency()
+    }
+}
+
+impl Display for AngularFrequency {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} rad/s", self.0)
+    }
+}
+
+impl FromStr for AngularFrequency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_suffixed_f64(s, &["rad/s"], |value| {
+            // Negative values are not allowed
+            (!value.is_sign_negative()).then_some(Self(value))
+        })
+    }
+}
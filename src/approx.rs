@@ -0,0 +1,56 @@
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{Frequency, FrequencyF64};
+
+impl AbsDiffEq for Frequency {
+    type Epsilon = Frequency;
+
+    /// `0 Hz`, so frequencies only compare equal when they are exactly equal by default
+    fn default_epsilon() -> Self::Epsilon {
+        Self(0)
+    }
+
+    /// Returns whether the frequencies are at most `epsilon` apart.
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff(other.0) <= epsilon.0
+    }
+}
+
+impl AbsDiffEq for FrequencyF64 {
+    /// The absolute difference in hertz, or for [`RelativeEq`] the relative difference as a
+    /// fraction, which [`Ppm::as_fraction`](crate::Ppm::as_fraction) converts from ppm
+    type Epsilon = f64;
+
+    fn default_epsilon() -> Self::Epsilon {
+        f64::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        self.0.abs_diff_eq(&other.0, epsilon)
+    }
+}
+
+impl RelativeEq for FrequencyF64 {
+    fn default_max_relative() -> Self::Epsilon {
+        f64::default_max_relative()
+    }
+
+    fn relative_eq(
+        &self,
+        other: &Self,
+        epsilon: Self::Epsilon,
+        max_relative: Self::Epsilon,
+    ) -> bool {
+        self.0.relative_eq(&other.0, epsilon, max_relative)
+    }
+}
+
+impl UlpsEq for FrequencyF64 {
+    fn default_max_ulps() -> u32 {
+        f64::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        self.0.ulps_eq(&other.0, epsilon, max_ulps)
+    }
+}
@@ -0,0 +1,214 @@
+use std::fmt::Display;
+
+use crate::{Frequency, FrequencyRange};
+
+/// Preferred (nominal) center frequencies in hertz of the ten third-octave bands in the decade
+/// starting at 1 kHz, per ISO 266
+const NOMINAL_DECADE: [f64; 10] = [
+    1000.0, 1250.0, 1600.0, 2000.0, 2500.0, 3150.0, 4000.0, 5000.0, 6300.0, 8000.0,
+];
+
+/// Center frequency of the reference band (index `0`) in hertz
+const REFERENCE_HZ: f64 = 1_000.0;
+
+/// The width of a band in a standard acoustic band series
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum BandFraction {
+    /// Full octave bands (e.g. 500 Hz, 1 kHz, 2 kHz)
+    Octave,
+
+    /// One-third octave bands (e.g. 800 Hz, 1 kHz, 1.25 kHz)
+    ThirdOctave,
+}
+
+impl BandFraction {
+    /// Returns the number of bands per decade (base-10 band series as used by ISO 266 and
+    /// IEC 61260).
+    fn bands_per_decade(self) -> f64 {
+        match self {
+            BandFraction::Octave => 10.0 / 3.0,
+            BandFraction::ThirdOctave => 10.0,
+        }
+    }
+
+    /// Returns the band `freq` falls into, or `None` for `0 Hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{BandFraction, Frequency};
+    ///
+    /// let band = BandFraction::ThirdOctave.band_for(Frequency::from_hz(1100)).unwrap();
+    /// assert_eq!(band.nominal_hz(), 1000.0);
+    ///
+    /// let band = BandFraction::Octave.band_for(Frequency::from_hz(1500)).unwrap();
+    /// assert_eq!(band.nominal_hz(), 2000.0);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here and band indices stay far within i32
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    pub fn band_for(self, freq: Frequency) -> Option<OctaveBand> {
+        if freq == Frequency::ZERO {
+            return None;
+        }
+
+        // Band edges are the geometric means of adjacent centers, so rounding in the
+        // logarithmic domain selects the band containing the frequency
+        let index = (self.bands_per_decade() * (freq.0 as f64 / REFERENCE_HZ).log10()).round();
+
+        Some(OctaveBand::new(self, index as i32))
+    }
+
+    /// Returns an iterator over the bands whose center frequency lies within `range`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{BandFraction, Frequency, FrequencyRange};
+    ///
+    /// let audible = FrequencyRange::new(Frequency::from_hz(20), Frequency::from_khz(20));
+    /// let nominal: Vec<f64> = BandFraction::Octave
+    ///     .bands_in(audible)
+    ///     .map(|band| band.nominal_hz())
+    ///     .collect();
+    ///
+    /// assert_eq!(
+    ///     nominal,
+    ///     [31.5, 63.0, 125.0, 250.0, 500.0, 1000.0, 2000.0, 4000.0, 8000.0, 16000.0]
+    /// );
+    /// ```
+    pub fn bands_in(self, range: FrequencyRange) -> impl Iterator<Item = OctaveBand> {
+        let first = self.band_for(range.min.max(Frequency::HERTZ));
+        let last = self.band_for(range.max);
+
+        // Precision loss is acceptable here
+        #[allow(clippy::cast_precision_loss)]
+        let bounds = range.min.0 as f64..=range.max.0 as f64;
+
+        first
+            .zip(last)
+            .into_iter()
+            .flat_map(|(first, last)| first.index..=last.index)
+            .map(move |index| OctaveBand::new(self, index))
+            .filter(move |band| bounds.contains(&band.center_hz()))
+    }
+}
+
+/// A band in the standard (ISO 266 / IEC 61260) octave or third-octave series
+///
+/// Bands are identified by their index relative to the 1 kHz reference band, so index `1` is
+/// the next band up (2 kHz for octaves, 1.25 kHz for third-octaves). Exact center frequencies
+/// use the base-10 series, while the nominal frequencies are the rounded preferred values
+/// used for labeling.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{BandFraction, Frequency, OctaveBand};
+///
+/// let band = OctaveBand::new(BandFraction::ThirdOctave, 5);
+/// assert_eq!(band.nominal_hz(), 3150.0);
+/// assert!((band.center_hz() - 3162.28).abs() < 0.01);
+/// assert!(band.contains(Frequency::from_khz(3)));
+/// assert_eq!(band.to_string(), "3150 Hz (1/3 octave)");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OctaveBand {
+    /// The band series this band belongs to
+    pub fraction: BandFraction,
+
+    /// Index of the band relative to the 1 kHz reference band
+    pub index: i32,
+}
+
+impl OctaveBand {
+    #[must_use]
+    pub fn new(fraction: BandFraction, index: i32) -> Self {
+        Self { fraction, index }
+    }
+
+    /// Returns the exact center frequency of the band in hertz.
+    #[must_use]
+    pub fn center_hz(&self) -> f64 {
+        REFERENCE_HZ * 10f64.powf(f64::from(self.index) / self.fraction.bands_per_decade())
+    }
+
+    /// Returns the nominal (preferred) center frequency of the band in hertz, as printed on
+    /// sound level meters and datasheets.
+    #[must_use]
+    pub fn nominal_hz(&self) -> f64 {
+        let third_index = match self.fraction {
+            BandFraction::Octave => self.index * 3,
+            BandFraction::ThirdOctave => self.index,
+        };
+
+        // rem_euclid always yields 0..10, so the index is in bounds
+        #[allow(clippy::cast_sign_loss)]
+        let nominal = NOMINAL_DECADE[third_index.rem_euclid(10) as usize];
+        let decade = third_index.div_euclid(10);
+
+        // Dividing by an exact power of ten keeps values like 31.5 exact
+        if decade < 0 {
+            nominal / 10f64.powi(-decade)
+        } else {
+            nominal * 10f64.powi(decade)
+        }
+    }
+
+    /// Returns the lower edge of the band in hertz.
+    #[must_use]
+    pub fn lower_edge_hz(&self) -> f64 {
+        self.center_hz() / self.half_band_ratio()
+    }
+
+    /// Returns the upper edge of the band in hertz.
+    #[must_use]
+    pub fn upper_edge_hz(&self) -> f64 {
+        self.center_hz() * self.half_band_ratio()
+    }
+
+    /// Returns the exact center frequency, rounded to the nearest hertz.
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn center(&self) -> Frequency {
+        Frequency(self.center_hz().round() as u64)
+    }
+
+    /// Returns `true` if `freq` lies within the edges of the band.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn contains(&self, freq: Frequency) -> bool {
+        let hz = freq.0 as f64;
+        self.lower_edge_hz() <= hz && hz < self.upper_edge_hz()
+    }
+
+    /// Returns the band directly below this one.
+    #[must_use]
+    pub fn previous(&self) -> Self {
+        Self::new(self.fraction, self.index - 1)
+    }
+
+    /// Returns the band directly above this one.
+    #[must_use]
+    pub fn next(&self) -> Self {
+        Self::new(self.fraction, self.index + 1)
+    }
+
+    /// Returns the ratio between the center frequency and either edge.
+    fn half_band_ratio(&self) -> f64 {
+        10f64.powf(0.5 / self.fraction.bands_per_decade())
+    }
+}
+
+impl Display for OctaveBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let fraction = match self.fraction {
+            BandFraction::Octave => "octave",
+            BandFraction::ThirdOctave => "1/3 octave",
+        };
+
+        write!(f, "{} Hz ({fraction})", self.nominal_hz())
+    }
+}
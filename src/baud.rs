@@ -0,0 +1,122 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{Error, Frequency, Result, Rounding, math::error_ppm, parse_suffixed_f64};
+
+/// Represents a serial line rate in baud (symbols per second)
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{BaudRate, Frequency};
+///
+/// let baud: BaudRate = "115200 baud".parse().unwrap();
+/// assert_eq!(baud, BaudRate::B115200);
+///
+/// // 16x oversampling from a 16 MHz peripheral clock
+/// let setting = baud.uart_divisor(Frequency::from_mhz(16), 16).unwrap();
+/// assert_eq!(setting.divisor, 9);
+/// assert_eq!(setting.actual, BaudRate(111_111));
+/// assert!((setting.error_ppm - -35_493.8).abs() < 0.1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct BaudRate(pub u64);
+
+/// The result of [`BaudRate::uart_divisor`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UartDivisor {
+    /// The value to program into the baud rate divisor register
+    pub divisor: u32,
+
+    /// The baud rate actually achieved with this divisor, rounded to the nearest baud
+    pub actual: BaudRate,
+
+    /// The relative error of the actual baud rate in parts per million (positive if too fast)
+    pub error_ppm: f64,
+}
+
+impl BaudRate {
+    pub const B9600: Self = Self(9_600);
+    pub const B19200: Self = Self(19_200);
+    pub const B38400: Self = Self(38_400);
+    pub const B57600: Self = Self(57_600);
+    pub const B115200: Self = Self(115_200);
+    pub const B230400: Self = Self(230_400);
+    pub const B460800: Self = Self(460_800);
+    pub const B921600: Self = Self(921_600);
+
+    #[must_use]
+    pub fn as_baud(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the symbol rate as a frequency.
+    #[must_use]
+    pub fn as_frequency(&self) -> Frequency {
+        Frequency(self.0)
+    }
+
+    /// Calculates the integer divisor that gets closest to this baud rate for a UART clocked at
+    /// `peripheral_clock` and sampling each bit `oversampling` times
+    /// (`baud = peripheral_clock / (oversampling * divisor)`).
+    ///
+    /// All calculations are done with exact integer arithmetic. Returns `None` if the baud rate,
+    /// clock or oversampling factor is zero, or if no divisor in `1..=u32::MAX` can be used.
+    #[must_use]
+    // The divisor is checked to fit in a u32 and the actual rate never exceeds the clock
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn uart_divisor(
+        &self,
+        peripheral_clock: Frequency,
+        oversampling: u32,
+    ) -> Option<UartDivisor> {
+        let clock = u128::from(peripheral_clock.as_hz());
+        let samples_per_second = u128::from(self.0) * u128::from(oversampling);
+
+        if clock == 0 || samples_per_second == 0 {
+            return None;
+        }
+
+        let divisor = Rounding::Nearest.div(clock, samples_per_second)?.max(1);
+        let divisor = u32::try_from(divisor).ok()?;
+
+        let denominator = u128::from(oversampling) * u128::from(divisor);
+        let actual = Rounding::Nearest.div(clock, denominator)?;
+
+        Some(UartDivisor {
+            divisor,
+            actual: BaudRate(actual as u64),
+            error_ppm: error_ppm(clock, denominator, u128::from(self.0)),
+        })
+    }
+}
+
+impl From<BaudRate> for Frequency {
+    fn from(baud: BaudRate) -> Self {
+        baud.as_frequency()
+    }
+}
+
+impl Display for BaudRate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} baud", self.0)
+    }
+}
+
+impl FromStr for BaudRate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_suffixed_f64(s, &["baud", "bd", "bps"], |value| {
+            // Only whole symbol rates are allowed, checked by converting back without loss
+            #[allow(
+                clippy::cast_possible_truncation,
+                clippy::cast_sign_loss,
+                clippy::cast_precision_loss
+            )]
+            let baud = value as u64;
+            (!value.is_sign_negative() && value < u64::MAX as f64 && baud as f64 == value)
+                .then_some(Self(baud))
+        })
+    }
+}
@@ -0,0 +1,30 @@
+use bincode::{
+    BorrowDecode, Decode, Encode,
+    de::{BorrowDecoder, Decoder},
+    enc::Encoder,
+    error::{DecodeError, EncodeError},
+};
+
+use crate::Frequency;
+
+impl Encode for Frequency {
+    /// Encodes the number of hertz as 8 little-endian bytes, whatever the integer encoding of
+    /// the configuration.
+    fn encode<E: Encoder>(&self, encoder: &mut E) -> Result<(), EncodeError> {
+        self.to_le_bytes().encode(encoder)
+    }
+}
+
+impl<Context> Decode<Context> for Frequency {
+    fn decode<D: Decoder<Context = Context>>(decoder: &mut D) -> Result<Self, DecodeError> {
+        <[u8; 8]>::decode(decoder).map(Self::from_le_bytes)
+    }
+}
+
+impl<'de, Context> BorrowDecode<'de, Context> for Frequency {
+    fn borrow_decode<D: BorrowDecoder<'de, Context = Context>>(
+        decoder: &mut D,
+    ) -> Result<Self, DecodeError> {
+        Self::decode(decoder)
+    }
+}
@@ -0,0 +1,32 @@
+use borsh::{
+    BorshDeserialize, BorshSerialize,
+    io::{Read, Result, Write},
+};
+
+use crate::{Frequency, FrequencyOf};
+
+impl BorshSerialize for Frequency {
+    /// Serializes the number of hertz as a little-endian `u64`.
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Frequency {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        u64::deserialize_reader(reader).map(Self)
+    }
+}
+
+impl<T: BorshSerialize> BorshSerialize for FrequencyOf<T> {
+    /// Serializes the number of hertz like the underlying integer.
+    fn serialize<W: Write>(&self, writer: &mut W) -> Result<()> {
+        self.0.serialize(writer)
+    }
+}
+
+impl<T: BorshDeserialize> BorshDeserialize for FrequencyOf<T> {
+    fn deserialize_reader<R: Read>(reader: &mut R) -> Result<Self> {
+        T::deserialize_reader(reader).map(Self)
+    }
+}
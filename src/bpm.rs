@@ -0,0 +1,211 @@
+use std::{
+    fmt::Display,
+    str::FromStr,
+    time::{Duration, Instant},
+};
+
+use crate::{Error, Frequency, Result, Rounding, parse_suffixed_f64, round_to_u64};
+
+/// Thousandths of a beat per minute in one beat per second (1 Hz)
+const MILLIBPM_PER_HZ: u64 = 60_000;
+
+/// Thousandths of a beat per minute in a beat that lasts one nanosecond
+const MILLIBPM_NANOS: u128 = 60_000_000_000_000;
+
+/// Represents a musical tempo in beats per minute
+///
+/// The tempo is stored as an integer number of thousandths of a beat per minute, so fractional
+/// tempos such as `128.5 BPM` are represented exactly and survive parsing and formatting
+/// unchanged.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{Bpm, Frequency};
+///
+/// let tempo: Bpm = "128.5 BPM".parse().unwrap();
+/// assert_eq!(tempo.as_millibpm(), 128_500);
+/// assert_eq!(tempo.to_string(), "128.5 BPM");
+///
+/// assert_eq!(Bpm::from_bpm(120.0).beat_duration(), Duration::from_millis(500));
+/// assert_eq!(Bpm::from(Frequency::from_hz(2)), Bpm::from_bpm(120.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct Bpm(u64);
+
+impl Bpm {
+    /// Equivalent to `0 BPM`
+    pub const ZERO: Self = Self(0);
+
+    /// Creates a tempo from thousandths of a beat per minute.
+    #[must_use]
+    pub fn from_millibpm(millibpm: u64) -> Self {
+        Self(millibpm)
+    }
+
+    /// Creates a tempo from beats per minute, rounded to the nearest thousandth.
+    ///
+    /// Negative or non-finite values result in [`Bpm::ZERO`], and tempos above `u64::MAX`
+    /// thousandths of a beat per minute saturate. See [`Bpm::checked_from_bpm`] for a checked
+    /// alternative.
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn from_bpm(bpm: f64) -> Self {
+        if !bpm.is_finite() {
+            return Self::ZERO;
+        }
+
+        Self((bpm * 1_000.0).round().max(0.0) as u64)
+    }
+
+    /// Creates a tempo from beats per minute, rounded to the nearest thousandth, or returns
+    /// `None` if it is negative, not finite or does not fit in a `u64` of thousandths of a beat
+    /// per minute.
+    ///
+    /// ```rust
+    /// use parse_frequency::Bpm;
+    ///
+    /// assert_eq!(Bpm::checked_from_bpm(128.5), Some(Bpm::from_millibpm(128_500)));
+    /// assert_eq!(Bpm::checked_from_bpm(1e30), None);
+    /// assert_eq!(Bpm::checked_from_bpm(-1.0), None);
+    /// ```
+    #[must_use]
+    pub fn checked_from_bpm(bpm: f64) -> Option<Self> {
+        round_to_u64(bpm * 1_000.0).map(Self)
+    }
+
+    /// Creates a tempo from the interval between two beats, rounded to the nearest thousandth
+    /// of a beat per minute. Returns `None` for a zero interval.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::Bpm;
+    ///
+    /// let tempo = Bpm::from_beat_interval(Duration::from_millis(400)).unwrap();
+    /// assert_eq!(tempo, Bpm::from_bpm(150.0));
+    /// ```
+    #[must_use]
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_beat_interval(interval: Duration) -> Option<Self> {
+        let millibpm = Rounding::Nearest.div(MILLIBPM_NANOS, interval.as_nanos())?;
+        Some(Self(millibpm.min(u128::from(u64::MAX)) as u64))
+    }
+
+    /// Estimates the tempo from a sequence of tap timestamps, using the average interval between
+    /// the first and the last tap. Returns `None` if fewer than two taps are given or if all taps
+    /// happened at the same instant.
+    ///
+    /// ```rust
+    /// use std::time::{Duration, Instant};
+    /// use parse_frequency::Bpm;
+    ///
+    /// let start = Instant::now();
+    /// let taps = (0..4).map(|beat| start + Duration::from_millis(500) * beat);
+    /// assert_eq!(Bpm::from_taps(taps), Some(Bpm::from_bpm(120.0)));
+    /// ```
+    #[must_use]
+    pub fn from_taps<I: IntoIterator<Item = Instant>>(taps: I) -> Option<Self> {
+        let mut taps = taps.into_iter();
+        let first = taps.next()?;
+        let (count, last) = taps.fold((0u32, first), |(count, _), tap| (count + 1, tap));
+
+        if count == 0 {
+            return None;
+        }
+
+        Self::from_beat_interval(last.saturating_duration_since(first) / count)
+    }
+
+    /// Returns the tempo in thousandths of a beat per minute.
+    #[must_use]
+    pub fn as_millibpm(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the tempo in beats per minute.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_bpm(&self) -> f64 {
+        self.0 as f64 / 1_000.0
+    }
+
+    /// Returns the duration of a single beat, truncated to whole nanoseconds.
+    ///
+    /// A tempo of `0 BPM` results in [`Duration::ZERO`].
+    #[must_use]
+    // The quotient never exceeds MILLIBPM_NANOS, which fits in a u64
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn beat_duration(&self) -> Duration {
+        MILLIBPM_NANOS
+            .checked_div(u128::from(self.0))
+            .map_or(Duration::ZERO, |nanos| Duration::from_nanos(nanos as u64))
+    }
+
+    /// Converts the tempo to a frequency (beats per second), rounding as requested.
+    ///
+    /// ```rust
+    /// use parse_frequency::{Bpm, Frequency, Rounding};
+    ///
+    /// let tempo = Bpm::from_bpm(150.0);
+    /// assert_eq!(tempo.to_frequency(Rounding::Down), Frequency::from_hz(2));
+    /// assert_eq!(tempo.to_frequency(Rounding::Nearest), Frequency::from_hz(3));
+    /// ```
+    #[must_use]
+    // The quotient is at most u64::MAX / 60 000, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_frequency(&self, rounding: Rounding) -> Frequency {
+        let hz = rounding.div(u128::from(self.0), u128::from(MILLIBPM_PER_HZ));
+        Frequency(hz.unwrap_or_default() as u64)
+    }
+
+    /// Converts a frequency to a tempo, or returns `None` if it does not fit in a `u64` of
+    /// thousandths of a beat per minute.
+    ///
+    /// ```rust
+    /// use parse_frequency::{Bpm, Frequency};
+    ///
+    /// assert_eq!(Bpm::checked_from_frequency(Frequency::from_hz(2)), Some(Bpm::from_bpm(120.0)));
+    /// assert_eq!(Bpm::checked_from_frequency(Frequency(u64::MAX)), None);
+    /// ```
+    #[must_use]
+    pub fn checked_from_frequency(freq: Frequency) -> Option<Self> {
+        freq.0.checked_mul(MILLIBPM_PER_HZ).map(Self)
+    }
+}
+
+impl From<Frequency> for Bpm {
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Bpm::checked_from_frequency`] for a checked
+    /// alternative.
+    fn from(freq: Frequency) -> Self {
+        Self(freq.0 * MILLIBPM_PER_HZ)
+    }
+}
+
+impl Display for Bpm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (whole, fraction) = (self.0 / 1_000, self.0 % 1_000);
+
+        if fraction == 0 {
+            write!(f, "{whole} BPM")
+        } else {
+            let fraction = format!("{fraction:03}");
+            write!(f, "{whole}.{} BPM", fraction.trim_end_matches('0'))
+        }
+    }
+}
+
+impl FromStr for Bpm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_suffixed_f64(s, &["bpm"], Self::checked_from_bpm)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::{ChannelGrid, Frequency, ItuRegion, KILOHERTZ};
+
+/// Returns the FM broadcast raster of `region`: 88.1 to 107.9 MHz on odd 200 kHz steps in the
+/// Americas, 87.5 to 108 MHz on 100 kHz steps elsewhere.
+fn fm_grid(region: ItuRegion) -> ChannelGrid {
+    match region {
+        ItuRegion::Region2 => {
+            ChannelGrid::new(Frequency::from_khz(88_100), Frequency(200 * KILOHERTZ), 100)
+        }
+        ItuRegion::Region1 | ItuRegion::Region3 => {
+            ChannelGrid::new(Frequency::from_khz(87_500), Frequency(100 * KILOHERTZ), 206)
+        }
+    }
+}
+
+/// Returns the AM (medium wave) broadcast raster of `region`: 530 to 1700 kHz on 10 kHz steps
+/// in the Americas, 531 to 1602 kHz on 9 kHz steps elsewhere.
+fn am_grid(region: ItuRegion) -> ChannelGrid {
+    match region {
+        ItuRegion::Region2 => {
+            ChannelGrid::new(Frequency::from_khz(530), Frequency(10 * KILOHERTZ), 118)
+        }
+        ItuRegion::Region1 | ItuRegion::Region3 => {
+            ChannelGrid::new(Frequency::from_khz(531), Frequency(9 * KILOHERTZ), 120)
+        }
+    }
+}
+
+impl Frequency {
+    /// Returns `true` if this frequency is an FM broadcast channel in `region`.
+    ///
+    /// The Americas (region 2) use 88.1 to 107.9 MHz on a 200 kHz raster, all other regions use
+    /// 87.5 to 108 MHz on a 100 kHz raster.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, ItuRegion};
+    ///
+    /// let freq = Frequency::from_khz(98_100);
+    /// assert!(freq.is_valid_fm_channel(ItuRegion::Region2));
+    ///
+    /// let freq = Frequency::from_khz(98_200);
+    /// assert!(!freq.is_valid_fm_channel(ItuRegion::Region2));
+    /// assert!(freq.is_valid_fm_channel(ItuRegion::Region1));
+    /// ```
+    #[must_use]
+    pub fn is_valid_fm_channel(&self, region: ItuRegion) -> bool {
+        fm_grid(region).contains(*self)
+    }
+
+    /// Snaps this frequency to the closest FM broadcast channel in `region`. Frequencies
+    /// outside the band snap to the nearest band edge.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, ItuRegion};
+    ///
+    /// let tuned = Frequency::from_khz(98_230).nearest_fm_channel(ItuRegion::Region2);
+    /// assert_eq!(tuned, Frequency::from_khz(98_300));
+    /// ```
+    #[must_use]
+    pub fn nearest_fm_channel(&self, region: ItuRegion) -> Frequency {
+        fm_grid(region).snap(*self).unwrap_or(*self)
+    }
+
+    /// Returns `true` if this frequency is an AM (medium wave) broadcast channel in `region`.
+    ///
+    /// The Americas (region 2) use 530 to 1700 kHz on a 10 kHz raster, all other regions use
+    /// 531 to 1602 kHz on a 9 kHz raster.
+    #[must_use]
+    pub fn is_valid_am_channel(&self, region: ItuRegion) -> bool {
+        am_grid(region).contains(*self)
+    }
+
+    /// Snaps this frequency to the closest AM (medium wave) broadcast channel in `region`.
+    /// Frequencies outside the band snap to the nearest band edge.
+    #[must_use]
+    pub fn nearest_am_channel(&self, region: ItuRegion) -> Frequency {
+        am_grid(region).snap(*self).unwrap_or(*self)
+    }
+}
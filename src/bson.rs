@@ -0,0 +1,98 @@
+//! BSON support for [`Frequency`]
+//!
+//! With the `bson` feature, a frequency converts to and from [`Bson`] values: an `Int64` number
+//! of hertz when it fits, and an exact frequency string such as `"18446744073.709551615 GHz"`
+//! otherwise. Reading also accepts `Int32`, `Double` and frequency strings, so documents written
+//! by hand or by other tools load as well.
+//!
+//! The [`serialize`] and [`deserialize`] functions in here use that representation for a single
+//! field with `#[serde(with = "parse_frequency::bson")]`. Without them, the default serde
+//! implementation writes a string, since BSON is a human-readable format.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use bson::doc;
+//! use parse_frequency::Frequency;
+//! use serde::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize, PartialEq, Debug)]
+//! struct Station {
+//!     name: String,
+//!     #[serde(with = "parse_frequency::bson")]
+//!     tune: Frequency,
+//! }
+//!
+//! let station = Station { name: "KEXP".to_string(), tune: Frequency::from_khz(90_300) };
+//! let document = bson::to_document(&station).unwrap();
+//! assert_eq!(document, doc! { "name": "KEXP", "tune": 90_300_000_i64 });
+//!
+//! let by_hand = doc! { "name": "KEXP", "tune": "90.3 MHz" };
+//! assert_eq!(bson::from_document::<Station>(by_hand).unwrap(), station);
+//! ```
+
+use ::bson::Bson;
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+
+use crate::{Error, Frequency, FrequencyF64, Rounding};
+
+impl From<Frequency> for Bson {
+    /// Converts to an `Int64` number of hertz, or to an exact frequency string above
+    /// `i64::MAX` Hz.
+    fn from(freq: Frequency) -> Self {
+        i64::try_from(freq.0).map_or_else(|_| Self::String(freq.to_exact_string()), Self::Int64)
+    }
+}
+
+impl TryFrom<&Bson> for Frequency {
+    type Error = Error;
+
+    /// Reads a non-negative `Int32`, `Int64` or `Double` number of hertz, rounded to the nearest
+    /// hertz, or a frequency string such as `"2.4 GHz"`.
+    fn try_from(value: &Bson) -> Result<Self, Self::Error> {
+        match value {
+            Bson::Int32(hz) => u64::try_from(*hz)
+                .map(Self)
+                .map_err(|_| Error::invalid_value(hz)),
+            Bson::Int64(hz) => u64::try_from(*hz)
+                .map(Self)
+                .map_err(|_| Error::invalid_value(hz)),
+            Bson::Double(hz) => FrequencyF64::from_hz(*hz)
+                .try_to_frequency(Rounding::Nearest)
+                .ok_or_else(|| Error::invalid_value(hz)),
+            Bson::String(s) => s.parse(),
+            other => Err(Error::invalid_value(format_args!(
+                "{:?}",
+                other.element_type()
+            ))),
+        }
+    }
+}
+
+impl TryFrom<Bson> for Frequency {
+    type Error = Error;
+
+    fn try_from(value: Bson) -> Result<Self, Self::Error> {
+        Self::try_from(&value)
+    }
+}
+
+/// Serializes a [`Frequency`] as an `Int64` number of hertz, or as an exact frequency string
+/// when it does not fit.
+///
+/// # Errors
+///
+/// Returns the serializer's error.
+pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+    Bson::from(*freq).serialize(serializer)
+}
+
+/// Deserializes a [`Frequency`] from any value accepted by its `TryFrom<Bson>` implementation.
+///
+/// # Errors
+///
+/// Returns the deserializer's error, or a custom error for values that are not a frequency.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+    let value = Bson::deserialize(deserializer)?;
+    Frequency::try_from(&value).map_err(de::Error::custom)
+}
@@ -0,0 +1,85 @@
+use crate::{Frequency, GIGAHERTZ, KILOHERTZ, MEGAHERTZ};
+
+/// Builds a frequency from parts in different units, checking for overflow
+///
+/// Created with [`Frequency::builder`]. Each part is added to the total, so parts can be given
+/// in any order and the same unit can be used more than once.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+///
+/// let freq = Frequency::builder().ghz(2).mhz(437).khz(500).build();
+/// assert_eq!(freq, Some(Frequency::from_hz(2_437_500_000)));
+///
+/// assert_eq!(Frequency::builder().ghz(u64::MAX).build(), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct FrequencyBuilder {
+    /// Total so far in hertz, or `None` once an overflow occurred
+    hz: Option<u64>,
+}
+
+impl FrequencyBuilder {
+    fn add(self, value: u64, unit: u64) -> Self {
+        let hz = value
+            .checked_mul(unit)
+            .and_then(|part| self.hz?.checked_add(part));
+        Self { hz }
+    }
+
+    /// Adds `ghz` gigahertz.
+    #[doc(alias = "gigahertz")]
+    pub fn ghz(self, ghz: u64) -> Self {
+        self.add(ghz, GIGAHERTZ)
+    }
+
+    /// Adds `mhz` megahertz.
+    #[doc(alias = "megahertz")]
+    pub fn mhz(self, mhz: u64) -> Self {
+        self.add(mhz, MEGAHERTZ)
+    }
+
+    /// Adds `khz` kilohertz.
+    #[doc(alias = "kilohertz")]
+    pub fn khz(self, khz: u64) -> Self {
+        self.add(khz, KILOHERTZ)
+    }
+
+    /// Adds `hz` hertz.
+    #[doc(alias = "hertz")]
+    pub fn hz(self, hz: u64) -> Self {
+        self.add(hz, 1)
+    }
+
+    /// Returns the sum of all parts, or `None` if it does not fit in a `u64`.
+    #[must_use]
+    pub fn build(self) -> Option<Frequency> {
+        self.hz.map(Frequency)
+    }
+}
+
+impl Default for FrequencyBuilder {
+    fn default() -> Self {
+        Self { hz: Some(0) }
+    }
+}
+
+impl Frequency {
+    /// Returns a builder that composes a frequency from parts in different units.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// // Wi-Fi channel 6
+    /// let channel = Frequency::builder().ghz(2).mhz(437).build().unwrap();
+    /// assert_eq!(channel, Frequency::from_mhz(2_437));
+    /// ```
+    pub fn builder() -> FrequencyBuilder {
+        FrequencyBuilder::default()
+    }
+}
@@ -0,0 +1,37 @@
+use bytemuck::{Pod, TransparentWrapper, Zeroable};
+
+use crate::{Frequency, FrequencyF64, FrequencyMilliHz, FrequencyOf, FrequencyShift, SampleRate};
+
+// SAFETY: `Frequency` is `repr(transparent)` over `u64`
+unsafe impl Zeroable for Frequency {}
+// SAFETY: `Frequency` is `repr(transparent)` over `u64`
+unsafe impl Pod for Frequency {}
+// SAFETY: `Frequency` is `repr(transparent)` over `u64`
+unsafe impl TransparentWrapper<u64> for Frequency {}
+
+// SAFETY: `FrequencyOf<T>` is `repr(transparent)` over `T`
+unsafe impl<T: Zeroable> Zeroable for FrequencyOf<T> {}
+// SAFETY: `FrequencyOf<T>` is `repr(transparent)` over `T`
+unsafe impl<T: Pod> Pod for FrequencyOf<T> {}
+// SAFETY: `FrequencyOf<T>` is `repr(transparent)` over `T`
+unsafe impl<T> TransparentWrapper<T> for FrequencyOf<T> {}
+
+// SAFETY: `FrequencyMilliHz` is `repr(transparent)` over `u64`
+unsafe impl Zeroable for FrequencyMilliHz {}
+// SAFETY: `FrequencyMilliHz` is `repr(transparent)` over `u64`
+unsafe impl Pod for FrequencyMilliHz {}
+
+// SAFETY: `FrequencyF64` is `repr(transparent)` over `f64`
+unsafe impl Zeroable for FrequencyF64 {}
+// SAFETY: `FrequencyF64` is `repr(transparent)` over `f64`
+unsafe impl Pod for FrequencyF64 {}
+
+// SAFETY: `FrequencyShift` is `repr(transparent)` over `f64`
+unsafe impl Zeroable for FrequencyShift {}
+// SAFETY: `FrequencyShift` is `repr(transparent)` over `f64`
+unsafe impl Pod for FrequencyShift {}
+
+// SAFETY: `SampleRate` is `repr(transparent)` over `Frequency`
+unsafe impl Zeroable for SampleRate {}
+// SAFETY: `SampleRate` is `repr(transparent)` over `Frequency`
+unsafe impl Pod for SampleRate {}
@@ -0,0 +1,261 @@
+use crate::{Frequency, FrequencyRange, KILOHERTZ};
+
+/// Spacing of the LTE channel raster in hertz
+const EARFCN_SPACING: u64 = 100 * KILOHERTZ;
+
+/// Direction of a cellular link
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Link {
+    /// From the base station to the device
+    Downlink,
+
+    /// From the device to the base station
+    Uplink,
+}
+
+/// One direction of an LTE operating band: `F = low + 0.1 MHz * (N - offset)` for
+/// `N` in `offset..=max`
+#[derive(Debug, Clone, Copy)]
+struct Raster {
+    /// Lowest frequency of the band in units of 100 kHz
+    low: u64,
+    offset: u32,
+    max: u32,
+}
+
+impl Raster {
+    const fn new(low_mhz: u64, offset: u32, max: u32) -> Self {
+        Self {
+            low: low_mhz * 10,
+            offset,
+            max,
+        }
+    }
+
+    fn frequency(&self, earfcn: u32) -> Option<Frequency> {
+        (self.offset..=self.max)
+            .contains(&earfcn)
+            .then(|| Frequency((self.low + u64::from(earfcn - self.offset)) * EARFCN_SPACING))
+    }
+
+    fn earfcn(&self, freq: Frequency) -> Option<u32> {
+        if !freq.0.is_multiple_of(EARFCN_SPACING) {
+            return None;
+        }
+
+        let steps = (freq.0 / EARFCN_SPACING).checked_sub(self.low)?;
+        let earfcn = u32::try_from(steps).ok()?.checked_add(self.offset)?;
+        (earfcn <= self.max).then_some(earfcn)
+    }
+
+    fn range(&self) -> FrequencyRange {
+        let count = u64::from(self.max - self.offset + 1);
+        FrequencyRange::new(
+            Frequency(self.low * EARFCN_SPACING),
+            Frequency((self.low + count) * EARFCN_SPACING),
+        )
+    }
+}
+
+/// Common LTE operating bands per 3GPP TS 36.101 as `(band, downlink, uplink)`. TDD bands
+/// share one raster for both directions and have no separate uplink.
+const LTE_BANDS: [(u16, Raster, Option<Raster>); 20] = [
+    (
+        1,
+        Raster::new(2110, 0, 599),
+        Some(Raster::new(1920, 18_000, 18_599)),
+    ),
+    (
+        2,
+        Raster::new(1930, 600, 1199),
+        Some(Raster::new(1850, 18_600, 19_199)),
+    ),
+    (
+        3,
+        Raster::new(1805, 1200, 1949),
+        Some(Raster::new(1710, 19_200, 19_949)),
+    ),
+    (
+        4,
+        Raster::new(2110, 1950, 2399),
+        Some(Raster::new(1710, 19_950, 20_399)),
+    ),
+    (
+        5,
+        Raster::new(869, 2400, 2649),
+        Some(Raster::new(824, 20_400, 20_649)),
+    ),
+    (
+        7,
+        Raster::new(2620, 2750, 3449),
+        Some(Raster::new(2500, 20_750, 21_449)),
+    ),
+    (
+        8,
+        Raster::new(925, 3450, 3799),
+        Some(Raster::new(880, 21_450, 21_799)),
+    ),
+    (
+        12,
+        Raster::new(729, 5010, 5179),
+        Some(Raster::new(699, 23_010, 23_179)),
+    ),
+    (
+        13,
+        Raster::new(746, 5180, 5279),
+        Some(Raster::new(777, 23_180, 23_279)),
+    ),
+    (
+        14,
+        Raster::new(758, 5280, 5379),
+        Some(Raster::new(788, 23_280, 23_379)),
+    ),
+    (
+        17,
+        Raster::new(734, 5730, 5849),
+        Some(Raster::new(704, 23_730, 23_849)),
+    ),
+    (
+        20,
+        Raster::new(791, 6150, 6449),
+        Some(Raster::new(832, 24_150, 24_449)),
+    ),
+    (
+        25,
+        Raster::new(1930, 8040, 8689),
+        Some(Raster::new(1850, 26_040, 26_689)),
+    ),
+    (
+        26,
+        Raster::new(859, 8690, 9039),
+        Some(Raster::new(814, 26_690, 27_039)),
+    ),
+    (
+        28,
+        Raster::new(758, 9210, 9659),
+        Some(Raster::new(703, 27_210, 27_659)),
+    ),
+    (38, Raster::new(2570, 37_750, 38_249), None),
+    (40, Raster::new(2300, 38_650, 39_649), None),
+    (41, Raster::new(2496, 39_650, 41_589), None),
+    (
+        66,
+        Raster::new(2110, 66_436, 67_335),
+        Some(Raster::new(1710, 131_972, 132_671)),
+    ),
+    (
+        71,
+        Raster::new(617, 68_586, 68_935),
+        Some(Raster::new(663, 133_122, 133_471)),
+    ),
+];
+
+/// Returns the raster of `band` in the direction of `link`.
+fn lte_raster(band: u16, link: Link) -> Option<Raster> {
+    let (_, downlink, uplink) = LTE_BANDS.iter().find(|(number, ..)| *number == band)?;
+
+    match link {
+        Link::Downlink => Some(*downlink),
+        Link::Uplink => Some(uplink.unwrap_or(*downlink)),
+    }
+}
+
+/// Converts an LTE channel number (EARFCN) in `band` to its carrier frequency.
+///
+/// Both downlink and uplink EARFCNs are accepted. Supported bands are 1, 2, 3, 4, 5, 7, 8, 12,
+/// 13, 14, 17, 20, 25, 26, 28, 38, 40, 41, 66 and 71.
+///
+/// Returns `None` if the band is not supported or the EARFCN does not belong to it.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Link, earfcn_to_frequency, frequency_to_earfcn};
+///
+/// assert_eq!(earfcn_to_frequency(3, 1300), Some(Frequency::from_khz(1_815_000)));
+/// assert_eq!(earfcn_to_frequency(3, 19_300), Some(Frequency::from_khz(1_720_000)));
+///
+/// let uplink = frequency_to_earfcn(3, Link::Uplink, Frequency::from_mhz(1_720));
+/// assert_eq!(uplink, Some(19_300));
+/// ```
+#[must_use]
+pub fn earfcn_to_frequency(band: u16, earfcn: u32) -> Option<Frequency> {
+    [Link::Downlink, Link::Uplink]
+        .into_iter()
+        .find_map(|link| lte_raster(band, link)?.frequency(earfcn))
+}
+
+/// Converts a carrier frequency in `band` to its LTE channel number (EARFCN) for the given
+/// `link` direction.
+///
+/// Returns `None` if the band is not supported, or if the frequency is outside the band or not
+/// on the 100 kHz channel raster.
+#[must_use]
+pub fn frequency_to_earfcn(band: u16, link: Link, freq: Frequency) -> Option<u32> {
+    lte_raster(band, link)?.earfcn(freq)
+}
+
+/// Returns the frequencies of an LTE operating band in the given `link` direction, from the
+/// lower band edge to the upper band edge.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Link, lte_band_range};
+///
+/// let band_7 = lte_band_range(7, Link::Downlink).unwrap();
+/// assert_eq!(band_7.min, Frequency::from_mhz(2_620));
+/// assert_eq!(band_7.max, Frequency::from_mhz(2_690));
+/// ```
+#[must_use]
+pub fn lte_band_range(band: u16, link: Link) -> Option<FrequencyRange> {
+    lte_raster(band, link).map(|raster| raster.range())
+}
+
+/// Global frequency raster of 5G NR per 3GPP TS 38.104 as
+/// `(first NR-ARFCN, last NR-ARFCN, offset frequency, raster spacing)`
+const NR_RASTER: [(u32, u32, u64, u64); 3] = [
+    (0, 599_999, 0, 5 * KILOHERTZ),
+    (600_000, 2_016_666, 3_000_000 * KILOHERTZ, 15 * KILOHERTZ),
+    (2_016_667, 3_279_165, 24_250_080 * KILOHERTZ, 60 * KILOHERTZ),
+];
+
+/// Converts a 5G NR channel number (NR-ARFCN) to its reference frequency.
+///
+/// Returns `None` if the NR-ARFCN is above `3279165`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, frequency_to_nr_arfcn, nr_arfcn_to_frequency};
+///
+/// assert_eq!(nr_arfcn_to_frequency(620_000), Some(Frequency::from_khz(3_300_000)));
+/// assert_eq!(frequency_to_nr_arfcn(Frequency::from_khz(3_300_000)), Some(620_000));
+/// ```
+#[must_use]
+pub fn nr_arfcn_to_frequency(arfcn: u32) -> Option<Frequency> {
+    NR_RASTER
+        .iter()
+        .find(|(first, last, ..)| (*first..=*last).contains(&arfcn))
+        .map(|&(first, _, offset, spacing)| Frequency(offset + u64::from(arfcn - first) * spacing))
+}
+
+/// Converts a frequency on the 5G NR global raster to its channel number (NR-ARFCN).
+///
+/// Returns `None` if the frequency is above 100 GHz or not on the raster.
+#[must_use]
+pub fn frequency_to_nr_arfcn(freq: Frequency) -> Option<u32> {
+    NR_RASTER
+        .iter()
+        .rev()
+        .find(|(_, _, offset, _)| freq.0 >= *offset)
+        .and_then(|&(first, last, offset, spacing)| {
+            let steps = freq.0 - offset;
+            if !steps.is_multiple_of(spacing) {
+                return None;
+            }
+
+            let arfcn = u32::try_from(steps / spacing).ok()?.checked_add(first)?;
+            (arfcn <= last).then_some(arfcn)
+        })
+}
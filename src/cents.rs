@@ -0,0 +1,164 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Neg, Sub},
+    str::FromStr,
+};
+
+use crate::{Error, Frequency, Result, parse_suffixed_f64};
+
+/// Represents a musical interval in cents, where 100 cents make up an equal tempered semitone
+/// and 1200 cents make up an octave
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Cents, Frequency};
+///
+/// let interval = Frequency::from_hz(440).interval_to(Frequency::from_hz(880));
+/// assert_eq!(interval, Cents(1200.0));
+///
+/// assert_eq!(Frequency::from_hz(440) + Cents(-1200.0), Frequency::from_hz(220));
+///
+/// let detune: Cents = "+14.3 ¢".parse().unwrap();
+/// assert_eq!(detune.to_string(), "+14.30 ¢");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Cents(pub f64);
+
+impl Cents {
+    /// Equivalent to `0 ¢`, a unison
+    pub const ZERO: Self = Self(0.0);
+
+    /// Equivalent to `100 ¢`, an equal tempered semitone
+    pub const SEMITONE: Self = Self(100.0);
+
+    /// Equivalent to `1200 ¢`, an octave
+    pub const OCTAVE: Self = Self(1200.0);
+
+    /// Returns the interval between two frequencies given as a ratio `to / from`.
+    ///
+    /// ```rust
+    /// use parse_frequency::Cents;
+    ///
+    /// assert_eq!(Cents::from_ratio(2.0), Cents::OCTAVE);
+    /// ```
+    #[must_use]
+    pub fn from_ratio(ratio: f64) -> Self {
+        Self(1200.0 * ratio.log2())
+    }
+
+    /// Returns the frequency ratio this interval represents (`2^(cents / 1200)`).
+    ///
+    /// ```rust
+    /// use parse_frequency::Cents;
+    ///
+    /// assert_eq!(Cents::OCTAVE.as_ratio(), 2.0);
+    /// ```
+    #[must_use]
+    pub fn as_ratio(&self) -> f64 {
+        (self.0 / 1200.0).exp2()
+    }
+}
+
+impl Frequency {
+    /// Returns the interval from this frequency up (or down) to `other`, in cents.
+    ///
+    /// Intervals involving `0 Hz` are infinite.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Cents, Frequency};
+    ///
+    /// let interval = Frequency::from_hz(440).interval_to(Frequency::from_hz(445));
+    /// assert!((interval.0 - 19.56).abs() < 0.01);
+    ///
+    /// let interval = Frequency::from_hz(440).interval_to(Frequency::from_hz(220));
+    /// assert_eq!(interval, -Cents::OCTAVE);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn interval_to(&self, other: Frequency) -> Cents {
+        Cents::from_ratio(other.0 as f64 / self.0 as f64)
+    }
+
+    /// Transposes the frequency by the given interval, rounding to the nearest hertz.
+    ///
+    /// This is equivalent to `self + interval`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Cents, Frequency};
+    ///
+    /// let fifth = Frequency::from_hz(440).transpose(Cents(700.0));
+    /// assert_eq!(fifth, Frequency::from_hz(659));
+    /// ```
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn transpose(&self, interval: Cents) -> Frequency {
+        Frequency((self.0 as f64 * interval.as_ratio()).round() as u64)
+    }
+}
+
+impl Add for Cents {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for Cents {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Neg for Cents {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Add<Cents> for Frequency {
+    type Output = Self;
+
+    fn add(self, rhs: Cents) -> Self::Output {
+        self.transpose(rhs)
+    }
+}
+
+impl Sub<Cents> for Frequency {
+    type Output = Self;
+
+    fn sub(self, rhs: Cents) -> Self::Output {
+        self.transpose(-rhs)
+    }
+}
+
+impl Display for Cents {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.2} ¢", self.0)
+    }
+}
+
+impl FromStr for Cents {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Intervals can go down, so negative values are allowed
+        parse_suffixed_f64(s, &["¢", "cents", "cent", "ct"], |value| Some(Self(value)))
+    }
+}
@@ -1,3 +1,5 @@
+use chrono::{DateTime, Utc};
+
 use super::Frequency;
 
 impl Frequency {
@@ -36,4 +38,31 @@ impl Frequency {
             chrono::Duration::nanoseconds(period_in_nanoseconds as i64)
         }
     }
+
+    /// Returns the timestamps of successive events at this frequency, starting at `start`.
+    ///
+    /// Each timestamp is computed from `start` and its index rather than by adding the period
+    /// repeatedly, so it is the exact tick time rounded down to the nanosecond and rounding
+    /// errors never accumulate. The iterator is empty for `0 Hz`, and ends once a timestamp is
+    /// out of range for `DateTime<Utc>`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use chrono::{DateTime, Duration};
+    /// use parse_frequency::Frequency;
+    ///
+    /// let start = DateTime::UNIX_EPOCH;
+    /// let ticks: Vec<_> = Frequency::from_hz(3).ticks_from(start).take(4).collect();
+    ///
+    /// assert_eq!(ticks[1] - start, Duration::nanoseconds(333_333_333));
+    /// assert_eq!(ticks[2] - start, Duration::nanoseconds(666_666_666));
+    /// assert_eq!(ticks[3] - start, Duration::seconds(1));
+    /// ```
+    pub fn ticks_from(&self, start: DateTime<Utc>) -> impl Iterator<Item = DateTime<Utc>> + use<> {
+        let hz = u128::from(self.0);
+        (0_u128..).take_while(move |_| hz != 0).map_while(move |n| {
+            let nanos = n.checked_mul(1_000_000_000)? / hz;
+            start.checked_add_signed(chrono::Duration::nanoseconds(i64::try_from(nanos).ok()?))
+        })
+    }
 }
@@ -1,19 +1,187 @@
-use crate::{Error, Frequency};
+use clap::{
+    builder::{PossibleValue, StyledStr, TypedValueParser},
+    error::{ContextKind, ContextValue, ErrorKind},
+};
 
-impl clap::builder::TypedValueParser for Frequency {
+use crate::{FREQUENCY_EXAMPLES, Frequency, FrequencyRange};
+
+impl TypedValueParser for Frequency {
     type Value = Self;
 
+    /// Parses a frequency argument.
+    ///
+    /// On failure the error names the argument, echoes the rejected value and lists example
+    /// formats.
     fn parse_ref(
         &self,
-        _cmd: &clap::Command,
-        _arg: Option<&clap::Arg>,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
         value: &std::ffi::OsStr,
     ) -> std::result::Result<Frequency, clap::error::Error> {
-        let value = value.to_str().ok_or_else(|| {
-            clap::Error::raw(clap::error::ErrorKind::InvalidUtf8, "Invalid UTF-8")
-        })?;
-        value
-            .parse()
-            .map_err(|e: Error| clap::Error::raw(clap::error::ErrorKind::InvalidValue, e))
+        let Some(value) = value.to_str() else {
+            return Err(clap::Error::new(ErrorKind::InvalidUtf8).with_cmd(cmd));
+        };
+
+        value.parse().map_err(|_: crate::Error| {
+            let [first, second, third] = FREQUENCY_EXAMPLES;
+            let tip = format!("use a number and a unit, like {first}, {second} or {third}");
+
+            let mut err = invalid_value(cmd, arg, value, ErrorKind::ValueValidation);
+            err.insert(
+                ContextKind::Suggested,
+                ContextValue::StyledStrs(vec![StyledStr::from(tip)]),
+            );
+            err
+        })
+    }
+}
+
+impl clap::builder::ValueParserFactory for Frequency {
+    type Parser = Self;
+
+    fn value_parser() -> Self::Parser {
+        Self::ZERO
+    }
+}
+
+/// clap value parser that only accepts a given set or range of frequencies
+///
+/// A set of allowed frequencies is reported to clap as possible values such as `44.1kHz`, so they
+/// are listed in `--help`, offered by shell completion and listed in errors. Values are compared
+/// after parsing, so `48 kHz` and `48000 Hz` both match an allowed 48 kHz.
+///
+/// Without an allowed set no possible values are reported. Shell completion replaces the whole
+/// word, so the unit symbols on their own would complete to invalid values, and clap prints an
+/// empty `[possible values: ]` in `--help` when every possible value is hidden. A value parser
+/// cannot set the argument's [`ValueHint`](clap::ValueHint) either, so set it to
+/// `ValueHint::Other` on the argument to keep shells from offering file names.
+///
+/// # Examples
+///
+/// ```rust
+/// use clap::{Arg, Command, ValueHint};
+/// use parse_frequency::{Frequency, FrequencyRange, FrequencyValueParser};
+///
+/// let cmd = Command::new("record")
+///     .arg(
+///         Arg::new("rate")
+///             .long("rate")
+///             .value_parser(FrequencyValueParser::new().allowed([
+///                 Frequency::from_hz(44_100),
+///                 Frequency::from_khz(48),
+///             ])),
+///     )
+///     .arg(Arg::new("tune").long("tune").value_hint(ValueHint::Other).value_parser(
+///         FrequencyValueParser::new().range(FrequencyRange::new(
+///             Frequency::from_mhz(88),
+///             Frequency::from_mhz(108),
+///         )),
+///     ));
+///
+/// let matches = cmd.clone().try_get_matches_from(["record", "--rate", "48kHz"]).unwrap();
+/// assert_eq!(matches.get_one::<Frequency>("rate"), Some(&Frequency::from_khz(48)));
+///
+/// assert!(cmd.clone().try_get_matches_from(["record", "--rate", "96 kHz"]).is_err());
+/// assert!(cmd.try_get_matches_from(["record", "--tune", "2.4 GHz"]).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Default, Hash)]
+#[must_use]
+pub struct FrequencyValueParser {
+    allowed: Option<Vec<Frequency>>,
+    range: Option<FrequencyRange>,
+}
+
+impl FrequencyValueParser {
+    /// Creates a parser that accepts any frequency.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only accepts the given frequencies.
+    pub fn allowed(mut self, allowed: impl IntoIterator<Item = Frequency>) -> Self {
+        self.allowed = Some(allowed.into_iter().collect());
+        self
     }
+
+    /// Only accepts frequencies within `range`, bounds included.
+    pub fn range(mut self, range: FrequencyRange) -> Self {
+        self.range = Some(range);
+        self
+    }
+}
+
+impl TypedValueParser for FrequencyValueParser {
+    type Value = Frequency;
+
+    fn parse_ref(
+        &self,
+        cmd: &clap::Command,
+        arg: Option<&clap::Arg>,
+        value: &std::ffi::OsStr,
+    ) -> std::result::Result<Frequency, clap::error::Error> {
+        let freq = Frequency::ZERO.parse_ref(cmd, arg, value)?;
+        let value = value.to_string_lossy();
+
+        if let Some(allowed) = &self.allowed
+            && !allowed.contains(&freq)
+        {
+            let mut err = invalid_value(cmd, arg, &value, ErrorKind::InvalidValue);
+            err.insert(
+                ContextKind::ValidValue,
+                ContextValue::Strings(allowed.iter().copied().map(compact).collect()),
+            );
+            return Err(err);
+        }
+
+        if let Some(range) = self.range
+            && !range.contains(freq)
+        {
+            let tip = format!(
+                "expected a frequency from {} to {}",
+                range.min.to_exact_string(),
+                range.max.to_exact_string()
+            );
+
+            let mut err = invalid_value(cmd, arg, &value, ErrorKind::ValueValidation);
+            err.insert(
+                ContextKind::Suggested,
+                ContextValue::StyledStrs(vec![StyledStr::from(tip)]),
+            );
+            return Err(err);
+        }
+
+        Ok(freq)
+    }
+
+    fn possible_values(&self) -> Option<Box<dyn Iterator<Item = PossibleValue> + '_>> {
+        let allowed = self.allowed.as_ref()?;
+        Some(Box::new(
+            allowed
+                .iter()
+                .map(|freq| PossibleValue::new(compact(*freq))),
+        ))
+    }
+}
+
+/// Formats `freq` exactly and without spaces, such as `44.1kHz`, so shells need no quoting
+fn compact(freq: Frequency) -> String {
+    freq.to_exact_string().replace(' ', "")
+}
+
+/// Creates an error of `kind` that names `arg` and echoes `value`
+fn invalid_value(
+    cmd: &clap::Command,
+    arg: Option<&clap::Arg>,
+    value: &str,
+    kind: ErrorKind,
+) -> clap::Error {
+    let arg = arg.map_or_else(|| "...".to_string(), ToString::to_string);
+
+    let mut err = clap::Error::new(kind).with_cmd(cmd);
+    err.insert(ContextKind::InvalidArg, ContextValue::String(arg));
+    err.insert(
+        ContextKind::InvalidValue,
+        ContextValue::String(value.to_string()),
+    );
+    err
 }
@@ -0,0 +1,279 @@
+use crate::{
+    Frequency, FrequencyRange, Rounding,
+    math::{error_ppm, gcd},
+};
+
+/// An exact frequency in hertz, as a fraction `(numerator, denominator)`
+type Exact = (u128, u128);
+
+/// Whether a [`ClockStage`] multiplies or divides its input
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StageKind {
+    /// The output is the input multiplied by the setting (e.g. a PLL feedback divider)
+    Multiplier,
+
+    /// The output is the input divided by the setting (e.g. a bus prescaler)
+    Divider,
+}
+
+/// A single multiplier or divider in a [`ClockChain`]
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{ClockStage, Frequency};
+///
+/// let apb1 = ClockStage::divider("APB1", [1, 2, 4, 8, 16]).with_max_output(Frequency::from_mhz(42));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ClockStage {
+    /// Name of the stage, used to identify it in a [`ClockPlan`]
+    pub name: String,
+
+    /// Whether the stage multiplies or divides
+    pub kind: StageKind,
+
+    /// The values the stage can be programmed with
+    pub values: Vec<u32>,
+
+    /// The frequencies the output of this stage is allowed to take
+    pub output_range: FrequencyRange,
+}
+
+impl ClockStage {
+    /// Creates a stage that multiplies its input by one of `values`.
+    #[must_use]
+    pub fn multiplier(name: impl Into<String>, values: impl IntoIterator<Item = u32>) -> Self {
+        Self::new(name, StageKind::Multiplier, values)
+    }
+
+    /// Creates a stage that divides its input by one of `values`.
+    #[must_use]
+    pub fn divider(name: impl Into<String>, values: impl IntoIterator<Item = u32>) -> Self {
+        Self::new(name, StageKind::Divider, values)
+    }
+
+    fn new(
+        name: impl Into<String>,
+        kind: StageKind,
+        values: impl IntoIterator<Item = u32>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            kind,
+            values: values.into_iter().filter(|&value| value != 0).collect(),
+            output_range: FrequencyRange::new(Frequency::ZERO, Frequency(u64::MAX)),
+        }
+    }
+
+    /// Returns a copy of this stage that only accepts outputs within `range`.
+    #[must_use]
+    pub fn with_output_range(self, range: FrequencyRange) -> Self {
+        Self {
+            output_range: range,
+            ..self
+        }
+    }
+
+    /// Returns a copy of this stage that only accepts outputs up to `max`.
+    #[must_use]
+    pub fn with_max_output(self, max: Frequency) -> Self {
+        let min = self.output_range.min.min(max);
+        self.with_output_range(FrequencyRange::new(min, max))
+    }
+
+    /// Applies `value` to the exact input frequency `(numerator, denominator)`, keeping the
+    /// result in lowest terms.
+    fn apply(&self, (num, den): Exact, value: u32) -> Exact {
+        let (num, den) = match self.kind {
+            StageKind::Multiplier => (num * u128::from(value), den),
+            StageKind::Divider => (num, den * u128::from(value)),
+        };
+
+        let divisor = gcd(num, den).max(1);
+        (num / divisor, den / divisor)
+    }
+
+    /// Returns `true` if the exact frequency `(numerator, denominator)` is an allowed output.
+    fn accepts(&self, (num, den): Exact) -> bool {
+        let (min, max) = (
+            u128::from(self.output_range.min.0),
+            u128::from(self.output_range.max.0),
+        );
+        min * den <= num && num <= max * den
+    }
+}
+
+/// A configuration of every stage in a [`ClockChain`] and the frequencies it results in
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClockPlan {
+    /// The value programmed into each stage, in chain order
+    pub settings: Vec<u32>,
+
+    /// The output frequency of each stage in chain order, rounded to the nearest hertz
+    pub stage_outputs: Vec<Frequency>,
+
+    /// The output frequency of the last stage, rounded to the nearest hertz
+    pub output: Frequency,
+
+    /// The relative error of the exact output in parts per million with respect to the
+    /// requested target (positive if too fast), or `0.0` when no target was given
+    pub error_ppm: f64,
+}
+
+/// Models a chain of clock multipliers and dividers, such as PLL → AHB → APB → peripheral
+///
+/// All frequencies are tracked exactly as fractions internally, so even deep chains with odd
+/// dividers do not accumulate rounding errors.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{ClockChain, ClockStage, Frequency, FrequencyRange};
+///
+/// let chain = ClockChain::new()
+///     .stage(ClockStage::divider("PLLM", 2..=63).with_output_range(FrequencyRange::new(
+///         Frequency::from_mhz(1),
+///         Frequency::from_mhz(2),
+///     )))
+///     .stage(ClockStage::multiplier("PLLN", 50..=432).with_output_range(FrequencyRange::new(
+///         Frequency::from_mhz(100),
+///         Frequency::from_mhz(432),
+///     )))
+///     .stage(ClockStage::divider("PLLP", [2, 4, 6, 8]).with_max_output(Frequency::from_mhz(168)));
+///
+/// let plan = chain.solve(Frequency::from_mhz(8), Frequency::from_mhz(168)).unwrap();
+/// assert_eq!(plan.output, Frequency::from_mhz(168));
+/// assert_eq!(plan.error_ppm, 0.0);
+///
+/// let output = chain.output(Frequency::from_mhz(8), &[4, 168, 2]);
+/// assert_eq!(output, Some(Frequency::from_mhz(168)));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct ClockChain {
+    /// The stages of the chain, from the source to the final output
+    pub stages: Vec<ClockStage>,
+}
+
+impl ClockChain {
+    /// Creates an empty chain.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns a copy of this chain with `stage` appended to the end.
+    #[must_use]
+    pub fn stage(mut self, stage: ClockStage) -> Self {
+        self.stages.push(stage);
+        self
+    }
+
+    /// Computes the output frequency for the given per-stage settings.
+    ///
+    /// Returns `None` if the number of settings does not match the number of stages, if a setting
+    /// is not one of the values a stage accepts, or if any stage output is out of range.
+    #[must_use]
+    pub fn output(&self, source: Frequency, settings: &[u32]) -> Option<Frequency> {
+        self.plan(source, settings, None).map(|plan| plan.output)
+    }
+
+    /// Returns every valid configuration of the chain for `source`, in search order.
+    #[must_use]
+    pub fn achievable_outputs(&self, source: Frequency) -> Vec<ClockPlan> {
+        let mut plans = Vec::new();
+        self.search(
+            &mut Vec::new(),
+            (u128::from(source.0), 1),
+            &mut |settings, _| {
+                plans.extend(self.plan(source, settings, None));
+            },
+        );
+        plans
+    }
+
+    /// Finds the configuration whose output is closest to `target`.
+    ///
+    /// When several configurations are equally close, the first one found is returned. Stages
+    /// are searched in the order their values were given.
+    #[must_use]
+    pub fn solve(&self, source: Frequency, target: Frequency) -> Option<ClockPlan> {
+        let target_hz = u128::from(target.0);
+        let mut best: Option<(Vec<u32>, Exact)> = None;
+
+        self.search(
+            &mut Vec::new(),
+            (u128::from(source.0), 1),
+            &mut |settings, exact| {
+                // |num / den - target| compared by cross-multiplying with the other denominator
+                let distance = |(num, den): Exact| num.abs_diff(target_hz * den);
+                let better = best.as_ref().is_none_or(|(_, current)| {
+                    distance(exact) * current.1 < distance(*current) * exact.1
+                });
+
+                if better {
+                    best = Some((settings.to_vec(), exact));
+                }
+            },
+        );
+
+        best.and_then(|(settings, _)| self.plan(source, &settings, Some(target)))
+    }
+
+    /// Visits every valid combination of settings, depth first.
+    fn search(&self, settings: &mut Vec<u32>, input: Exact, visit: &mut dyn FnMut(&[u32], Exact)) {
+        let Some(stage) = self.stages.get(settings.len()) else {
+            visit(settings, input);
+            return;
+        };
+
+        for &value in &stage.values {
+            let output = stage.apply(input, value);
+
+            if stage.accepts(output) {
+                settings.push(value);
+                self.search(settings, output, visit);
+                settings.pop();
+            }
+        }
+    }
+
+    /// Builds a plan for the given settings, validating every stage along the way.
+    // Stage outputs are bounded by their u64 output ranges
+    #[allow(clippy::cast_possible_truncation)]
+    fn plan(
+        &self,
+        source: Frequency,
+        settings: &[u32],
+        target: Option<Frequency>,
+    ) -> Option<ClockPlan> {
+        if settings.len() != self.stages.len() {
+            return None;
+        }
+
+        let mut exact = (u128::from(source.0), 1);
+        let mut stage_outputs = Vec::with_capacity(settings.len());
+
+        for (stage, &value) in self.stages.iter().zip(settings) {
+            if !stage.values.contains(&value) {
+                return None;
+            }
+
+            exact = stage.apply(exact, value);
+            if !stage.accepts(exact) {
+                return None;
+            }
+
+            stage_outputs.push(Frequency(Rounding::Nearest.div(exact.0, exact.1)? as u64));
+        }
+
+        Some(ClockPlan {
+            settings: settings.to_vec(),
+            output: stage_outputs.last().copied().unwrap_or(source),
+            stage_outputs,
+            error_ppm: target.filter(|target| target.0 != 0).map_or(0.0, |target| {
+                error_ppm(exact.0, exact.1, u128::from(target.0))
+            }),
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use crate::Frequency;
+
+/// Maximum number of bytes a LEB128 encoded [`Frequency`] takes up
+pub const VARINT_MAX_LEN: usize = 10;
+
+impl Frequency {
+    /// Returns the number of hertz as 8 little-endian bytes.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let bytes = Frequency::from_khz(1).to_le_bytes();
+    /// assert_eq!(bytes, [0xe8, 0x03, 0, 0, 0, 0, 0, 0]);
+    /// assert_eq!(Frequency::from_le_bytes(bytes), Frequency::from_khz(1));
+    /// ```
+    #[must_use]
+    pub fn to_le_bytes(&self) -> [u8; 8] {
+        self.0.to_le_bytes()
+    }
+
+    /// Creates a frequency from a number of hertz stored as 8 little-endian bytes.
+    #[must_use]
+    pub fn from_le_bytes(bytes: [u8; 8]) -> Self {
+        Self(u64::from_le_bytes(bytes))
+    }
+
+    /// Returns the number of bytes [`Frequency::encode_varint`] writes for this frequency.
+    #[must_use]
+    pub fn varint_len(&self) -> usize {
+        let bits = u64::BITS - self.0.leading_zeros();
+        (bits as usize).div_ceil(7).max(1)
+    }
+
+    /// Writes the number of hertz as an unsigned LEB128 varint to the start of `buf`, returning
+    /// the number of bytes written.
+    ///
+    /// The encoding is always the shortest one, so equal frequencies produce equal bytes. Small
+    /// frequencies take fewer bytes, with at most [`VARINT_MAX_LEN`] bytes for `u64::MAX` Hz.
+    ///
+    /// Returns `None`, leaving `buf` untouched, if it is too short.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, VARINT_MAX_LEN};
+    ///
+    /// let mut buf = [0; VARINT_MAX_LEN];
+    /// let len = Frequency::from_khz(1).encode_varint(&mut buf).unwrap();
+    /// assert_eq!(&buf[..len], &[0xe8, 0x07]);
+    ///
+    /// assert_eq!(Frequency::decode_varint(&buf[..len]), Some((Frequency::from_khz(1), 2)));
+    /// ```
+    pub fn encode_varint(&self, buf: &mut [u8]) -> Option<usize> {
+        let len = self.varint_len();
+        let out = buf.get_mut(..len)?;
+        let mut value = self.0;
+
+        for byte in out.iter_mut() {
+            // Truncation keeps exactly the low 7 bits, which is the point
+            #[allow(clippy::cast_possible_truncation)]
+            let low = (value & 0x7f) as u8;
+            value >>= 7;
+            *byte = if value == 0 { low } else { low | 0x80 };
+        }
+
+        Some(len)
+    }
+
+    /// Reads an unsigned LEB128 varint from the start of `bytes`, returning the frequency and the
+    /// number of bytes read.
+    ///
+    /// Returns `None` if the varint is truncated or does not fit in a `u64`.
+    #[must_use]
+    pub fn decode_varint(bytes: &[u8]) -> Option<(Self, usize)> {
+        let mut value = 0u64;
+
+        for (i, &byte) in bytes.iter().take(VARINT_MAX_LEN).enumerate() {
+            let low = u64::from(byte & 0x7f);
+            let shift = 7 * i as u32;
+
+            // The last byte may only carry the single remaining bit
+            if i == VARINT_MAX_LEN - 1 && low > 1 {
+                return None;
+            }
+
+            value |= low << shift;
+            if byte & 0x80 == 0 {
+                return Some((Self(value), i + 1));
+            }
+        }
+
+        None
+    }
+}
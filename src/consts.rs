@@ -0,0 +1,68 @@
+//! Well-known frequencies from electronics, audio, video and radio
+//!
+//! # Examples
+//!
+//! ```rust
+//! use parse_frequency::{Frequency, consts};
+//!
+//! assert_eq!(consts::WATCH_CRYSTAL, Frequency::from_hz(32_768));
+//!
+//! // A 1 Hz tick from the watch crystal
+//! assert_eq!(consts::WATCH_CRYSTAL / (1 << 15), Frequency::HERTZ);
+//! ```
+
+use crate::Frequency;
+
+/// 32.768 kHz watch crystal, which divides down to 1 Hz with a 15-bit counter
+pub const WATCH_CRYSTAL: Frequency = Frequency(32_768);
+
+/// 8 MHz crystal, common on microcontroller boards
+pub const CRYSTAL_8MHZ: Frequency = Frequency(8_000_000);
+
+/// 12 MHz crystal, common for USB full speed devices
+pub const CRYSTAL_12MHZ: Frequency = Frequency(12_000_000);
+
+/// 16 MHz crystal, common on AVR and other microcontroller boards
+pub const CRYSTAL_16MHZ: Frequency = Frequency(16_000_000);
+
+/// 24 MHz crystal, common on application processors
+pub const CRYSTAL_24MHZ: Frequency = Frequency(24_000_000);
+
+/// 25 MHz crystal, common for Ethernet PHYs
+pub const CRYSTAL_25MHZ: Frequency = Frequency(25_000_000);
+
+/// 27 MHz MPEG system clock, also used as the reference for standard definition video
+pub const VIDEO_27MHZ: Frequency = Frequency(27_000_000);
+
+/// 44.1 kHz CD audio sample rate, also available as `SampleRate::KHZ_44_1`
+pub const AUDIO_44K1: Frequency = Frequency(44_100);
+
+/// 48 kHz professional audio and video sample rate, also available as `SampleRate::KHZ_48`
+pub const AUDIO_48K: Frequency = Frequency(48_000);
+
+/// 96 kHz high resolution audio sample rate, also available as `SampleRate::KHZ_96`
+pub const AUDIO_96K: Frequency = Frequency(96_000);
+
+/// 13.56 MHz carrier used by NFC and RFID
+pub const NFC: Frequency = Frequency(13_560_000);
+
+/// 1.023 MHz chip rate of the GPS C/A code
+pub const GPS_CA_CHIP_RATE: Frequency = Frequency(1_023_000);
+
+/// 1575.42 MHz GPS L1 carrier
+pub const GPS_L1: Frequency = Frequency(1_575_420_000);
+
+/// 1227.6 MHz GPS L2 carrier
+pub const GPS_L2: Frequency = Frequency(1_227_600_000);
+
+/// 50 Hz mains frequency used in most of the world
+pub const MAINS_50HZ: Frequency = Frequency(50);
+
+/// 60 Hz mains frequency used in the Americas and parts of Asia
+pub const MAINS_60HZ: Frequency = Frequency(60);
+
+/// 440 Hz concert pitch A4, also available as `Frequency::A440`
+pub const CONCERT_PITCH: Frequency = Frequency(440);
+
+/// 1420.405 MHz hydrogen line, rounded to the nearest hertz
+pub const HYDROGEN_LINE: Frequency = Frequency(1_420_405_752);
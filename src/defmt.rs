@@ -0,0 +1,65 @@
+//! [`defmt::Format`] implementations for logging from firmware
+//!
+//! Frequencies and periods are logged as their raw integer with the unit in the interned format
+//! string, so the target only sends the integer over RTT and the host does the formatting.
+
+use defmt::{Format, Formatter, write};
+
+use crate::{
+    Error, Frequency, FrequencyInt, FrequencyOf, FrequencyUnit, NonZeroFrequency, Period, Rounding,
+};
+
+impl Format for Frequency {
+    /// Logs the frequency in hertz, e.g. `2400000000 Hz`.
+    fn format(&self, f: Formatter) {
+        write!(f, "{=u64} Hz", self.0);
+    }
+}
+
+impl<T: FrequencyInt + Format> Format for FrequencyOf<T> {
+    /// Logs the frequency in hertz, e.g. `2400000000 Hz`.
+    fn format(&self, f: Formatter) {
+        write!(f, "{} Hz", self.0);
+    }
+}
+
+impl Format for NonZeroFrequency {
+    /// Logs the frequency in hertz, e.g. `32768 Hz`.
+    fn format(&self, f: Formatter) {
+        write!(f, "{=u64} Hz", self.get().0);
+    }
+}
+
+impl Format for Period {
+    /// Logs the period in picoseconds, e.g. `667 ps`.
+    fn format(&self, f: Formatter) {
+        write!(f, "{=u64} ps", self.as_picos());
+    }
+}
+
+impl Format for FrequencyUnit {
+    /// Logs the unit symbol, e.g. `MHz`.
+    fn format(&self, f: Formatter) {
+        write!(f, "{=str}", self.symbol());
+    }
+}
+
+impl Format for Rounding {
+    fn format(&self, f: Formatter) {
+        match self {
+            Rounding::Down => write!(f, "Down"),
+            Rounding::Nearest => write!(f, "Nearest"),
+            Rounding::Up => write!(f, "Up"),
+        }
+    }
+}
+
+impl Format for Error {
+    /// Logs the error like its `Display` implementation.
+    fn format(&self, f: Formatter) {
+        match self {
+            Error::UnknownUnit(unit) => write!(f, "Unknown unit: {=str}", unit.as_str()),
+            Error::InvalidValue(value) => write!(f, "Invalid value: {=str}", value.as_str()),
+        }
+    }
+}
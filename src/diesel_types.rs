@@ -0,0 +1,36 @@
+use diesel::{
+    backend::Backend,
+    deserialize::{self, FromSql},
+    serialize::{self, Output, ToSql},
+    sql_types::BigInt,
+};
+
+use crate::Frequency;
+
+impl<DB: Backend> ToSql<BigInt, DB> for Frequency
+where
+    i64: ToSql<BigInt, DB>,
+{
+    /// Stores the frequency as a `BIGINT` number of hertz.
+    ///
+    /// Frequencies above `i64::MAX` Hz do not fit and fail to serialize.
+    fn to_sql<'b>(&'b self, out: &mut Output<'b, '_, DB>) -> serialize::Result {
+        i64::try_from(self.0)?;
+
+        // Backends may keep the bound value for `'b`, so the `i64` has to be borrowed from
+        // `self` rather than copied into a local. The range check above makes both equal.
+        let hz: &i64 = bytemuck::cast_ref(&self.0);
+        <i64 as ToSql<BigInt, DB>>::to_sql(hz, out)
+    }
+}
+
+impl<DB: Backend> FromSql<BigInt, DB> for Frequency
+where
+    i64: FromSql<BigInt, DB>,
+{
+    /// Reads a non-negative `BIGINT` number of hertz.
+    fn from_sql(bytes: DB::RawValue<'_>) -> deserialize::Result<Self> {
+        let hz = <i64 as FromSql<BigInt, DB>>::from_sql(bytes)?;
+        Ok(Self(u64::try_from(hz)?))
+    }
+}
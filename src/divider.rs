@@ -0,0 +1,66 @@
+use crate::{Frequency, Rounding, math::error_ppm};
+
+/// The result of [`Frequency::best_divider`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ClockDivider {
+    /// The integer divider to apply to the source clock
+    pub divider: u32,
+
+    /// The output frequency achieved with this divider, rounded to the nearest hertz
+    pub output: Frequency,
+
+    /// The relative error of the output in parts per million (positive if too fast)
+    pub error_ppm: f64,
+}
+
+impl Frequency {
+    /// Finds the integer divider in `1..=max_div` that brings `source` closest to `target`.
+    ///
+    /// The search is done with exact integer arithmetic. When two dividers are equally close, the
+    /// smaller one is chosen. Returns `None` if `source`, `target` or `max_div` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// // An ADC clock as close as possible to 14 MHz from a 72 MHz bus
+    /// let adc = Frequency::best_divider(Frequency::from_mhz(72), Frequency::from_mhz(14), 8).unwrap();
+    /// assert_eq!(adc.divider, 5);
+    /// assert_eq!(adc.output, Frequency::from_hz(14_400_000));
+    /// assert!((adc.error_ppm - 28_571.4).abs() < 0.1);
+    /// ```
+    #[must_use]
+    // The divider is bounded by max_div and the output never exceeds the source
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn best_divider(
+        source: Frequency,
+        target: Frequency,
+        max_div: u32,
+    ) -> Option<ClockDivider> {
+        let (source_hz, target_hz) = (u128::from(source.0), u128::from(target.0));
+
+        if source_hz == 0 || target_hz == 0 || max_div == 0 {
+            return None;
+        }
+
+        // The ideal divider lies between these two candidates
+        let clamp = |div: u128| div.clamp(1, u128::from(max_div));
+        let low = clamp(Rounding::Down.div(source_hz, target_hz)?);
+        let high = clamp(Rounding::Up.div(source_hz, target_hz)?);
+
+        // |source / div - target| = |source - target * div| / div, compared by cross-multiplying
+        let distance = |div: u128| source_hz.abs_diff(target_hz * div);
+        let divider = if distance(high) * low < distance(low) * high {
+            high
+        } else {
+            low
+        };
+
+        Some(ClockDivider {
+            divider: divider as u32,
+            output: Frequency(Rounding::Nearest.div(source_hz, divider)? as u64),
+            error_ppm: error_ppm(source_hz, divider, target_hz),
+        })
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Neg},
+};
+
+use crate::{Frequency, SPEED_OF_LIGHT};
+
+/// Represents a signed frequency offset in hertz, such as a Doppler shift
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyShift};
+///
+/// let shift = FrequencyShift(-1_250.0);
+/// assert_eq!(Frequency::from_khz(10) + shift, Frequency::from_hz(8_750));
+/// assert_eq!(shift.to_string(), "-1250.00 Hz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct FrequencyShift(pub f64);
+
+impl FrequencyShift {
+    /// Equivalent to `0 Hz`, no shift
+    pub const ZERO: Self = Self(0.0);
+
+    #[must_use]
+    #[doc(alias = "as_hertz")]
+    pub fn as_hz(&self) -> f64 {
+        self.0
+    }
+}
+
+impl Display for FrequencyShift {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:+.2} Hz", self.0)
+    }
+}
+
+impl Neg for FrequencyShift {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self(-self.0)
+    }
+}
+
+impl Add<FrequencyShift> for Frequency {
+    type Output = Self;
+
+    /// Applies the shift, rounding to the nearest hertz. Results below `0 Hz` saturate at
+    /// [`Frequency::ZERO`].
+    // Precision loss is acceptable here and the value is clamped before casting
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    fn add(self, shift: FrequencyShift) -> Self::Output {
+        let hz = (self.0 as f64 + shift.0).round();
+        if hz.is_nan() {
+            Self::ZERO
+        } else {
+            Self(hz.max(0.0) as u64)
+        }
+    }
+}
+
+impl Frequency {
+    /// Returns the Doppler shift of an electromagnetic wave at this frequency for a radial
+    /// velocity in metres per second, using the non-relativistic approximation
+    /// `Δf = f * v / c`.
+    ///
+    /// Positive velocities mean the transmitter and receiver are closing in on each other,
+    /// which raises the observed frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// // A LEO satellite approaching at 7 km/s on 437 MHz
+    /// let shift = Frequency::from_mhz(437).doppler_shift(7_000.0);
+    /// assert!((shift.0 - 10_203.73).abs() < 0.01);
+    /// ```
+    #[must_use]
+    #[doc(alias = "doppler")]
+    pub fn doppler_shift(&self, relative_velocity_mps: f64) -> FrequencyShift {
+        self.doppler_shift_in(relative_velocity_mps, SPEED_OF_LIGHT)
+    }
+
+    /// Returns the Doppler shift for a radial velocity in metres per second and a wave
+    /// propagating at `speed` metres per second, using the approximation `Δf = f * v / speed`.
+    ///
+    /// This is accurate as long as the velocity is small compared to the propagation speed,
+    /// e.g. for sound use [`SPEED_OF_SOUND`](crate::SPEED_OF_SOUND).
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn doppler_shift_in(&self, relative_velocity_mps: f64, speed: f64) -> FrequencyShift {
+        FrequencyShift(self.0 as f64 * relative_velocity_mps / speed)
+    }
+
+    /// Returns the Doppler shift of an electromagnetic wave at this frequency using the
+    /// relativistic formula `f' = f * sqrt((1 + β) / (1 - β))` with `β = v / c`.
+    ///
+    /// Positive velocities mean the transmitter and receiver are closing in on each other.
+    /// Velocities at or beyond the speed of light result in a non-finite shift.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let freq = Frequency::from_ghz(10);
+    /// let classic = freq.doppler_shift(-30_000.0);
+    /// let exact = freq.doppler_shift_relativistic(-30_000.0);
+    ///
+    /// // The approximation is off by about 50 Hz at 30 km/s
+    /// assert!((classic.0 - exact.0).abs() < 100.0);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn doppler_shift_relativistic(&self, relative_velocity_mps: f64) -> FrequencyShift {
+        let beta = relative_velocity_mps / SPEED_OF_LIGHT;
+        let hz = self.0 as f64;
+        FrequencyShift(hz * ((1.0 + beta) / (1.0 - beta)).sqrt() - hz)
+    }
+
+    /// Returns the frequency observed by a receiver for a radial velocity in metres per second,
+    /// using the non-relativistic approximation and rounding to the nearest hertz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let observed = Frequency::from_mhz(145).observed_frequency(-6_000.0);
+    /// assert_eq!(observed, Frequency::from_hz(144_997_098));
+    /// ```
+    #[must_use]
+    pub fn observed_frequency(&self, relative_velocity_mps: f64) -> Frequency {
+        *self + self.doppler_shift(relative_velocity_mps)
+    }
+}
@@ -0,0 +1,85 @@
+use embedded_time::rate::{Hertz, Kilohertz, Megahertz};
+
+use crate::{Error, Frequency, KILOHERTZ, MEGAHERTZ};
+
+impl From<Frequency> for Hertz<u64> {
+    fn from(freq: Frequency) -> Self {
+        Self(freq.0)
+    }
+}
+
+impl From<Hertz<u64>> for Frequency {
+    fn from(rate: Hertz<u64>) -> Self {
+        Self(rate.0)
+    }
+}
+
+/// Implements the checked conversion from [`Frequency`] to an embedded-time rate of `$unit`
+/// hertz stored as `$int`, which fails unless the frequency is a whole number of the rate's
+/// unit that fits
+macro_rules! impl_try_into_rate {
+    ($($rate:ident<$int:ty>($unit:expr)),*) => {
+        $(
+            impl TryFrom<Frequency> for $rate<$int> {
+                type Error = Error;
+
+                // The unit is 1 for hertz
+                #[allow(clippy::modulo_one)]
+                fn try_from(freq: Frequency) -> Result<Self, Self::Error> {
+                    if freq.0 % $unit != 0 {
+                        return Err(Error::invalid_value(freq.to_exact_string()));
+                    }
+
+                    <$int>::try_from(freq.0 / $unit)
+                        .map($rate)
+                        .map_err(|_| Error::invalid_value(freq.to_exact_string()))
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the infallible conversion from an embedded-time rate of `$unit` hertz stored as
+/// a `u32` to [`Frequency`]
+macro_rules! impl_from_rate_u32 {
+    ($($rate:ident($unit:expr)),*) => {
+        $(
+            impl From<$rate<u32>> for Frequency {
+                // The unit is 1 for hertz
+                #[allow(clippy::identity_op)]
+                fn from(rate: $rate<u32>) -> Self {
+                    Self(u64::from(rate.0) * $unit)
+                }
+            }
+        )*
+    };
+}
+
+/// Implements the checked conversion from an embedded-time rate of `$unit` hertz stored as a
+/// `u64` to [`Frequency`], which fails if the frequency overflows
+macro_rules! impl_try_from_rate_u64 {
+    ($($rate:ident($unit:expr)),*) => {
+        $(
+            impl TryFrom<$rate<u64>> for Frequency {
+                type Error = Error;
+
+                fn try_from(rate: $rate<u64>) -> Result<Self, Self::Error> {
+                    rate.0
+                        .checked_mul($unit)
+                        .map(Self)
+                        .ok_or_else(|| Error::invalid_value(format_args!("{} * {} Hz", rate.0, $unit)))
+                }
+            }
+        )*
+    };
+}
+
+impl_try_into_rate!(
+    Hertz<u32>(1),
+    Kilohertz<u32>(KILOHERTZ),
+    Kilohertz<u64>(KILOHERTZ),
+    Megahertz<u32>(MEGAHERTZ),
+    Megahertz<u64>(MEGAHERTZ)
+);
+impl_from_rate_u32!(Hertz(1), Kilohertz(KILOHERTZ), Megahertz(MEGAHERTZ));
+impl_try_from_rate_u64!(Kilohertz(KILOHERTZ), Megahertz(MEGAHERTZ));
@@ -1,11 +1,189 @@
-use std::fmt;
+use core::{fmt, hash, ops::Deref};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// The copy of the rejected input an [`Error`] carries
+///
+/// The text is stored inline, so it is available with or without `alloc` and the type is the
+/// same in every configuration. Input longer than [`ErrorText::CAPACITY`] bytes is cut at a
+/// character boundary and ends with `…`.
+///
+/// ```
+/// use parse_frequency::ErrorText;
+///
+/// assert_eq!(ErrorText::from("12 parsecs"), "12 parsecs");
+/// assert!(ErrorText::from("x".repeat(100).as_str()).ends_with('…'));
+/// ```
+#[derive(Clone, Copy)]
+pub struct ErrorText {
+    bytes: [u8; Self::CAPACITY],
+    len: u8,
+    truncated: bool,
+}
+
+impl ErrorText {
+    /// The most bytes of text an [`ErrorText`] keeps
+    pub const CAPACITY: usize = 48;
+
+    const ELLIPSIS: &str = "…";
+
+    /// Returns the text as a string slice
+    pub fn as_str(&self) -> &str {
+        // Only whole characters are ever copied in, so the prefix is always valid UTF-8.
+        core::str::from_utf8(&self.bytes[..usize::from(self.len)]).unwrap_or_default()
+    }
+
+    /// Returns whether the original text did not fit and was cut short
+    pub const fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+
+    fn new(text: impl fmt::Display) -> Self {
+        let mut out = Self {
+            bytes: [0; Self::CAPACITY],
+            len: 0,
+            truncated: false,
+        };
+        // The writer never fails; a full buffer only marks the text as truncated.
+        let _ = fmt::write(&mut Writer(&mut out), format_args!("{text}"));
+        if out.truncated {
+            let mut end = usize::from(out.len).min(Self::CAPACITY - Self::ELLIPSIS.len());
+            while !out.as_str().is_char_boundary(end) {
+                end -= 1;
+            }
+            out.len = end as u8;
+            out.push(Self::ELLIPSIS);
+        }
+        out
+    }
+
+    fn push(&mut self, s: &str) {
+        let start = usize::from(self.len);
+        self.bytes[start..start + s.len()].copy_from_slice(s.as_bytes());
+        self.len += s.len() as u8;
+    }
+}
+
+/// Copies formatted text into an [`ErrorText`] until it is full
+struct Writer<'a>(&'a mut ErrorText);
+
+impl fmt::Write for Writer<'_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        let text = &mut *self.0;
+        if text.truncated {
+            return Ok(());
+        }
+        let room = ErrorText::CAPACITY - usize::from(text.len);
+        if s.len() <= room {
+            text.push(s);
+        } else {
+            let mut end = room;
+            while !s.is_char_boundary(end) {
+                end -= 1;
+            }
+            text.push(&s[..end]);
+            text.truncated = true;
+        }
+        Ok(())
+    }
+}
+
+impl Deref for ErrorText {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl AsRef<str> for ErrorText {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl From<&str> for ErrorText {
+    fn from(text: &str) -> Self {
+        Self::new(text)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl From<String> for ErrorText {
+    fn from(text: String) -> Self {
+        Self::new(text)
+    }
+}
+
+impl fmt::Display for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl fmt::Debug for ErrorText {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl PartialEq for ErrorText {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for ErrorText {}
+
+impl PartialEq<str> for ErrorText {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for ErrorText {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl PartialOrd for ErrorText {
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for ErrorText {
+    fn cmp(&self, other: &Self) -> core::cmp::Ordering {
+        self.as_str().cmp(other.as_str())
+    }
+}
+
+impl hash::Hash for ErrorText {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum Error {
-    UnknownUnit(String),
-    InvalidValue(String),
+    UnknownUnit(ErrorText),
+    InvalidValue(ErrorText),
+}
+
+impl Error {
+    /// Returns an [`Error::UnknownUnit`] for `unit`
+    pub(crate) fn unknown_unit(unit: impl fmt::Display) -> Self {
+        Self::UnknownUnit(ErrorText::new(unit))
+    }
+
+    /// Returns an [`Error::InvalidValue`] for `value`
+    pub(crate) fn invalid_value(value: impl fmt::Display) -> Self {
+        Self::InvalidValue(ErrorText::new(value))
+    }
 }
 
 impl fmt::Display for Error {
@@ -17,4 +195,4 @@ impl fmt::Display for Error {
     }
 }
 
-impl std::error::Error for Error {}
+impl core::error::Error for Error {}
@@ -0,0 +1,162 @@
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use crate::Frequency;
+
+/// How a [`RateEstimator`] smooths the measured intervals
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Smoothing {
+    /// Only events within this duration of the most recent event are counted
+    Window(Duration),
+
+    /// Exponentially weighted moving average of the intervals with this weight for new samples
+    Ewma(f64),
+}
+
+/// Estimates the rate of recurring events (packets, interrupts, requests, ...) from their
+/// timestamps
+///
+/// Events are fed in with [`RateEstimator::record`] or, when only the time between events is
+/// known, [`RateEstimator::record_interval`]. At least two events are needed before a rate
+/// can be reported.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{Frequency, RateEstimator};
+///
+/// let mut estimator = RateEstimator::sliding_window(Duration::from_secs(1));
+/// for _ in 0..100 {
+///     estimator.record_interval(Duration::from_millis(4));
+/// }
+///
+/// assert_eq!(estimator.rate(), Some(Frequency::from_hz(250)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateEstimator {
+    smoothing: Smoothing,
+
+    /// Instant of the first recorded event, used as the origin for offsets
+    origin: Option<Instant>,
+
+    /// Offsets of the events within the window, relative to `origin`
+    events: VecDeque<Duration>,
+
+    /// Smoothed interval between events in seconds
+    average: Option<f64>,
+}
+
+impl RateEstimator {
+    /// Creates an estimator that averages over the events within `window` of the most recent
+    /// one.
+    #[must_use]
+    pub fn sliding_window(window: Duration) -> Self {
+        Self::new(Smoothing::Window(window))
+    }
+
+    /// Creates an estimator that tracks an exponentially weighted moving average of the
+    /// intervals between events. Each new interval is weighted by `alpha`, which is clamped to
+    /// `0..=1`; smaller values react slower but are less noisy.
+    #[must_use]
+    pub fn ewma(alpha: f64) -> Self {
+        let alpha = if alpha.is_nan() {
+            1.0
+        } else {
+            alpha.clamp(0.0, 1.0)
+        };
+        Self::new(Smoothing::Ewma(alpha))
+    }
+
+    fn new(smoothing: Smoothing) -> Self {
+        Self {
+            smoothing,
+            origin: None,
+            events: VecDeque::new(),
+            average: None,
+        }
+    }
+
+    /// Records an event that happened at `instant`. Instants earlier than the previous event
+    /// are treated as simultaneous with it.
+    pub fn record(&mut self, instant: Instant) {
+        let origin = *self.origin.get_or_insert(instant);
+        let offset = instant.saturating_duration_since(origin);
+        self.push(offset.max(self.latest().unwrap_or_default()));
+    }
+
+    /// Records an event that happened `interval` after the previous one. The first call only
+    /// marks the starting point.
+    pub fn record_interval(&mut self, interval: Duration) {
+        let offset = self
+            .latest()
+            .map_or(Duration::ZERO, |latest| latest.saturating_add(interval));
+        self.push(offset);
+    }
+
+    /// Forgets all recorded events.
+    pub fn reset(&mut self) {
+        self.origin = None;
+        self.events.clear();
+        self.average = None;
+    }
+
+    /// Returns the estimated rate in events per second, or `None` if fewer than two events
+    /// were recorded (or all of them happened at the same time).
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn rate_hz(&self) -> Option<f64> {
+        let interval = match self.smoothing {
+            Smoothing::Window(_) => {
+                let span = self.latest()?.checked_sub(*self.events.front()?)?;
+                span.as_secs_f64() / (self.events.len() - 1) as f64
+            }
+            Smoothing::Ewma(_) => self.average?,
+        };
+
+        (interval > 0.0).then(|| 1.0 / interval)
+    }
+
+    /// Returns the estimated rate rounded to the nearest hertz, or `None` if fewer than two
+    /// events were recorded (or all of them happened at the same time).
+    #[must_use]
+    // The rate is never negative and saturates at u64::MAX
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn rate(&self) -> Option<Frequency> {
+        self.rate_hz().map(|hz| Frequency(hz.round() as u64))
+    }
+
+    fn latest(&self) -> Option<Duration> {
+        self.events.back().copied()
+    }
+
+    fn push(&mut self, offset: Duration) {
+        let previous = self.latest();
+        self.events.push_back(offset);
+
+        match self.smoothing {
+            Smoothing::Window(window) => {
+                let start = offset.saturating_sub(window);
+                while self.events.front().is_some_and(|&event| event < start) {
+                    self.events.pop_front();
+                }
+            }
+            Smoothing::Ewma(alpha) => {
+                // Only the latest event is needed to measure the next interval
+                if self.events.len() > 1 {
+                    self.events.pop_front();
+                }
+
+                if let Some(previous) = previous {
+                    let interval = (offset - previous).as_secs_f64();
+                    self.average = Some(self.average.map_or(interval, |average| {
+                        alpha * interval + (1.0 - alpha) * average
+                    }));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+//! C bindings for the parser and formatter
+//!
+//! With the `ffi` feature, firmware and drivers written in C or C++ can link against the crate
+//! and share its parser. The functions use only C types and are named with a `pf_` prefix, so
+//! `cbindgen` can generate a header for them directly:
+//!
+//! ```c
+//! uint64_t hz;
+//! if (pf_parse("2.4 GHz", &hz) == PF_OK) {
+//!     char buf[32];
+//!     pf_format(hz, buf, sizeof buf); // "2.40 GHz"
+//! }
+//! ```
+//!
+//! [`Frequency`] is `#[repr(transparent)]` over a `u64`, so Rust code on the other side of the
+//! boundary can pass a `Frequency` wherever C expects a `uint64_t` number of hertz.
+
+use std::{
+    ffi::{CStr, c_char, c_int},
+    ptr,
+};
+
+use crate::{Error, Frequency};
+
+const _: () = assert!(size_of::<Frequency>() == size_of::<u64>());
+const _: () = assert!(align_of::<Frequency>() == align_of::<u64>());
+
+/// The call succeeded
+pub const PF_OK: c_int = 0;
+
+/// A required pointer argument was null
+pub const PF_ERR_NULL: c_int = -1;
+
+/// The input string is not valid UTF-8
+pub const PF_ERR_UTF8: c_int = -2;
+
+/// The input string has an unknown unit, see [`Error::UnknownUnit`]
+pub const PF_ERR_UNKNOWN_UNIT: c_int = -3;
+
+/// The input string has an invalid or out of range value, see [`Error::InvalidValue`]
+pub const PF_ERR_INVALID_VALUE: c_int = -4;
+
+/// Parses a nul-terminated frequency string such as `"2.4 GHz"` into a number of hertz.
+///
+/// Returns [`PF_OK`] and writes the frequency to `out` on success, or one of the `PF_ERR_*`
+/// codes and leaves `out` untouched on failure.
+///
+/// # Safety
+///
+/// `s` must be null or point to a nul-terminated string, and `out` must be null or valid for a
+/// write of a `u64`.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pf_parse(s: *const c_char, out: *mut u64) -> c_int {
+    if s.is_null() || out.is_null() {
+        return PF_ERR_NULL;
+    }
+
+    // SAFETY: `s` is non-null and nul-terminated per the contract above
+    let Ok(s) = unsafe { CStr::from_ptr(s) }.to_str() else {
+        return PF_ERR_UTF8;
+    };
+
+    match s.parse::<Frequency>() {
+        Ok(freq) => {
+            // SAFETY: `out` is non-null and valid for writes per the contract above
+            unsafe { out.write(freq.0) };
+            PF_OK
+        }
+        Err(Error::UnknownUnit(_)) => PF_ERR_UNKNOWN_UNIT,
+        Err(Error::InvalidValue(_)) => PF_ERR_INVALID_VALUE,
+    }
+}
+
+/// Formats a number of hertz like `"2.40 GHz"` into `buf`, like `snprintf`.
+///
+/// Writes at most `len` bytes including the nul terminator, truncating the string if needed,
+/// and returns the length of the full string without the terminator. The output was truncated
+/// when the return value is `len` or more. `buf` may be null when `len` is zero, to query the
+/// required size.
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pf_format(hz: u64, buf: *mut c_char, len: usize) -> usize {
+    // SAFETY: forwarded from the contract above
+    unsafe { write_c_str(&Frequency(hz).to_string(), buf, len) }
+}
+
+/// Formats a number of hertz exactly like `"2.400000001 GHz"` into `buf`, like [`pf_format`].
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn pf_format_exact(hz: u64, buf: *mut c_char, len: usize) -> usize {
+    // SAFETY: forwarded from the contract above
+    unsafe { write_c_str(&Frequency(hz).to_exact_string(), buf, len) }
+}
+
+/// Copies as much of `s` as fits into `buf` followed by a nul, and returns the length of `s`
+///
+/// # Safety
+///
+/// `buf` must be valid for writes of `len` bytes.
+unsafe fn write_c_str(s: &str, buf: *mut c_char, len: usize) -> usize {
+    if buf.is_null() || len == 0 {
+        return s.len();
+    }
+
+    let count = s.len().min(len - 1);
+    // SAFETY: `count + 1 <= len` bytes are written, and `s` cannot overlap a C buffer
+    unsafe {
+        ptr::copy_nonoverlapping(s.as_ptr().cast::<c_char>(), buf, count);
+        buf.add(count).write(0);
+    }
+
+    s.len()
+}
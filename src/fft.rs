@@ -0,0 +1,92 @@
+use crate::{Frequency, SampleRate};
+
+/// Returns the center frequency of `bin` in an FFT of `fft_size` points at `sample_rate`.
+///
+/// The result (`bin * sample_rate / fft_size`) is rounded to the nearest hertz. An `fft_size` of
+/// zero results in [`Frequency::ZERO`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, SampleRate, fft_bin_frequency};
+///
+/// assert_eq!(fft_bin_frequency(SampleRate::KHZ_48, 1024, 1), Frequency::from_hz(47));
+/// assert_eq!(fft_bin_frequency(SampleRate::KHZ_48, 1024, 512), Frequency::from_khz(24));
+/// ```
+#[must_use]
+// Saturating at u64::MAX is acceptable here
+#[allow(clippy::cast_possible_truncation)]
+pub fn fft_bin_frequency(
+    sample_rate: impl Into<SampleRate>,
+    fft_size: usize,
+    bin: usize,
+) -> Frequency {
+    let rate = u128::from(sample_rate.into().as_hz());
+    let size = fft_size as u128;
+
+    if size == 0 {
+        return Frequency::ZERO;
+    }
+
+    let hz = (bin as u128 * rate + size / 2) / size;
+    Frequency(hz.min(u128::from(u64::MAX)) as u64)
+}
+
+/// Returns the index of the FFT bin whose center is closest to `freq`.
+///
+/// Only the one-sided spectrum (bins `0..=fft_size / 2`) is considered. Returns `None` if the
+/// sample rate or `fft_size` is zero, or if `freq` lies above the Nyquist frequency.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, SampleRate, bin_for_frequency};
+///
+/// let bin = bin_for_frequency(SampleRate::KHZ_48, 1024, Frequency::from_khz(1));
+/// assert_eq!(bin, Some(21));
+///
+/// let bin = bin_for_frequency(SampleRate::KHZ_48, 1024, Frequency::from_khz(30));
+/// assert_eq!(bin, None);
+/// ```
+#[must_use]
+// Bins are bounded by fft_size, so truncation cannot occur
+#[allow(clippy::cast_possible_truncation)]
+pub fn bin_for_frequency(
+    sample_rate: impl Into<SampleRate>,
+    fft_size: usize,
+    freq: Frequency,
+) -> Option<usize> {
+    let sample_rate = sample_rate.into();
+    let rate = u128::from(sample_rate.as_hz());
+    let size = fft_size as u128;
+
+    if rate == 0 || size == 0 || freq > sample_rate.nyquist() {
+        return None;
+    }
+
+    let bin = (u128::from(freq.as_hz()) * size + rate / 2) / rate;
+    Some((bin as usize).min(fft_size / 2))
+}
+
+/// Returns an iterator over the center frequencies of the one-sided spectrum of an FFT of
+/// `fft_size` points at `sample_rate` (bins `0..=fft_size / 2`).
+///
+/// Each frequency is rounded to the nearest hertz, as with [`fft_bin_frequency`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, SampleRate, fft_bin_frequencies};
+///
+/// let bins: Vec<Frequency> = fft_bin_frequencies(SampleRate::KHZ_8, 8).collect();
+/// assert_eq!(bins, [0, 1000, 2000, 3000, 4000].map(Frequency::from_hz));
+/// ```
+pub fn fft_bin_frequencies(
+    sample_rate: impl Into<SampleRate>,
+    fft_size: usize,
+) -> impl Iterator<Item = Frequency> {
+    let sample_rate = sample_rate.into();
+    let bins = if fft_size == 0 { 0 } else { fft_size / 2 + 1 };
+
+    (0..bins).map(move |bin| fft_bin_frequency(sample_rate, fft_size, bin))
+}
@@ -0,0 +1,217 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+    time::Duration,
+};
+
+use crate::{
+    Error, Frequency, GIGAHERTZ, KILOHERTZ, MEGAHERTZ, Result, Rounding, fmt_scaled, parse_hz_with,
+};
+
+/// Represents a frequency in hertz as a floating point number
+///
+/// Useful where fractional hertz and fast float math matter more than exactness, such as in
+/// DSP pipelines. It parses and formats like [`Frequency`], but keeps the fractional part.
+/// Converting to and from [`Frequency`] is explicit, as it can round or lose precision.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyF64, Rounding};
+///
+/// let tone: FrequencyF64 = "440.5 Hz".parse().unwrap();
+/// assert_eq!(tone.as_hz(), 440.5);
+/// assert_eq!(tone.to_string(), "440.50 Hz");
+///
+/// let harmonic = tone * 3.0;
+/// assert_eq!(harmonic.to_string(), "1.32 kHz");
+///
+/// assert_eq!(tone.to_frequency(Rounding::Down), Frequency::from_hz(440));
+/// assert_eq!(FrequencyF64::from_frequency(Frequency::from_khz(1)).as_khz(), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct FrequencyF64(pub f64);
+
+impl FrequencyF64 {
+    /// Equivalent to `0 Hz`
+    pub const ZERO: Self = Self(0.0);
+
+    /// Equivalent to `1 Hz`
+    pub const HERTZ: Self = Self(1.0);
+
+    #[must_use]
+    #[doc(alias = "from_hertz")]
+    pub fn from_hz(hz: f64) -> Self {
+        Self(hz)
+    }
+
+    #[must_use]
+    #[doc(alias = "from_kilohertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_khz(khz: f64) -> Self {
+        Self(khz * KILOHERTZ as f64)
+    }
+
+    #[must_use]
+    #[doc(alias = "from_megahertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_mhz(mhz: f64) -> Self {
+        Self(mhz * MEGAHERTZ as f64)
+    }
+
+    #[must_use]
+    #[doc(alias = "from_gigahertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_ghz(ghz: f64) -> Self {
+        Self(ghz * GIGAHERTZ as f64)
+    }
+
+    /// Converts an integer frequency, losing precision above 2^53 Hz.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn from_frequency(freq: Frequency) -> Self {
+        Self(freq.0 as f64)
+    }
+
+    #[must_use]
+    #[doc(alias = "as_hertz")]
+    pub fn as_hz(&self) -> f64 {
+        self.0
+    }
+
+    #[must_use]
+    #[doc(alias = "as_kilohertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_khz(&self) -> f64 {
+        self.0 / KILOHERTZ as f64
+    }
+
+    #[must_use]
+    #[doc(alias = "as_megahertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_mhz(&self) -> f64 {
+        self.0 / MEGAHERTZ as f64
+    }
+
+    #[must_use]
+    #[doc(alias = "as_gigahertz")]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_ghz(&self) -> f64 {
+        self.0 / GIGAHERTZ as f64
+    }
+
+    /// Returns the period of the frequency, or [`Duration::ZERO`] if the frequency is not
+    /// positive or the period does not fit in a `Duration`.
+    #[must_use]
+    pub fn as_duration(&self) -> Duration {
+        Duration::try_from_secs_f64(self.0.recip()).unwrap_or(Duration::ZERO)
+    }
+
+    /// Converts to a whole number of hertz, rounding as requested.
+    ///
+    /// Negative and NaN values result in `0 Hz`, values above `u64::MAX` saturate.
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn to_frequency(&self, rounding: Rounding) -> Frequency {
+        let hz = match rounding {
+            Rounding::Down => self.0.floor(),
+            Rounding::Nearest => self.0.round(),
+            Rounding::Up => self.0.ceil(),
+        };
+
+        Frequency(hz as u64)
+    }
+
+    /// Converts to a whole number of hertz, rounding as requested, or returns `None` if the
+    /// rounded value is negative, NaN or does not fit in a `u64`.
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyF64, Rounding};
+    ///
+    /// let freq = FrequencyF64::from_hz(2_400.5);
+    /// assert_eq!(freq.try_to_frequency(Rounding::Nearest), Some(Frequency::from_hz(2_401)));
+    /// assert_eq!(FrequencyF64::from_hz(-1.0).try_to_frequency(Rounding::Nearest), None);
+    /// assert_eq!(FrequencyF64::from_hz(1e20).try_to_frequency(Rounding::Down), None);
+    /// ```
+    #[must_use]
+    // Out of range values are rejected, and `u64::MAX as f64` rounds up to 2^64, the first
+    // whole number that does not fit
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn try_to_frequency(&self, rounding: Rounding) -> Option<Frequency> {
+        let hz = match rounding {
+            Rounding::Down => self.0.floor(),
+            Rounding::Nearest => self.0.round(),
+            Rounding::Up => self.0.ceil(),
+        };
+
+        (!hz.is_nan() && hz >= 0.0 && hz < u64::MAX as f64).then_some(Frequency(hz as u64))
+    }
+}
+
+impl Add for FrequencyF64 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for FrequencyF64 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<f64> for FrequencyF64 {
+    type Output = Self;
+
+    fn mul(self, rhs: f64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<f64> for FrequencyF64 {
+    type Output = Self;
+
+    fn div(self, rhs: f64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Display for FrequencyF64 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fmt_scaled(f, self.0).unwrap_or_else(|| write!(f, "{:.2} Hz", self.0))
+    }
+}
+
+impl FromStr for FrequencyF64 {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_hz_with(s, |hz, _, _| Some(Self(hz)))
+    }
+}
+
+impl TryFrom<&str> for FrequencyF64 {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
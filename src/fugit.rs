@@ -0,0 +1,100 @@
+use fugit::{Duration, Rate};
+
+use crate::{Error, Frequency};
+
+/// Implements the conversions between [`Frequency`] and fugit rates and durations stored as
+/// `$int`
+///
+/// A `Rate<_, NOM, DENOM>` counts units of `NOM / DENOM` hertz, while a
+/// `Duration<_, NOM, DENOM>` counts ticks of `NOM / DENOM` seconds.
+macro_rules! impl_fugit {
+    ($($int:ty),*) => {
+        $(
+            impl<const NOM: u32, const DENOM: u32> TryFrom<Frequency> for Rate<$int, NOM, DENOM> {
+                type Error = Error;
+
+                /// Converts a frequency to a rate, failing unless it is a whole number of rate
+                /// units that fits in the rate.
+                fn try_from(freq: Frequency) -> Result<Self, Self::Error> {
+                    let (nom, denom) = (u128::from(NOM), u128::from(DENOM));
+                    let units = u128::from(freq.0) * denom;
+                    if nom == 0 || units % nom != 0 {
+                        return Err(Error::invalid_value(freq.to_exact_string()));
+                    }
+
+                    <$int>::try_from(units / nom)
+                        .map(Self::from_raw)
+                        .map_err(|_| Error::invalid_value(freq.to_exact_string()))
+                }
+            }
+
+            impl<const NOM: u32, const DENOM: u32> TryFrom<Rate<$int, NOM, DENOM>> for Frequency {
+                type Error = Error;
+
+                /// Converts a rate to a frequency, failing unless it is a whole number of hertz
+                /// that fits in a `u64`.
+                fn try_from(rate: Rate<$int, NOM, DENOM>) -> Result<Self, Self::Error> {
+                    let (nom, denom) = (u128::from(NOM), u128::from(DENOM));
+                    let hz = u128::from(rate.raw()) * nom;
+                    let invalid = || Error::invalid_value(format_args!("{} * {NOM}/{DENOM} Hz", rate.raw()));
+                    if denom == 0 || hz % denom != 0 {
+                        return Err(invalid());
+                    }
+
+                    u64::try_from(hz / denom).map(Self).map_err(|_| invalid())
+                }
+            }
+
+            impl<const NOM: u32, const DENOM: u32> TryFrom<Frequency>
+                for Duration<$int, NOM, DENOM>
+            {
+                type Error = Error;
+
+                /// Converts a frequency to its period, rounded to the nearest tick.
+                ///
+                /// Fails for `0 Hz`, for periods that round to zero ticks and for periods that
+                /// do not fit.
+                fn try_from(freq: Frequency) -> Result<Self, Self::Error> {
+                    let invalid = || Error::invalid_value(freq.to_exact_string());
+                    let divisor = u128::from(NOM) * u128::from(freq.0);
+                    if divisor == 0 {
+                        return Err(invalid());
+                    }
+
+                    let ticks = (u128::from(DENOM) + divisor / 2) / divisor;
+                    match <$int>::try_from(ticks) {
+                        Ok(0) | Err(_) => Err(invalid()),
+                        Ok(ticks) => Ok(Self::from_ticks(ticks)),
+                    }
+                }
+            }
+
+            impl<const NOM: u32, const DENOM: u32> TryFrom<Duration<$int, NOM, DENOM>>
+                for Frequency
+            {
+                type Error = Error;
+
+                /// Converts a period to its frequency, rounded to the nearest hertz.
+                ///
+                /// Fails for zero periods and for periods that round to `0 Hz`.
+                fn try_from(period: Duration<$int, NOM, DENOM>) -> Result<Self, Self::Error> {
+                    let invalid = || {
+                        Error::invalid_value(format_args!("{} * {NOM}/{DENOM} s", period.ticks()))
+                    };
+                    let divisor = u128::from(NOM) * u128::from(period.ticks());
+                    if divisor == 0 {
+                        return Err(invalid());
+                    }
+
+                    let hz = (u128::from(DENOM) + divisor / 2) / divisor;
+                    match u64::try_from(hz) {
+                        Ok(0) | Err(_) => Err(invalid()),
+                        Ok(hz) => Ok(Self(hz)),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_fugit!(u32, u64);
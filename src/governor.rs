@@ -0,0 +1,97 @@
+use std::num::NonZeroU32;
+
+use governor::Quota;
+
+use crate::{Error, Frequency};
+
+impl Frequency {
+    /// Returns a quota that allows this rate, with bursts of up to `burst` cells.
+    ///
+    /// Cells replenish every [`Frequency::interval`], which is rounded up so the rate is never
+    /// exceeded. Returns `None` for `0 Hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::{num::NonZeroU32, time::Duration};
+    /// use parse_frequency::Frequency;
+    ///
+    /// let limit: Frequency = "100 Hz".parse().unwrap();
+    /// let quota = limit.to_quota_with_burst(NonZeroU32::new(5).unwrap()).unwrap();
+    ///
+    /// assert_eq!(quota.replenish_interval(), Duration::from_millis(10));
+    /// assert_eq!(quota.burst_size().get(), 5);
+    /// ```
+    #[must_use]
+    pub fn to_quota_with_burst(&self, burst: NonZeroU32) -> Option<Quota> {
+        if self.0 == 0 {
+            return None;
+        }
+
+        Quota::with_period(self.interval()).map(|quota| quota.allow_burst(burst))
+    }
+
+    /// Returns a quota that allows this rate, with bursts of up to one second's worth of cells
+    /// like `Quota::per_second`.
+    ///
+    /// Returns `None` for `0 Hz` and for bursts that do not fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let quota = Frequency::from_hz(50).to_quota_per_second().unwrap();
+    /// assert_eq!(quota.burst_size().get(), 50);
+    /// ```
+    #[must_use]
+    pub fn to_quota_per_second(&self) -> Option<Quota> {
+        self.to_quota_with_burst(burst_size(self.0)?)
+    }
+
+    /// Returns a quota that allows this rate, with bursts of up to one minute's worth of cells
+    /// like `Quota::per_minute`.
+    ///
+    /// Returns `None` for `0 Hz` and for bursts that do not fit in a `u32`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let quota = Frequency::from_hz(2).to_quota_per_minute().unwrap();
+    /// assert_eq!(quota.burst_size().get(), 120);
+    /// ```
+    #[must_use]
+    pub fn to_quota_per_minute(&self) -> Option<Quota> {
+        self.to_quota_with_burst(burst_size(self.0.checked_mul(60)?)?)
+    }
+}
+
+/// Converts a number of cells to a burst size, if it is non-zero and fits in a `u32`
+fn burst_size(cells: u64) -> Option<NonZeroU32> {
+    u32::try_from(cells).ok().and_then(NonZeroU32::new)
+}
+
+impl TryFrom<Frequency> for Quota {
+    type Error = Error;
+
+    /// Converts a rate limit to a quota with bursts of one second's worth of cells, see
+    /// [`Frequency::to_quota_per_second`].
+    fn try_from(freq: Frequency) -> Result<Self, Self::Error> {
+        freq.to_quota_per_second()
+            .ok_or_else(|| Error::invalid_value(freq))
+    }
+}
+
+impl TryFrom<Quota> for Frequency {
+    type Error = Error;
+
+    /// Converts a quota to the rate its cells replenish at, rounded to the nearest hertz.
+    ///
+    /// The burst size does not affect the rate. Fails for quotas that replenish less than once
+    /// every 2 seconds, whose rate rounds to `0 Hz`.
+    fn try_from(quota: Quota) -> Result<Self, Self::Error> {
+        Self::try_from(quota.replenish_interval())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{Frequency, FrequencyRange, Rounding};
+
+/// An evenly spaced raster of radio channels: `count` channels starting at `base`, `step`
+/// apart
+///
+/// Channels are numbered consecutively starting at `first_channel`, which is `0` unless set
+/// with [`ChannelGrid::with_first_channel`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{ChannelGrid, Frequency};
+///
+/// // PMR446: 16 channels from 446.00625 MHz in 12.5 kHz steps, numbered from 1
+/// let pmr = ChannelGrid::new(Frequency::from_hz(446_006_250), Frequency::from_hz(12_500), 16)
+///     .with_first_channel(1);
+///
+/// assert_eq!(pmr.channel_to_frequency(8), Some(Frequency::from_hz(446_093_750)));
+/// assert_eq!(pmr.frequency_to_channel(Frequency::from_hz(446_093_750)), Some(8));
+/// assert_eq!(pmr.nearest_channel(Frequency::from_khz(446_095)), Some(8));
+/// assert_eq!(pmr.channel_to_frequency(17), None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ChannelGrid {
+    /// Frequency of the first channel
+    pub base: Frequency,
+
+    /// Spacing between adjacent channels
+    pub step: Frequency,
+
+    /// Number of channels in the grid
+    pub count: u32,
+
+    /// Number of the first channel
+    pub first_channel: u32,
+}
+
+impl ChannelGrid {
+    /// Creates a grid of `count` channels starting at `base`, numbered from `0`.
+    #[must_use]
+    pub fn new(base: Frequency, step: Frequency, count: u32) -> Self {
+        Self {
+            base,
+            step,
+            count,
+            first_channel: 0,
+        }
+    }
+
+    /// Returns a copy of this grid with channels numbered from `first_channel`.
+    #[must_use]
+    pub fn with_first_channel(self, first_channel: u32) -> Self {
+        Self {
+            first_channel,
+            ..self
+        }
+    }
+
+    /// Returns the frequency of channel `channel`, or `None` if the grid has no such channel.
+    #[must_use]
+    pub fn channel_to_frequency(&self, channel: u32) -> Option<Frequency> {
+        let index = channel.checked_sub(self.first_channel)?;
+        if index >= self.count {
+            return None;
+        }
+
+        let offset = self.step.0.checked_mul(u64::from(index))?;
+        self.base.0.checked_add(offset).map(Frequency)
+    }
+
+    /// Returns the channel whose frequency is exactly `freq`, or `None` if `freq` is not on the
+    /// grid.
+    #[must_use]
+    pub fn frequency_to_channel(&self, freq: Frequency) -> Option<u32> {
+        let channel = self.nearest_channel(freq)?;
+        (self.channel_to_frequency(channel)? == freq).then_some(channel)
+    }
+
+    /// Returns the channel closest to `freq`. Frequencies outside the grid snap to the first or
+    /// last channel, and ties go to the higher channel.
+    ///
+    /// Returns `None` if the grid has no channels.
+    #[must_use]
+    // The index is clamped to the channel count, which fits in a u32
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn nearest_channel(&self, freq: Frequency) -> Option<u32> {
+        let last = self.count.checked_sub(1)?;
+        let offset = u128::from(freq.0.saturating_sub(self.base.0));
+        let index = Rounding::Nearest
+            .div(offset, u128::from(self.step.0))
+            .unwrap_or(0)
+            .min(u128::from(last)) as u32;
+
+        self.first_channel.checked_add(index)
+    }
+
+    /// Returns the frequency of the channel closest to `freq`, or `None` if the grid has no
+    /// channels.
+    #[must_use]
+    pub fn snap(&self, freq: Frequency) -> Option<Frequency> {
+        self.channel_to_frequency(self.nearest_channel(freq)?)
+    }
+
+    /// Returns `true` if `freq` is exactly the frequency of one of the channels.
+    #[must_use]
+    pub fn contains(&self, freq: Frequency) -> bool {
+        self.frequency_to_channel(freq).is_some()
+    }
+
+    /// Returns the frequencies from the first to the last channel, or `None` if the grid has no
+    /// channels.
+    #[must_use]
+    pub fn range(&self) -> Option<FrequencyRange> {
+        let last = self.first_channel.checked_add(self.count.checked_sub(1)?)?;
+        Some(FrequencyRange::new(
+            self.base,
+            self.channel_to_frequency(last)?,
+        ))
+    }
+
+    /// Returns an iterator over every channel number and its frequency, in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, Frequency)> + '_ {
+        (0..self.count).map_while(|index| {
+            let channel = self.first_channel.checked_add(index)?;
+            Some((channel, self.channel_to_frequency(channel)?))
+        })
+    }
+}
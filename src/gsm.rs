@@ -0,0 +1,119 @@
+use core::fmt::Display;
+
+use crate::{Frequency, KILOHERTZ};
+
+/// Spacing between adjacent GSM channels in kilohertz
+const CHANNEL_SPACING_KHZ: u64 = 200;
+
+/// A GSM frequency band per 3GPP TS 45.005
+///
+/// ARFCN ranges overlap between some bands (e.g. 512 is a valid channel in both DCS 1800 and
+/// PCS 1900), so conversions always need the band.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum GsmBand {
+    /// GSM 450, ARFCN 259 to 293
+    Gsm450,
+
+    /// GSM 480, ARFCN 306 to 340
+    Gsm480,
+
+    /// GSM 850, ARFCN 128 to 251
+    Gsm850,
+
+    /// Primary GSM 900, ARFCN 1 to 124
+    PGsm900,
+
+    /// Extended GSM 900, ARFCN 0 to 124 and 975 to 1023
+    EGsm900,
+
+    /// Railway GSM 900, ARFCN 0 to 124 and 955 to 1023
+    RGsm900,
+
+    /// DCS 1800, ARFCN 512 to 885
+    Dcs1800,
+
+    /// PCS 1900, ARFCN 512 to 810
+    Pcs1900,
+}
+
+impl GsmBand {
+    /// Returns the spacing between the uplink and downlink frequencies of a channel.
+    #[must_use]
+    pub fn duplex_spacing(self) -> Frequency {
+        Frequency::from_mhz(match self {
+            GsmBand::Gsm450 | GsmBand::Gsm480 => 10,
+            GsmBand::Gsm850 | GsmBand::PGsm900 | GsmBand::EGsm900 | GsmBand::RGsm900 => 45,
+            GsmBand::Dcs1800 => 95,
+            GsmBand::Pcs1900 => 80,
+        })
+    }
+
+    /// Returns the uplink frequency in kilohertz, or `None` if `arfcn` is not in this band.
+    fn uplink_khz(self, arfcn: u16) -> Option<u64> {
+        let n = u64::from(arfcn);
+        let channel = |first: u64, first_khz: u64| first_khz + CHANNEL_SPACING_KHZ * (n - first);
+
+        match (self, arfcn) {
+            (GsmBand::Gsm450, 259..=293) => Some(channel(259, 450_600)),
+            (GsmBand::Gsm480, 306..=340) => Some(channel(306, 479_000)),
+            (GsmBand::Gsm850, 128..=251) => Some(channel(128, 824_200)),
+            (GsmBand::PGsm900, 1..=124) | (GsmBand::EGsm900 | GsmBand::RGsm900, 0..=124) => {
+                Some(channel(0, 890_000))
+            }
+            (GsmBand::EGsm900, 975..=1023) | (GsmBand::RGsm900, 955..=1023) => {
+                // These channels sit below channel 0, counting down from 1024
+                Some(890_000 - CHANNEL_SPACING_KHZ * (1024 - n))
+            }
+            (GsmBand::Dcs1800, 512..=885) => Some(channel(512, 1_710_200)),
+            (GsmBand::Pcs1900, 512..=810) => Some(channel(512, 1_850_200)),
+            _ => None,
+        }
+    }
+}
+
+impl Display for GsmBand {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let name = match self {
+            GsmBand::Gsm450 => "GSM 450",
+            GsmBand::Gsm480 => "GSM 480",
+            GsmBand::Gsm850 => "GSM 850",
+            GsmBand::PGsm900 => "P-GSM 900",
+            GsmBand::EGsm900 => "E-GSM 900",
+            GsmBand::RGsm900 => "R-GSM 900",
+            GsmBand::Dcs1800 => "DCS 1800",
+            GsmBand::Pcs1900 => "PCS 1900",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// Converts a GSM channel number (ARFCN) in `band` to its uplink (mobile transmit) frequency.
+///
+/// Returns `None` if `arfcn` is not a channel of `band`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, GsmBand, arfcn_to_downlink, arfcn_to_uplink};
+///
+/// assert_eq!(arfcn_to_uplink(GsmBand::PGsm900, 62), Some(Frequency::from_khz(902_400)));
+/// assert_eq!(arfcn_to_downlink(GsmBand::PGsm900, 62), Some(Frequency::from_khz(947_400)));
+///
+/// // The same ARFCN means different frequencies in DCS 1800 and PCS 1900
+/// assert_eq!(arfcn_to_uplink(GsmBand::Dcs1800, 600), Some(Frequency::from_khz(1_727_800)));
+/// assert_eq!(arfcn_to_uplink(GsmBand::Pcs1900, 600), Some(Frequency::from_khz(1_867_800)));
+/// ```
+#[must_use]
+pub fn arfcn_to_uplink(band: GsmBand, arfcn: u16) -> Option<Frequency> {
+    band.uplink_khz(arfcn).map(|khz| Frequency(khz * KILOHERTZ))
+}
+
+/// Converts a GSM channel number (ARFCN) in `band` to its downlink (base station transmit)
+/// frequency.
+///
+/// Returns `None` if `arfcn` is not a channel of `band`.
+#[must_use]
+pub fn arfcn_to_downlink(band: GsmBand, arfcn: u16) -> Option<Frequency> {
+    arfcn_to_uplink(band, arfcn).map(|uplink| uplink + band.duplex_spacing())
+}
@@ -0,0 +1,69 @@
+use crate::Frequency;
+
+impl Frequency {
+    /// Formats the frequency like its `Display` implementation into a `heapless::String`, for
+    /// targets without an allocator.
+    ///
+    /// `N` must be at least [`Frequency::MAX_DISPLAY_LEN`], which is checked at compile time,
+    /// so the output always fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let s = Frequency::from_mhz(2_500).to_heapless_string::<{ Frequency::MAX_DISPLAY_LEN }>();
+    /// assert_eq!(s, "2.50 GHz");
+    /// ```
+    ///
+    /// Buffers that are too small are rejected:
+    ///
+    /// ```rust,compile_fail
+    /// use parse_frequency::Frequency;
+    ///
+    /// let s = Frequency::from_mhz(2_500).to_heapless_string::<8>();
+    /// ```
+    #[must_use]
+    pub fn to_heapless_string<const N: usize>(&self) -> heapless::String<N> {
+        const {
+            assert!(
+                N >= Frequency::MAX_DISPLAY_LEN,
+                "the buffer must fit Frequency::MAX_DISPLAY_LEN bytes"
+            );
+        }
+
+        let mut s = heapless::String::new();
+        // The buffer fits the longest output
+        let _ = self.format_into(&mut s);
+        s
+    }
+
+    /// Formats the frequency exactly like [`Frequency::format_exact_into`] into a
+    /// `heapless::String`.
+    ///
+    /// `N` must be at least [`Frequency::MAX_EXACT_LEN`], which is checked at compile time, so
+    /// the output always fits.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let s = Frequency::from_hz(2_400_000_001).to_heapless_exact_string::<32>();
+    /// assert_eq!(s, "2.400000001 GHz");
+    /// ```
+    #[must_use]
+    pub fn to_heapless_exact_string<const N: usize>(&self) -> heapless::String<N> {
+        const {
+            assert!(
+                N >= Frequency::MAX_EXACT_LEN,
+                "the buffer must fit Frequency::MAX_EXACT_LEN bytes"
+            );
+        }
+
+        let mut s = heapless::String::new();
+        // The buffer fits the longest output
+        let _ = self.format_exact_into(&mut s);
+        s
+    }
+}
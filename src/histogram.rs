@@ -0,0 +1,165 @@
+use crate::{Frequency, FrequencyRange};
+
+/// A histogram of frequencies with logarithmically spaced bins
+///
+/// Each bin spans the same ratio of frequencies, so a histogram from 1 Hz to 1 MHz with six bins
+/// has one bin per decade. Samples outside the range are counted separately as underflow and
+/// overflow.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyHistogram, FrequencyRange};
+///
+/// let range = FrequencyRange::new(Frequency::from_hz(1), Frequency::from_mhz(1));
+/// let mut histogram = FrequencyHistogram::new(range, 6).unwrap();
+///
+/// for hz in [5, 50, 60, 70, 500, 5_000_000] {
+///     histogram.record(Frequency::from_hz(hz));
+/// }
+///
+/// let counts: Vec<u64> = histogram.bins().map(|(_, count)| count).collect();
+/// assert_eq!(counts, [1, 3, 1, 0, 0, 0]);
+/// assert_eq!(histogram.overflow(), 1);
+///
+/// // The median lies in the 10 Hz to 100 Hz bin
+/// let median = histogram.percentile(50.0).unwrap();
+/// assert!((Frequency::from_hz(10)..Frequency::from_hz(100)).contains(&median));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrequencyHistogram {
+    /// Bin edges in hertz, `bins + 1` values from the range minimum to the range maximum
+    edges: Vec<u64>,
+    counts: Vec<u64>,
+    underflow: u64,
+    overflow: u64,
+}
+
+impl FrequencyHistogram {
+    /// Creates an empty histogram with `bins` logarithmically spaced bins covering `range`.
+    ///
+    /// Returns `None` if `bins` is zero, the range starts at `0 Hz` or the range is empty.
+    #[must_use]
+    // Precision loss is acceptable here and edges stay within the range
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn new(range: FrequencyRange, bins: usize) -> Option<Self> {
+        if bins == 0 || range.min == Frequency::ZERO || range.min == range.max {
+            return None;
+        }
+
+        let (min, max) = (range.min.0 as f64, range.max.0 as f64);
+        let ratio = max / min;
+
+        let mut edges: Vec<u64> = (0..=bins)
+            .map(|i| (min * ratio.powf(i as f64 / bins as f64)).round() as u64)
+            .collect();
+
+        // Pin the outer edges so rounding never shrinks the range
+        edges[0] = range.min.0;
+        edges[bins] = range.max.0;
+
+        Some(Self {
+            edges,
+            counts: vec![0; bins],
+            underflow: 0,
+            overflow: 0,
+        })
+    }
+
+    /// Adds a sample. Each bin includes its lower edge, the last bin also includes the upper
+    /// edge of the range.
+    pub fn record(&mut self, freq: Frequency) {
+        let (first, last) = (self.edges[0], self.edges[self.edges.len() - 1]);
+
+        if freq.0 < first {
+            self.underflow += 1;
+        } else if freq.0 > last {
+            self.overflow += 1;
+        } else {
+            // The upper edge of the range belongs to the last bin
+            let last_bin = self.counts.len() - 1;
+            let index = self.edges.partition_point(|&edge| edge <= freq.0) - 1;
+            self.counts[index.min(last_bin)] += 1;
+        }
+    }
+
+    /// Returns the total number of samples recorded, including underflow and overflow.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.underflow + self.counts.iter().sum::<u64>() + self.overflow
+    }
+
+    /// Returns the number of samples below the range.
+    #[must_use]
+    pub fn underflow(&self) -> u64 {
+        self.underflow
+    }
+
+    /// Returns the number of samples above the range.
+    #[must_use]
+    pub fn overflow(&self) -> u64 {
+        self.overflow
+    }
+
+    /// Returns an iterator over each bin's frequencies and sample count, from low to high.
+    pub fn bins(&self) -> impl Iterator<Item = (FrequencyRange, u64)> + '_ {
+        self.edges
+            .windows(2)
+            .zip(&self.counts)
+            .map(|(edges, &count)| {
+                (
+                    FrequencyRange::new(Frequency(edges[0]), Frequency(edges[1])),
+                    count,
+                )
+            })
+    }
+
+    /// Estimates the frequency below which `percent` percent of the samples lie.
+    ///
+    /// Within a bin, samples are assumed to be spread evenly on the logarithmic scale. Samples
+    /// below or above the range are treated as lying on the range minimum or maximum. Returns
+    /// `None` if no samples were recorded.
+    #[must_use]
+    // Precision loss is acceptable here and the result stays within the bin
+    #[allow(
+        clippy::cast_precision_loss,
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss
+    )]
+    pub fn percentile(&self, percent: f64) -> Option<Frequency> {
+        let total = self.count();
+        if total == 0 {
+            return None;
+        }
+
+        let fraction = if percent.is_nan() {
+            0.0
+        } else {
+            percent.clamp(0.0, 100.0) / 100.0
+        };
+        let rank = ((fraction * total as f64).ceil() as u64).max(1);
+
+        let (first, last) = (self.edges[0], self.edges[self.edges.len() - 1]);
+        if rank <= self.underflow {
+            return Some(Frequency(first));
+        }
+
+        let mut before = self.underflow;
+        for (range, count) in self.bins() {
+            if rank <= before + count {
+                let position = (rank - before) as f64 / count as f64;
+                let (lower, upper) = (range.min.0 as f64, range.max.0 as f64);
+                let hz = lower * (upper / lower).powf(position);
+                return Some(Frequency(hz.round() as u64));
+            }
+
+            before += count;
+        }
+
+        Some(Frequency(last))
+    }
+}
@@ -0,0 +1,442 @@
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
+    fmt::{Debug, Display},
+    hash::Hash,
+    num::{NonZero, NonZeroU64},
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    Error, Frequency, GIGAHERTZ, KILOHERTZ, MEGAHERTZ, Result, fmt_scaled, math,
+    parse_frequency_as, write_digits,
+};
+
+/// An unsigned integer type that can store the hertz value of a [`FrequencyOf`]
+///
+/// Implemented for `u16`, `u32`, `u64` and `u128`. A `u32` covers frequencies up to about
+/// 4.29 GHz and avoids 64-bit math on small microcontrollers, while a `u128` leaves room for
+/// intermediate products in scientific work.
+pub trait FrequencyInt:
+    Copy
+    + Ord
+    + Default
+    + Hash
+    + Debug
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+{
+    /// The value `0`
+    const ZERO: Self;
+
+    /// The largest representable value
+    const MAX: Self;
+
+    /// Widens the value to a `u128`.
+    fn to_u128(self) -> u128;
+
+    /// Narrows a `u128` to this type, or returns `None` if it does not fit.
+    fn from_u128(value: u128) -> Option<Self>;
+}
+
+/// A frequency in hertz stored in any [`FrequencyInt`]
+///
+/// [`Frequency`] always stores a `u64`, so integer literals such as `Frequency(440)` just work.
+/// `FrequencyOf` parses, formats and does arithmetic the same way for other storage types, and
+/// converts to and from [`Frequency`]: losslessly where the value always fits, checked otherwise.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyOf};
+///
+/// let uart: FrequencyOf<u32> = "115.2 kHz".parse().unwrap();
+/// assert_eq!(uart, FrequencyOf(115_200u32));
+/// assert_eq!(uart.to_string(), "115.20 kHz");
+///
+/// // Widening is lossless, narrowing is checked
+/// let wide: Frequency = uart.into();
+/// assert_eq!(wide, Frequency::from_hz(115_200));
+/// assert!(FrequencyOf::<u32>::try_from(Frequency::from_ghz(5)).is_err());
+///
+/// assert!("5 GHz".parse::<FrequencyOf<u32>>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+#[repr(transparent)]
+pub struct FrequencyOf<T>(pub T);
+
+macro_rules! impl_frequency_int {
+    ($($int:ty),*) => {
+        $(
+            impl FrequencyInt for $int {
+                const ZERO: Self = 0;
+                const MAX: Self = <$int>::MAX;
+
+                fn to_u128(self) -> u128 {
+                    u128::from(self)
+                }
+
+                fn from_u128(value: u128) -> Option<Self> {
+                    Self::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+impl_frequency_int!(u16, u32, u64, u128);
+
+impl<T: FrequencyInt> Display for FrequencyOf<T> {
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.0.to_u128();
+        fmt_scaled(f, value as f64).unwrap_or_else(|| write!(f, "{value} Hz"))
+    }
+}
+
+impl<T: FrequencyInt> FrequencyOf<T> {
+    /// Writes the frequency like its [`Display`] implementation, see [`Frequency::format_into`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w` fails, such as when a fixed-size buffer is full.
+    pub fn format_into(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        write!(w, "{self}")
+    }
+
+    /// Writes the frequency exactly, see [`Frequency::format_exact_into`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w` fails, such as when a fixed-size buffer is full.
+    pub fn format_exact_into(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        let value = self.0.to_u128();
+        let units = [
+            (GIGAHERTZ, 9, "GHz"),
+            (MEGAHERTZ, 6, "MHz"),
+            (KILOHERTZ, 3, "kHz"),
+        ];
+
+        let Some((unit, mut digits, name)) = units
+            .into_iter()
+            .find(|(unit, _, _)| value >= u128::from(*unit))
+        else {
+            write_digits(w, value, 1)?;
+            return w.write_str(" Hz");
+        };
+
+        let (whole, mut fraction) = (value / u128::from(unit), value % u128::from(unit));
+        write_digits(w, whole, 1)?;
+
+        if fraction != 0 {
+            while fraction % 10 == 0 {
+                fraction /= 10;
+                digits -= 1;
+            }
+            w.write_str(".")?;
+            write_digits(w, fraction, digits)?;
+        }
+
+        w.write_str(" ")?;
+        w.write_str(name)
+    }
+
+    /// Formats the frequency exactly, see [`Frequency::to_exact_string`].
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_exact_string(&self) -> String {
+        let mut s = String::new();
+        // Writing to a `String` never fails
+        let _ = self.format_exact_into(&mut s);
+        s
+    }
+
+    /// Parses a plain decimal frequency without floating point, see [`Frequency::parse_plain`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::FrequencyOf;
+    ///
+    /// assert_eq!(FrequencyOf::<u32>::parse_plain("115.2 kHz"), Some(FrequencyOf(115_200)));
+    /// assert_eq!(FrequencyOf::<u32>::parse_plain("5 GHz"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_plain(s: &str) -> Option<Self> {
+        math::parse_plain_hz(s)
+            .and_then(T::from_u128)
+            .map(FrequencyOf)
+    }
+
+    /// Converts the frequency to a different storage type, or returns `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::FrequencyOf;
+    ///
+    /// let wide = FrequencyOf(48_000_000u128);
+    /// assert_eq!(wide.try_cast::<u32>(), Some(FrequencyOf(48_000_000u32)));
+    /// assert_eq!(wide.try_cast::<u16>(), None);
+    /// ```
+    #[must_use]
+    pub fn try_cast<U: FrequencyInt>(self) -> Option<FrequencyOf<U>> {
+        U::from_u128(self.0.to_u128()).map(FrequencyOf)
+    }
+
+    /// Adds two frequencies, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn try_add(self, other: Self) -> Option<Self> {
+        let hz = self.0.to_u128().checked_add(other.0.to_u128())?;
+        T::from_u128(hz).map(FrequencyOf)
+    }
+
+    /// Subtracts `other`, or returns `None` if the result would be negative.
+    #[must_use]
+    pub fn try_sub(self, other: Self) -> Option<Self> {
+        let hz = self.0.to_u128().checked_sub(other.0.to_u128())?;
+        T::from_u128(hz).map(FrequencyOf)
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn try_mul(self, rhs: T) -> Option<Self> {
+        let hz = self.0.to_u128().checked_mul(rhs.to_u128())?;
+        T::from_u128(hz).map(FrequencyOf)
+    }
+
+    /// Divides by `rhs`, or returns `None` if `rhs` is zero.
+    #[must_use]
+    pub fn try_div(self, rhs: T) -> Option<Self> {
+        let hz = self.0.to_u128().checked_div(rhs.to_u128())?;
+        T::from_u128(hz).map(FrequencyOf)
+    }
+}
+
+impl<T: FrequencyInt> FromStr for FrequencyOf<T> {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_frequency_as(s)
+    }
+}
+
+impl<T: FrequencyInt> TryFrom<&str> for FrequencyOf<T> {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        parse_frequency_as(s)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T: FrequencyInt> TryFrom<String> for FrequencyOf<T> {
+    type Error = Error;
+
+    fn try_from(s: String) -> Result<Self> {
+        parse_frequency_as(&s)
+    }
+}
+
+impl<T: FrequencyInt> Add for FrequencyOf<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`FrequencyOf::try_add`] for a checked alternative.
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl<T: FrequencyInt> Sub for FrequencyOf<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be negative and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`FrequencyOf::try_sub`] for a checked alternative.
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+impl<T: FrequencyInt> Mul<T> for FrequencyOf<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`FrequencyOf::try_mul`] for a checked alternative.
+    fn mul(self, rhs: T) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl<T: FrequencyInt> Div<T> for FrequencyOf<T> {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`FrequencyOf::try_div`] for a checked alternative.
+    fn div(self, rhs: T) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Frequency {
+    /// Converts the frequency to a different storage type, or returns `None` if it does not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyOf};
+    ///
+    /// assert_eq!(Frequency::from_mhz(48).try_cast::<u32>(), Some(FrequencyOf(48_000_000u32)));
+    /// assert_eq!(Frequency::from_ghz(5).try_cast::<u32>(), None);
+    /// ```
+    #[must_use]
+    pub fn try_cast<U: FrequencyInt>(self) -> Option<FrequencyOf<U>> {
+        FrequencyOf(self.0).try_cast()
+    }
+}
+
+impl From<u64> for Frequency {
+    fn from(hz: u64) -> Self {
+        Self(hz)
+    }
+}
+
+impl From<NonZeroU64> for Frequency {
+    fn from(hz: NonZeroU64) -> Self {
+        Self(hz.get())
+    }
+}
+
+impl From<Frequency> for u64 {
+    fn from(freq: Frequency) -> Self {
+        freq.0
+    }
+}
+
+macro_rules! impl_frequency_conversions {
+    ($narrow:ty => $($wide:ty),*) => {
+        $(
+            impl From<FrequencyOf<$narrow>> for FrequencyOf<$wide> {
+                fn from(freq: FrequencyOf<$narrow>) -> Self {
+                    Self(<$wide>::from(freq.0))
+                }
+            }
+
+            impl TryFrom<FrequencyOf<$wide>> for FrequencyOf<$narrow> {
+                type Error = Error;
+
+                fn try_from(freq: FrequencyOf<$wide>) -> Result<Self> {
+                    freq.try_cast()
+                        .ok_or_else(|| Error::invalid_value(freq))
+                }
+            }
+        )*
+    };
+}
+
+impl_frequency_conversions!(u16 => u32, u64, u128);
+impl_frequency_conversions!(u32 => u64, u128);
+impl_frequency_conversions!(u64 => u128);
+
+/// Implements conversions between a [`Frequency`] and the other storage types, lossless when
+/// widening and checked when narrowing
+macro_rules! impl_storage_conversions {
+    (narrow: $($narrow:ty),*; wide: $($wide:ty),*) => {
+        $(
+            impl From<FrequencyOf<$narrow>> for Frequency {
+                fn from(freq: FrequencyOf<$narrow>) -> Self {
+                    Self(u64::from(freq.0))
+                }
+            }
+
+            impl TryFrom<Frequency> for FrequencyOf<$narrow> {
+                type Error = Error;
+
+                fn try_from(freq: Frequency) -> Result<Self> {
+                    freq.try_cast()
+                        .ok_or_else(|| Error::invalid_value(freq))
+                }
+            }
+        )*
+
+        $(
+            impl From<Frequency> for FrequencyOf<$wide> {
+                fn from(freq: Frequency) -> Self {
+                    Self(<$wide>::from(freq.0))
+                }
+            }
+
+            impl TryFrom<FrequencyOf<$wide>> for Frequency {
+                type Error = Error;
+
+                fn try_from(freq: FrequencyOf<$wide>) -> Result<Self> {
+                    freq.try_cast::<u64>()
+                        .map(Self::from)
+                        .ok_or_else(|| Error::invalid_value(freq))
+                }
+            }
+        )*
+    };
+}
+
+impl_storage_conversions!(narrow: u16, u32; wide: u128);
+
+impl From<FrequencyOf<u64>> for Frequency {
+    fn from(freq: FrequencyOf<u64>) -> Self {
+        Self(freq.0)
+    }
+}
+
+impl From<Frequency> for FrequencyOf<u64> {
+    fn from(freq: Frequency) -> Self {
+        Self(freq.0)
+    }
+}
+
+/// Implements conversions between a [`FrequencyOf`] and its raw number of hertz, so generic code
+/// does not need to reach into the field
+macro_rules! impl_hz_conversions {
+    ($($int:ty),*) => {
+        $(
+            impl From<$int> for FrequencyOf<$int> {
+                fn from(hz: $int) -> Self {
+                    Self(hz)
+                }
+            }
+
+            impl From<NonZero<$int>> for FrequencyOf<$int> {
+                fn from(hz: NonZero<$int>) -> Self {
+                    Self(hz.get())
+                }
+            }
+
+            impl From<FrequencyOf<$int>> for $int {
+                fn from(freq: FrequencyOf<$int>) -> Self {
+                    freq.0
+                }
+            }
+        )*
+    };
+}
+
+impl_hz_conversions!(u16, u32, u64, u128);
@@ -0,0 +1,127 @@
+use crate::{Frequency, FrequencyRange, ItuRegion, KILOHERTZ, MEGAHERTZ};
+
+/// A band designated for industrial, scientific and medical (ISM) or short range device use
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct IsmBand {
+    /// The common name of the band, e.g. `"2.4 GHz"`
+    pub name: &'static str,
+
+    /// The frequencies of the band
+    pub range: FrequencyRange,
+
+    /// The ITU region the band is limited to, or `None` if it is designated worldwide
+    pub region: Option<ItuRegion>,
+}
+
+impl IsmBand {
+    const fn new(name: &'static str, min: u64, max: u64, region: Option<ItuRegion>) -> Self {
+        Self {
+            name,
+            range: FrequencyRange {
+                min: Frequency(min),
+                max: Frequency(max),
+            },
+            region,
+        }
+    }
+
+    /// Returns `true` if the band can be used in `region`.
+    #[must_use]
+    pub fn is_available_in(&self, region: ItuRegion) -> bool {
+        self.region.is_none_or(|band_region| band_region == region)
+    }
+}
+
+/// The ISM bands of the ITU Radio Regulations (footnotes 5.138 and 5.150), plus the European
+/// 868 MHz short range device band used by LoRa and Zigbee, in ascending order
+pub const ISM_BANDS: [IsmBand; 13] = [
+    IsmBand::new("6.78 MHz", 6_765 * KILOHERTZ, 6_795 * KILOHERTZ, None),
+    IsmBand::new("13.56 MHz", 13_553 * KILOHERTZ, 13_567 * KILOHERTZ, None),
+    IsmBand::new("27.12 MHz", 26_957 * KILOHERTZ, 27_283 * KILOHERTZ, None),
+    IsmBand::new("40.68 MHz", 40_660 * KILOHERTZ, 40_700 * KILOHERTZ, None),
+    IsmBand::new(
+        "433 MHz",
+        433_050 * KILOHERTZ,
+        434_790 * KILOHERTZ,
+        Some(ItuRegion::Region1),
+    ),
+    IsmBand::new(
+        "868 MHz",
+        863 * MEGAHERTZ,
+        870 * MEGAHERTZ,
+        Some(ItuRegion::Region1),
+    ),
+    IsmBand::new(
+        "915 MHz",
+        902 * MEGAHERTZ,
+        928 * MEGAHERTZ,
+        Some(ItuRegion::Region2),
+    ),
+    IsmBand::new("2.4 GHz", 2_400 * MEGAHERTZ, 2_500 * MEGAHERTZ, None),
+    IsmBand::new("5.8 GHz", 5_725 * MEGAHERTZ, 5_875 * MEGAHERTZ, None),
+    IsmBand::new("24 GHz", 24_000 * MEGAHERTZ, 24_250 * MEGAHERTZ, None),
+    IsmBand::new("61 GHz", 61_000 * MEGAHERTZ, 61_500 * MEGAHERTZ, None),
+    IsmBand::new("122 GHz", 122_000 * MEGAHERTZ, 123_000 * MEGAHERTZ, None),
+    IsmBand::new("245 GHz", 244_000 * MEGAHERTZ, 246_000 * MEGAHERTZ, None),
+];
+
+impl Frequency {
+    /// Returns the ISM band this frequency falls into in any region, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let band = Frequency::from_khz(13_560).ism_band().unwrap();
+    /// assert_eq!(band.name, "13.56 MHz");
+    ///
+    /// assert!(Frequency::from_mhz(100).ism_band().is_none());
+    /// ```
+    #[must_use]
+    pub fn ism_band(&self) -> Option<&'static IsmBand> {
+        ISM_BANDS.iter().find(|band| band.range.contains(*self))
+    }
+
+    /// Returns the ISM band this frequency falls into in `region`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, ItuRegion};
+    ///
+    /// let lora = Frequency::from_khz(915_000);
+    /// assert!(lora.ism_band_in(ItuRegion::Region2).is_some());
+    /// assert!(lora.ism_band_in(ItuRegion::Region1).is_none());
+    /// ```
+    #[must_use]
+    pub fn ism_band_in(&self, region: ItuRegion) -> Option<&'static IsmBand> {
+        ISM_BANDS
+            .iter()
+            .find(|band| band.is_available_in(region) && band.range.contains(*self))
+    }
+}
+
+impl FrequencyRange {
+    /// Returns `true` if the whole range lies within a single ISM band of any region, e.g. to
+    /// check that a channel including its bandwidth stays inside the band.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange};
+    ///
+    /// // A BLE advertising channel at 2402 MHz with 2 MHz bandwidth
+    /// let channel = FrequencyRange::new(Frequency::from_mhz(2_401), Frequency::from_mhz(2_403));
+    /// assert!(channel.fits_within_ism());
+    ///
+    /// let channel = FrequencyRange::new(Frequency::from_mhz(2_399), Frequency::from_mhz(2_401));
+    /// assert!(!channel.fits_within_ism());
+    /// ```
+    #[must_use]
+    pub fn fits_within_ism(&self) -> bool {
+        self.min
+            .ism_band()
+            .is_some_and(|band| band.range.contains(self.max))
+    }
+}
@@ -0,0 +1,290 @@
+use std::fmt::Display;
+
+use crate::{Frequency, FrequencyRange, KILOHERTZ, MEGAHERTZ};
+
+/// A radio frequency band as designated by ITU-R V.431
+///
+/// Band `N` covers `0.3 * 10^N Hz` (exclusive) up to `3 * 10^N Hz` (inclusive).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItuBand {
+    /// Extremely low frequency, 3 Hz to 30 Hz (band 1)
+    Elf,
+
+    /// Super low frequency, 30 Hz to 300 Hz (band 2)
+    Slf,
+
+    /// Ultra low frequency, 300 Hz to 3 kHz (band 3)
+    Ulf,
+
+    /// Very low frequency, 3 kHz to 30 kHz (band 4)
+    Vlf,
+
+    /// Low frequency, 30 kHz to 300 kHz (band 5)
+    Lf,
+
+    /// Medium frequency, 300 kHz to 3 MHz (band 6)
+    Mf,
+
+    /// High frequency, 3 MHz to 30 MHz (band 7)
+    Hf,
+
+    /// Very high frequency, 30 MHz to 300 MHz (band 8)
+    Vhf,
+
+    /// Ultra high frequency, 300 MHz to 3 GHz (band 9)
+    Uhf,
+
+    /// Super high frequency, 3 GHz to 30 GHz (band 10)
+    Shf,
+
+    /// Extremely high frequency, 30 GHz to 300 GHz (band 11)
+    Ehf,
+
+    /// Tremendously high frequency, 300 GHz to 3 THz (band 12)
+    Thf,
+}
+
+impl ItuBand {
+    const ALL: [ItuBand; 12] = [
+        ItuBand::Elf,
+        ItuBand::Slf,
+        ItuBand::Ulf,
+        ItuBand::Vlf,
+        ItuBand::Lf,
+        ItuBand::Mf,
+        ItuBand::Hf,
+        ItuBand::Vhf,
+        ItuBand::Uhf,
+        ItuBand::Shf,
+        ItuBand::Ehf,
+        ItuBand::Thf,
+    ];
+
+    /// Returns the ITU band number (`1` for ELF up to `12` for THF).
+    #[must_use]
+    pub fn number(self) -> u32 {
+        self as u32 + 1
+    }
+
+    /// Returns the frequencies covered by this band.
+    #[must_use]
+    pub fn range(self) -> FrequencyRange {
+        let max = 3 * 10u64.pow(self.number());
+        FrequencyRange::new(Frequency(max / 10), Frequency(max))
+    }
+}
+
+impl Display for ItuBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name = match self {
+            ItuBand::Elf => "ELF",
+            ItuBand::Slf => "SLF",
+            ItuBand::Ulf => "ULF",
+            ItuBand::Vlf => "VLF",
+            ItuBand::Lf => "LF",
+            ItuBand::Mf => "MF",
+            ItuBand::Hf => "HF",
+            ItuBand::Vhf => "VHF",
+            ItuBand::Uhf => "UHF",
+            ItuBand::Shf => "SHF",
+            ItuBand::Ehf => "EHF",
+            ItuBand::Thf => "THF",
+        };
+
+        write!(f, "{name}")
+    }
+}
+
+/// An ITU Radio Regulations region, which determines the frequency allocations in effect
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ItuRegion {
+    /// Europe, Africa, the Middle East and northern Asia
+    Region1,
+
+    /// The Americas
+    Region2,
+
+    /// Southern Asia and Oceania
+    Region3,
+}
+
+impl ItuRegion {
+    /// Returns the amateur radio bands allocated in this region, in ascending order.
+    ///
+    /// The table follows the ITU Radio Regulations; national allocations may be narrower or
+    /// wider.
+    #[must_use]
+    pub fn ham_bands(self) -> &'static [HamBand] {
+        match self {
+            ItuRegion::Region1 => &REGION_1,
+            ItuRegion::Region2 => &REGION_2,
+            ItuRegion::Region3 => &REGION_3,
+        }
+    }
+}
+
+/// An amateur radio band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct HamBand {
+    /// The conventional name of the band, e.g. `"20m"`
+    pub name: &'static str,
+
+    /// The frequencies allocated to the band
+    pub range: FrequencyRange,
+}
+
+impl HamBand {
+    const fn hz(name: &'static str, min: u64, max: u64) -> Self {
+        Self {
+            name,
+            range: FrequencyRange {
+                min: Frequency(min),
+                max: Frequency(max),
+            },
+        }
+    }
+
+    const fn khz(name: &'static str, min: u64, max: u64) -> Self {
+        Self::hz(name, min * KILOHERTZ, max * KILOHERTZ)
+    }
+
+    const fn mhz(name: &'static str, min: u64, max: u64) -> Self {
+        Self::hz(name, min * MEGAHERTZ, max * MEGAHERTZ)
+    }
+}
+
+impl Display for HamBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+const BAND_2200M: HamBand = HamBand::hz("2200m", 135_700, 137_800);
+const BAND_630M: HamBand = HamBand::khz("630m", 472, 479);
+const BAND_60M: HamBand = HamBand::hz("60m", 5_351_500, 5_366_500);
+const BAND_30M: HamBand = HamBand::khz("30m", 10_100, 10_150);
+const BAND_20M: HamBand = HamBand::khz("20m", 14_000, 14_350);
+const BAND_17M: HamBand = HamBand::khz("17m", 18_068, 18_168);
+const BAND_15M: HamBand = HamBand::khz("15m", 21_000, 21_450);
+const BAND_12M: HamBand = HamBand::khz("12m", 24_890, 24_990);
+const BAND_10M: HamBand = HamBand::khz("10m", 28_000, 29_700);
+const BAND_23CM: HamBand = HamBand::mhz("23cm", 1_240, 1_300);
+const BAND_13CM: HamBand = HamBand::mhz("13cm", 2_300, 2_450);
+const BAND_5CM: HamBand = HamBand::mhz("5cm", 5_650, 5_850);
+const BAND_3CM: HamBand = HamBand::mhz("3cm", 10_000, 10_500);
+const BAND_1_2CM: HamBand = HamBand::mhz("1.2cm", 24_000, 24_250);
+
+const REGION_1: [HamBand; 21] = [
+    BAND_2200M,
+    BAND_630M,
+    HamBand::khz("160m", 1_810, 2_000),
+    HamBand::khz("80m", 3_500, 3_800),
+    BAND_60M,
+    HamBand::khz("40m", 7_000, 7_200),
+    BAND_30M,
+    BAND_20M,
+    BAND_17M,
+    BAND_15M,
+    BAND_12M,
+    BAND_10M,
+    HamBand::mhz("6m", 50, 52),
+    HamBand::mhz("2m", 144, 146),
+    HamBand::mhz("70cm", 430, 440),
+    BAND_23CM,
+    BAND_13CM,
+    BAND_5CM,
+    BAND_3CM,
+    BAND_1_2CM,
+    HamBand::mhz("6mm", 47_000, 47_200),
+];
+
+const REGION_2: [HamBand; 23] = [
+    BAND_2200M,
+    BAND_630M,
+    HamBand::khz("160m", 1_800, 2_000),
+    HamBand::khz("80m", 3_500, 4_000),
+    BAND_60M,
+    HamBand::khz("40m", 7_000, 7_300),
+    BAND_30M,
+    BAND_20M,
+    BAND_17M,
+    BAND_15M,
+    BAND_12M,
+    BAND_10M,
+    HamBand::mhz("6m", 50, 54),
+    HamBand::mhz("2m", 144, 148),
+    HamBand::mhz("1.25m", 220, 225),
+    HamBand::mhz("70cm", 420, 450),
+    HamBand::mhz("33cm", 902, 928),
+    BAND_23CM,
+    BAND_13CM,
+    BAND_5CM,
+    BAND_3CM,
+    BAND_1_2CM,
+    HamBand::mhz("6mm", 47_000, 47_200),
+];
+
+const REGION_3: [HamBand; 21] = [
+    BAND_2200M,
+    BAND_630M,
+    HamBand::khz("160m", 1_800, 2_000),
+    HamBand::khz("80m", 3_500, 3_900),
+    BAND_60M,
+    HamBand::khz("40m", 7_000, 7_200),
+    BAND_30M,
+    BAND_20M,
+    BAND_17M,
+    BAND_15M,
+    BAND_12M,
+    BAND_10M,
+    HamBand::mhz("6m", 50, 54),
+    HamBand::mhz("2m", 144, 148),
+    HamBand::mhz("70cm", 430, 440),
+    BAND_23CM,
+    BAND_13CM,
+    BAND_5CM,
+    BAND_3CM,
+    BAND_1_2CM,
+    HamBand::mhz("6mm", 47_000, 47_200),
+];
+
+impl Frequency {
+    /// Returns the ITU band this frequency falls into, or `None` if it is outside 3 Hz to
+    /// 3 THz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, ItuBand};
+    ///
+    /// assert_eq!(Frequency::from_mhz(100).itu_band(), Some(ItuBand::Vhf));
+    /// assert_eq!(Frequency::from_mhz(300).itu_band(), Some(ItuBand::Vhf));
+    /// assert_eq!(Frequency::from_hz(300_000_001).itu_band(), Some(ItuBand::Uhf));
+    /// ```
+    #[must_use]
+    pub fn itu_band(&self) -> Option<ItuBand> {
+        ItuBand::ALL.into_iter().find(|band| {
+            let range = band.range();
+            range.min < *self && *self <= range.max
+        })
+    }
+
+    /// Returns the amateur radio band this frequency falls into in `region`, if any.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, ItuRegion};
+    ///
+    /// let freq = Frequency::from_khz(7_250);
+    /// assert_eq!(freq.ham_band(ItuRegion::Region2).map(|band| band.name), Some("40m"));
+    /// assert_eq!(freq.ham_band(ItuRegion::Region1), None);
+    /// ```
+    #[must_use]
+    pub fn ham_band(&self, region: ItuRegion) -> Option<&'static HamBand> {
+        region
+            .ham_bands()
+            .iter()
+            .find(|band| band.range.contains(*self))
+    }
+}
@@ -1,26 +1,271 @@
-use std::{
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+use core::{
     fmt::Display,
     ops::{Add, Div, Mul, Sub},
     str::FromStr,
 };
 
+#[cfg(feature = "std")]
+pub use angular::*;
+#[cfg(feature = "std")]
+pub use bands::*;
+pub use baud::*;
+#[cfg(feature = "std")]
+pub use bpm::*;
+pub use builder::*;
+pub use cellular::*;
+#[cfg(feature = "std")]
+pub use cents::*;
+#[cfg(feature = "std")]
+pub use clock_tree::*;
+pub use codec::*;
+pub use divider::*;
+#[cfg(feature = "std")]
+pub use doppler::*;
 pub use error::*;
+#[cfg(feature = "std")]
+pub use estimator::*;
+#[cfg(feature = "std")]
+pub use fft::*;
+#[cfg(feature = "std")]
+pub use float::*;
+pub use grid::*;
+pub use gsm::*;
+#[cfg(feature = "std")]
+pub use histogram::*;
+pub use int::*;
+#[cfg(feature = "std")]
+pub use ism::*;
+#[cfg(feature = "std")]
+pub use itu::*;
+pub use millihertz::*;
+#[cfg(feature = "std")]
+pub use mixer::*;
+pub use nonzero::*;
+#[cfg(feature = "std")]
+pub use oscillator::*;
+pub use period::*;
+#[cfg(feature = "std")]
+pub use pitch::*;
+#[cfg(feature = "std")]
+pub use pll::*;
+#[cfg(feature = "std")]
+pub use ppm::*;
+#[cfg(feature = "std")]
+pub use pwm::*;
+pub use range::*;
+pub use rational::*;
+pub use rounding::*;
+#[cfg(feature = "std")]
+pub use rpm::*;
+#[cfg(feature = "std")]
+pub use sample_rate::*;
+#[cfg(feature = "std")]
+pub use stats::*;
+#[cfg(feature = "std")]
+pub use ticker::*;
+pub use timer::*;
+#[cfg(feature = "std")]
+pub use tv::*;
+pub use unit::*;
+#[cfg(feature = "std")]
+pub use wavelength::*;
+#[cfg(feature = "std")]
+pub use wifi::*;
 
+#[cfg(feature = "std")]
+mod angular;
+#[cfg(feature = "std")]
+mod bands;
+mod baud;
+#[cfg(feature = "std")]
+mod bpm;
+#[cfg(feature = "std")]
+mod broadcast;
+mod builder;
+mod cellular;
+#[cfg(feature = "std")]
+mod cents;
+#[cfg(feature = "std")]
+mod clock_tree;
+mod codec;
+pub mod consts;
+mod divider;
+#[cfg(feature = "std")]
+mod doppler;
 mod error;
+#[cfg(feature = "std")]
+mod estimator;
+#[cfg(feature = "std")]
+mod fft;
+#[cfg(feature = "std")]
+mod float;
+mod grid;
+mod gsm;
+#[cfg(feature = "std")]
+mod histogram;
+mod int;
+#[cfg(feature = "std")]
+mod ism;
+#[cfg(feature = "std")]
+mod itu;
+mod math;
+mod millihertz;
+#[cfg(feature = "std")]
+mod mixer;
+mod nonzero;
+#[cfg(feature = "std")]
+mod oscillator;
+mod period;
+#[cfg(feature = "std")]
+mod pitch;
+#[cfg(feature = "std")]
+mod pll;
+#[cfg(feature = "std")]
+mod ppm;
+#[cfg(feature = "std")]
+mod pwm;
+mod range;
+#[cfg(feature = "std")]
+mod rate_limit;
+mod rational;
+mod rounding;
+#[cfg(feature = "std")]
+mod rpm;
+#[cfg(feature = "std")]
+mod sample_rate;
+#[cfg(feature = "std")]
+mod stats;
+#[cfg(all(test, feature = "std"))]
 mod tests;
+#[cfg(feature = "std")]
+mod ticker;
+mod timer;
+#[cfg(feature = "std")]
+mod tv;
+mod unit;
+#[cfg(feature = "std")]
+mod wavelength;
+#[cfg(feature = "std")]
+mod wifi;
 
+#[cfg(feature = "approx")]
+#[cfg(feature = "std")]
+mod approx;
+#[cfg(feature = "arbitrary")]
+mod arbitrary;
+#[cfg(feature = "arbitrary")]
+pub use self::arbitrary::FrequencyInput;
+#[cfg(feature = "bincode")]
+mod bincode;
+#[cfg(feature = "borsh")]
+mod borsh;
+#[cfg(feature = "bson")]
+pub mod bson;
+#[cfg(feature = "bytemuck")]
+mod bytemuck;
 #[cfg(feature = "chrono")]
 mod chrono;
 #[cfg(feature = "clap")]
 mod clap;
+#[cfg(feature = "clap")]
+pub use self::clap::FrequencyValueParser;
+#[cfg(feature = "defmt")]
+mod defmt;
+#[cfg(feature = "diesel")]
+mod diesel_types;
+#[cfg(feature = "embedded-time")]
+mod embedded_time;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(feature = "fugit")]
+mod fugit;
+#[cfg(feature = "governor")]
+mod governor;
+#[cfg(feature = "heapless")]
+mod heapless;
+#[cfg(feature = "measurements")]
+mod measurements;
+#[cfg(feature = "metrics")]
+pub mod metrics;
+#[cfg(feature = "napi")]
+mod napi;
+#[cfg(feature = "napi")]
+pub use self::napi::NapiFrequency;
 #[cfg(feature = "num-traits")]
 mod num_traits;
+#[cfg(feature = "postgres")]
+mod postgres;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "prost")]
+pub mod prost;
+#[cfg(feature = "pyo3")]
+mod pyo3;
+#[cfg(feature = "quickcheck")]
+mod quickcheck;
+#[cfg(feature = "rand")]
+mod rand;
+#[cfg(feature = "rand")]
+pub use self::rand::UniformFrequency;
+#[cfg(feature = "rayon")]
+mod rayon;
+#[cfg(feature = "rayon")]
+pub use self::rayon::par_parse_many;
+#[cfg(feature = "rkyv")]
+mod rkyv;
+#[cfg(feature = "rkyv")]
+pub use self::rkyv::ArchivedFrequency;
+#[cfg(feature = "rusqlite")]
+mod rusqlite;
 #[cfg(feature = "schemars")]
 mod schemars;
+#[cfg(feature = "schemars1")]
+mod schemars1;
+#[cfg(feature = "sea-orm")]
+mod sea_orm;
 #[cfg(feature = "serde")]
-mod serde;
+pub mod serde;
+#[cfg(feature = "sqlx")]
+mod sqlx;
 #[cfg(feature = "time")]
 mod time;
+#[cfg(feature = "tokio")]
+mod tokio;
+#[cfg(feature = "uom")]
+mod uom;
+#[cfg(feature = "valuable")]
+mod valuable;
+#[cfg(feature = "wasm")]
+mod wasm;
+#[cfg(feature = "wasm")]
+pub use self::wasm::WasmFrequency;
+#[cfg(feature = "zerocopy")]
+mod zerocopy;
+#[cfg(feature = "zerocopy")]
+pub use self::zerocopy::UnalignedFrequency;
+
+/// Regular expression matching the strings accepted by [`parse_frequency`]: a non-negative
+/// decimal number, optionally with an exponent, followed by a case-insensitive unit
+#[cfg(any(feature = "schemars", feature = "schemars1"))]
+const FREQUENCY_PATTERN: &str =
+    r"^\s*\+?([0-9]+(\.[0-9]*)?|\.[0-9]+)([eE][+-]?[0-9]+)?\s*[gGmMkK]?[hH][zZ]\s*$";
+
+/// Description of the frequency string format for JSON schemas
+#[cfg(any(feature = "schemars", feature = "schemars1"))]
+const FREQUENCY_DESCRIPTION: &str = "A non-negative frequency, either as an integer number of \
+    hertz or as a decimal number followed by a case-insensitive unit of Hz, kHz, MHz or GHz, like \
+    \"2.4 GHz\", \"100 kHz\" or \"440Hz\"";
+
+/// Example frequency strings for JSON schemas and command line errors
+#[cfg(any(feature = "clap", feature = "schemars", feature = "schemars1"))]
+const FREQUENCY_EXAMPLES: [&str; 3] = ["2.4 GHz", "100 kHz", "440Hz"];
 
 /// 1 kilohertz (kHz) in hertz
 pub const KILOHERTZ: u64 = 1_000;
@@ -34,6 +279,8 @@ pub const GIGAHERTZ: u64 = 1_000_000_000;
 /// Represents a frequency
 ///
 /// This struct is a wrapper around a `u64` value representing the frequency in hertz.
+/// [`FrequencyOf`] stores the hertz in any other [`FrequencyInt`], such as a `u32` on small
+/// microcontrollers.
 /// It provides methods to convert between different frequency units (Hz, kHz, MHz, GHz) and
 /// to parse frequency strings.
 ///
@@ -62,9 +309,31 @@ pub const GIGAHERTZ: u64 = 1_000_000_000;
 /// let strfreq: String = freq.to_string();
 /// assert_eq!(strfreq, "2.50 GHz");
 ///
+/// // Converting to and from a number of hertz
+/// let hz: u64 = freq.into();
+/// assert_eq!(Frequency::from(hz), freq);
+///
+/// // Constructors and accessors are `const`, for static clock tables
+/// const SYSCLK: Frequency = Frequency::from_mhz(168);
+/// const SYSCLK_MHZ: u64 = SYSCLK.as_mhz();
+///
 /// println!("Frequency: {}", freq);
 /// ```
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[cfg_attr(
+    feature = "zerocopy",
+    derive(
+        ::zerocopy::FromBytes,
+        ::zerocopy::IntoBytes,
+        ::zerocopy::Immutable,
+        ::zerocopy::KnownLayout
+    )
+)]
+#[cfg_attr(
+    feature = "diesel",
+    derive(::diesel::expression::AsExpression, ::diesel::deserialize::FromSqlRow),
+    diesel(sql_type = ::diesel::sql_types::BigInt)
+)]
 #[repr(transparent)]
 pub struct Frequency(pub u64);
 
@@ -112,55 +381,122 @@ impl Frequency {
     /// ```
     pub const GIGAHERTZ: Self = Self(GIGAHERTZ);
 
+    /// The length in bytes of the longest [`Display`] output, `"18446744073.71 GHz"`
+    ///
+    /// A buffer of this size always fits [`Frequency::format_into`].
+    pub const MAX_DISPLAY_LEN: usize = 18;
+
+    /// The length in bytes of the longest exact output, `"18446744073.709551615 GHz"`
+    ///
+    /// A buffer of this size always fits [`Frequency::format_exact_into`].
+    pub const MAX_EXACT_LEN: usize = 25;
+
     #[must_use]
     #[doc(alias = "from_hertz")]
-    pub fn from_hz(hz: u64) -> Self {
+    pub const fn from_hz(hz: u64) -> Self {
         Self(hz)
     }
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Frequency::try_from_khz`] for a checked alternative.
     #[must_use]
     #[doc(alias = "from_kilohertz")]
-    pub fn from_khz(khz: u64) -> Self {
+    pub const fn from_khz(khz: u64) -> Self {
         Self(khz * KILOHERTZ)
     }
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Frequency::try_from_mhz`] for a checked alternative.
     #[must_use]
     #[doc(alias = "from_megahertz")]
-    pub fn from_mhz(mhz: u64) -> Self {
+    pub const fn from_mhz(mhz: u64) -> Self {
         Self(mhz * MEGAHERTZ)
     }
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Frequency::try_from_ghz`] for a checked alternative.
     #[must_use]
     #[doc(alias = "from_gigahertz")]
-    pub fn from_ghz(ghz: u64) -> Self {
+    pub const fn from_ghz(ghz: u64) -> Self {
         Self(ghz * GIGAHERTZ)
     }
 
+    /// Creates a frequency from kilohertz, or returns `None` if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::try_from_khz(48), Some(Frequency::from_hz(48_000)));
+    /// assert_eq!(Frequency::try_from_khz(u64::MAX), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_khz(khz: u64) -> Option<Self> {
+        match khz.checked_mul(KILOHERTZ) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    /// Creates a frequency from megahertz, or returns `None` if it does not fit in a `u64`.
+    #[must_use]
+    pub const fn try_from_mhz(mhz: u64) -> Option<Self> {
+        match mhz.checked_mul(MEGAHERTZ) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    /// Creates a frequency from gigahertz, or returns `None` if it does not fit in a `u64`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::try_from_ghz(2), Some(Frequency::from_mhz(2_000)));
+    /// assert_eq!(Frequency::try_from_ghz(18_446_744_074), None);
+    /// ```
+    #[must_use]
+    pub const fn try_from_ghz(ghz: u64) -> Option<Self> {
+        match ghz.checked_mul(GIGAHERTZ) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
     #[must_use]
     #[doc(alias = "as_hertz")]
-    pub fn as_hz(&self) -> u64 {
+    pub const fn as_hz(&self) -> u64 {
         self.0
     }
 
     #[must_use]
     #[doc(alias = "as_kilohertz")]
-    pub fn as_khz(&self) -> u64 {
+    pub const fn as_khz(&self) -> u64 {
         self.as_hz() / KILOHERTZ
     }
 
     #[must_use]
     #[doc(alias = "as_megahertz")]
-    pub fn as_mhz(&self) -> u64 {
+    pub const fn as_mhz(&self) -> u64 {
         self.as_hz() / MEGAHERTZ
     }
 
     #[must_use]
     #[doc(alias = "as_gigahertz")]
-    pub fn as_ghz(&self) -> u64 {
+    pub const fn as_ghz(&self) -> u64 {
         self.as_hz() / GIGAHERTZ
     }
 
-    /// Converts the frequency to a `std::time::Duration`.
+    /// Converts the frequency to a `core::time::Duration`.
     ///
     /// # Examples
     ///
@@ -172,32 +508,143 @@ impl Frequency {
     /// assert_eq!(duration.as_nanos(), 1);
     /// ```
     /// # Returns
-    /// A `std::time::Duration` representing the frequency.
+    /// A `core::time::Duration` representing the frequency.
     #[must_use]
-    pub fn as_duration(&self) -> std::time::Duration {
-        if self.0 == 0 {
-            std::time::Duration::ZERO
-        } else {
-            std::time::Duration::from_nanos(GIGAHERTZ / self.0)
+    pub const fn as_duration(&self) -> core::time::Duration {
+        match GIGAHERTZ.checked_div(self.0) {
+            Some(nanos) => core::time::Duration::from_nanos(nanos),
+            None => core::time::Duration::ZERO,
         }
     }
+
+    #[must_use]
+    pub const fn is_zero(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// Returns the lower of two frequencies, like [`Ord::min`] but usable in constants.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// const SYSCLK: Frequency = Frequency::from_mhz(168);
+    /// const APB1: Frequency = Frequency::from_hz(SYSCLK.as_hz() / 4).min(Frequency::from_mhz(42));
+    /// assert_eq!(APB1, Frequency::from_mhz(42));
+    /// ```
+    #[must_use]
+    pub const fn min(self, other: Self) -> Self {
+        if self.0 <= other.0 { self } else { other }
+    }
+
+    /// Returns the higher of two frequencies, like [`Ord::max`] but usable in constants.
+    #[must_use]
+    pub const fn max(self, other: Self) -> Self {
+        if self.0 >= other.0 { self } else { other }
+    }
+
+    /// Restricts the frequency to `min..=max`, like [`Ord::clamp`] but usable in constants.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `min > max`. `freq.max(min).min(max)` never panics, and returns `max` in that
+    /// case.
+    #[must_use]
+    pub const fn clamp(self, min: Self, max: Self) -> Self {
+        assert!(min.0 <= max.0, "min must not be greater than max");
+        self.max(min).min(max)
+    }
 }
 
 impl Display for Frequency {
-    // Precision loss is acceptable here
-    #[allow(clippy::cast_precision_loss)]
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let value = self.as_hz();
-
-        if value >= GIGAHERTZ {
-            write!(f, "{:.2} GHz", value as f64 / GIGAHERTZ as f64)
-        } else if value >= MEGAHERTZ {
-            write!(f, "{:.2} MHz", value as f64 / MEGAHERTZ as f64)
-        } else if value >= KILOHERTZ {
-            write!(f, "{:.2} kHz", value as f64 / KILOHERTZ as f64)
-        } else {
-            write!(f, "{value} Hz")
-        }
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        FrequencyOf(self.0).fmt(f)
+    }
+}
+
+impl Frequency {
+    /// Writes the frequency like its [`Display`] implementation, e.g. into a stack buffer on
+    /// targets without an allocator.
+    ///
+    /// The output is at most [`Frequency::MAX_DISPLAY_LEN`] bytes long.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w` fails, such as when a fixed-size buffer is full.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::fmt::Write;
+    /// use parse_frequency::Frequency;
+    ///
+    /// let mut line = String::from("AT+FREQ=");
+    /// Frequency::from_mhz(2_500).format_into(&mut line).unwrap();
+    /// assert_eq!(line, "AT+FREQ=2.50 GHz");
+    /// ```
+    pub fn format_into(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        write!(w, "{self}")
+    }
+
+    /// Writes the frequency like [`Frequency::to_exact_string`], without allocating.
+    ///
+    /// The output is at most [`Frequency::MAX_EXACT_LEN`] bytes long.
+    /// The digits are written without going through `core::fmt`, whose padding and integer
+    /// formatting keep panicking branches the optimizer cannot remove, so this never panics
+    /// unless `w` does.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `w` fails, such as when a fixed-size buffer is full.
+    pub fn format_exact_into(&self, w: &mut impl core::fmt::Write) -> core::fmt::Result {
+        FrequencyOf(self.0).format_exact_into(w)
+    }
+
+    /// Formats the frequency exactly in the largest unit it reaches, without trailing zeros.
+    ///
+    /// Unlike [`Display`], which rounds to two decimals, parsing the result always gives back
+    /// the same frequency.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let freq = Frequency::from_hz(2_400_000_001);
+    /// assert_eq!(freq.to_string(), "2.40 GHz");
+    /// assert_eq!(freq.to_exact_string(), "2.400000001 GHz");
+    /// assert_eq!(Frequency::from_khz(48).to_exact_string(), "48 kHz");
+    /// ```
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_exact_string(&self) -> String {
+        let mut s = String::new();
+        // Writing to a `String` never fails
+        let _ = self.format_exact_into(&mut s);
+        s
+    }
+
+    /// Parses a frequency with a plain decimal value such as `"2400 MHz"` or `"2.437ghz"`,
+    /// rounding to the nearest hertz with ties rounding up.
+    ///
+    /// This is the subset of the notations [`FromStr`] accepts that can be parsed without
+    /// floating point, and it never panics, while `f64` parsing in `core` keeps panicking
+    /// branches the optimizer cannot remove. Returns `None` for other notations such as
+    /// `"1e3 kHz"`, for more than 19 whole or 18 fractional digits, for unknown units and for
+    /// values that do not fit.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::parse_plain("2.4 GHz"), Some(Frequency::from_mhz(2_400)));
+    /// assert_eq!(Frequency::parse_plain("1e3 kHz"), None);
+    /// ```
+    #[must_use]
+    pub fn parse_plain(s: &str) -> Option<Self> {
+        FrequencyOf::<u64>::parse_plain(s).map(Self::from)
     }
 }
 
@@ -217,6 +664,7 @@ impl TryFrom<&str> for Frequency {
     }
 }
 
+#[cfg(feature = "alloc")]
 impl TryFrom<String> for Frequency {
     type Error = Error;
 
@@ -225,9 +673,107 @@ impl TryFrom<String> for Frequency {
     }
 }
 
+impl TryFrom<core::time::Duration> for Frequency {
+    type Error = Error;
+
+    /// Converts a period to its frequency, rounded to the nearest hertz with ties rounding up.
+    ///
+    /// Fails for a zero duration, and for durations longer than 2 seconds since their frequency
+    /// rounds to `0 Hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use core::time::Duration;
+    /// use parse_frequency::Frequency;
+    ///
+    /// let freq = Frequency::try_from(Duration::from_micros(125)).unwrap();
+    /// assert_eq!(freq, Frequency::from_khz(8));
+    ///
+    /// // 1 / 3 ms is 333.33 Hz
+    /// assert_eq!(Frequency::try_from(Duration::from_millis(3)), Ok(Frequency::from_hz(333)));
+    ///
+    /// assert!(Frequency::try_from(Duration::ZERO).is_err());
+    /// assert!(Frequency::try_from(Duration::from_secs(3)).is_err());
+    /// ```
+    // The frequency never exceeds 1 GHz
+    #[allow(clippy::cast_possible_truncation)]
+    fn try_from(period: core::time::Duration) -> Result<Self> {
+        match Rounding::Nearest.div(u128::from(GIGAHERTZ), period.as_nanos()) {
+            Some(0) | None => Err(Error::invalid_value(format_args!("{period:?}"))),
+            Some(hz) => Ok(Self(hz as u64)),
+        }
+    }
+}
+
+impl Frequency {
+    /// Adds two frequencies, or returns `None` if the result does not fit.
+    ///
+    /// Unlike the `+` operator, this never panics. The checked methods are named `try_*` so they
+    /// do not shadow `num_traits::CheckedAdd` and friends, which take their arguments by
+    /// reference.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let carrier = Frequency::from_mhz(2_400);
+    /// assert_eq!(carrier.try_add(Frequency::from_mhz(5)), Some(Frequency::from_mhz(2_405)));
+    /// assert_eq!(Frequency(u64::MAX).try_add(Frequency::HERTZ), None);
+    /// ```
+    #[must_use]
+    pub const fn try_add(self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    /// Subtracts `other`, or returns `None` if the result would be negative.
+    #[must_use]
+    pub const fn try_sub(self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if the result does not fit.
+    #[must_use]
+    pub const fn try_mul(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    /// Divides by `rhs`, or returns `None` if `rhs` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::from_mhz(48).try_div(4), Some(Frequency::from_mhz(12)));
+    /// assert_eq!(Frequency::from_mhz(48).try_div(0), None);
+    /// ```
+    #[must_use]
+    pub const fn try_div(self, rhs: u64) -> Option<Self> {
+        match self.0.checked_div(rhs) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+}
+
 impl Add for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`Frequency::try_add`] for a checked alternative.
     fn add(self, other: Self) -> Self::Output {
         Self(self.0 + other.0)
     }
@@ -236,6 +782,10 @@ impl Add for Frequency {
 impl Sub for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if the result would be negative and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`Frequency::try_sub`] for a checked alternative.
     fn sub(self, other: Self) -> Self::Output {
         Self(self.0 - other.0)
     }
@@ -244,6 +794,10 @@ impl Sub for Frequency {
 impl Mul<u64> for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`Frequency::try_mul`] for a checked alternative.
     fn mul(self, rhs: u64) -> Self::Output {
         Self(self.0 * rhs)
     }
@@ -252,6 +806,10 @@ impl Mul<u64> for Frequency {
 impl Div<u64> for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`Frequency::try_div`] for a checked alternative, or divide
+    /// by a [`NonZeroU64`](core::num::NonZeroU64) with [`NonZeroFrequency::divide`].
     fn div(self, rhs: u64) -> Self::Output {
         Self(self.0 / rhs)
     }
@@ -281,33 +839,202 @@ impl Div<u64> for Frequency {
 /// # Errors
 ///
 /// If the input string does not match any of the expected formats (e.g., "1GHz", "2.5MHz", etc.), an error is returned.
+/// An error is also returned if the frequency does not fit in a `u64`.
 pub fn parse_frequency(s: &str) -> Result<Frequency> {
-    let s = s.trim().to_lowercase();
-
-    let (value_str, multiplier) = if let Some(value) = s.strip_suffix("ghz") {
-        (value, 1_000_000_000)
-    } else if let Some(value) = s.strip_suffix("mhz") {
-        (value, 1_000_000)
-    } else if let Some(value) = s.strip_suffix("khz") {
-        (value, 1_000)
-    } else if let Some(value) = s.strip_suffix("hz") {
-        (value, 1)
-    } else {
-        return Err(Error::UnknownUnit(s.to_string()));
-    };
+    parse_frequency_as::<u64>(s).map(Frequency::from)
+}
+
+/// Parses each string of `inputs` like [`parse_frequency`], lazily and in order.
+///
+/// The parser keeps no state between inputs, so there is no setup to amortize: every string goes
+/// through the same allocation-free fast path, and only rejected inputs allocate for their
+/// error. Enable the `rayon` feature for [`par_parse_many`], which parses on all cores.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, parse_many};
+///
+/// let csv = "channel,frequency\n1,2412 MHz\n6,2437 MHz\n11,2.462 GHz\n";
+/// let column = csv.lines().skip(1).filter_map(|row| row.split(',').nth(1));
+///
+/// let freqs: Vec<Frequency> = parse_many(column).collect::<Result<_, _>>().unwrap();
+/// assert_eq!(freqs[0], Frequency::from_mhz(2_412));
+/// assert_eq!(freqs[2], Frequency::from_mhz(2_462));
+///
+/// // Errors are reported per input, so bad rows can be skipped or counted
+/// let owned = vec![String::from("48 kHz"), String::from("fast")];
+/// let valid = parse_many(&owned).filter(Result::is_ok).count();
+/// assert_eq!(valid, 1);
+/// ```
+pub fn parse_many<I>(inputs: I) -> impl Iterator<Item = Result<Frequency>>
+where
+    I: IntoIterator,
+    I::Item: AsRef<str>,
+{
+    inputs
+        .into_iter()
+        .map(|input| parse_frequency(input.as_ref()))
+}
+
+/// Parses a frequency string into a frequency with any storage type, failing if the value does
+/// not fit.
+fn parse_frequency_as<T: FrequencyInt>(s: &str) -> Result<FrequencyOf<T>> {
+    // Most inputs are plain decimals like "2400 MHz", which a single pass over the bytes handles
+    // without going through floating point
+    if let Some(freq) = FrequencyOf::parse_plain(s) {
+        return Ok(freq);
+    }
+
+    parse_frequency_general(s)
+}
+
+/// Parses a frequency string of any notation the parser accepts, such as `"1e3 kHz"`, and
+/// reports why it failed.
+fn parse_frequency_general<T: FrequencyInt>(s: &str) -> Result<FrequencyOf<T>> {
+    parse_hz_with(s, |hz, value, multiplier| {
+        // Plain decimals are parsed exactly so large values keep every digit, other notations
+        // such as exponents go through floating point
+        // It is OK to lose sign and precision here, out of range values are rejected below.
+        // `hz` is never negative, so adding a half rounds it like `f64::round`, which needs std
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let hz = math::parse_decimal(value, u128::from(multiplier)).unwrap_or((hz + 0.5) as u128);
+        T::from_u128(hz).map(FrequencyOf)
+    })
+}
+
+/// Parses a frequency string into a non-negative, finite value in hertz and passes it to
+/// `convert`, along with the trimmed number and the multiplier of its unit. Returns an error if
+/// the unit is unknown, the value is invalid or `convert` fails.
+fn parse_hz_with<R>(s: &str, convert: impl FnOnce(f64, &str, u32) -> Option<R>) -> Result<R> {
+    let s = s.trim();
+    let (value_str, multiplier) = split_unit(s).ok_or_else(|| Error::unknown_unit(Lowercase(s)))?;
 
     let value = value_str
         .trim()
         .parse::<f64>()
-        .map_err(|_| Error::InvalidValue(value_str.to_string()))?;
+        .map_err(|_| Error::invalid_value(Lowercase(value_str)))?;
 
     // Negative values are not allowed
+    if value.is_sign_negative() || !value.is_finite() {
+        return Err(Error::invalid_value(Lowercase(value_str)));
+    }
+
+    convert(value * f64::from(multiplier), value_str.trim(), multiplier)
+        .ok_or_else(|| Error::invalid_value(Lowercase(value_str)))
+}
+
+/// Splits a frequency string into the number before its unit and the multiplier of that unit in
+/// hertz, matching the unit case-insensitively. Returns `None` if the unit is unknown.
+fn split_unit(s: &str) -> Option<(&str, u32)> {
+    [
+        ("ghz", 1_000_000_000),
+        ("mhz", 1_000_000),
+        ("khz", 1_000),
+        ("hz", 1),
+    ]
+    .into_iter()
+    .find_map(|(unit, multiplier)| {
+        strip_suffix_ignore_case(s, unit).map(|value| (value, multiplier))
+    })
+}
+
+/// Parses a finite number followed by one of `suffixes`, ignoring ASCII case and surrounding
+/// whitespace, and passes it to `convert`. Every error, including `convert` returning `None`,
+/// reports the whole input.
+fn parse_suffixed_f64<R>(
+    s: &str,
+    suffixes: &[&str],
+    convert: impl FnOnce(f64) -> Option<R>,
+) -> Result<R> {
+    let value_str = suffixes
+        .iter()
+        .find_map(|suffix| strip_suffix_ignore_case(s.trim(), suffix))
+        .ok_or_else(|| Error::unknown_unit(s))?;
+
+    value_str
+        .trim()
+        .parse::<f64>()
+        .ok()
+        .filter(|value| value.is_finite())
+        .and_then(convert)
+        .ok_or_else(|| Error::invalid_value(s))
+}
+
+/// Rounds `value` to the nearest integer, or returns `None` if it is negative, not finite or
+/// does not fit in a `u64`.
+#[cfg(feature = "std")]
+fn round_to_u64(value: f64) -> Option<u64> {
+    // A parsed negative zero such as "-0 rpm" is rejected like any other negative value
     if value.is_sign_negative() {
-        return Err(Error::InvalidValue(value_str.to_string()));
+        return None;
     }
 
-    // It is OK to lose sign and precision here
-    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
-    let hz = (value * f64::from(multiplier)).round() as u64;
-    Ok(Frequency(hz))
+    FrequencyF64(value)
+        .try_to_frequency(Rounding::Nearest)
+        .map(u64::from)
+}
+
+/// Returns `s` without `suffix`, ignoring ASCII case, or `None` if it does not end with it.
+fn strip_suffix_ignore_case<'a>(s: &'a str, suffix: &str) -> Option<&'a str> {
+    let split = s.len().checked_sub(suffix.len())?;
+    let (value, end) = (s.get(..split)?, s.get(split..)?);
+    end.eq_ignore_ascii_case(suffix).then_some(value)
+}
+
+/// Displays a string in lowercase without allocating
+struct Lowercase<'a>(&'a str);
+
+impl Display for Lowercase<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0
+            .chars()
+            .flat_map(char::to_lowercase)
+            .try_for_each(|c| core::fmt::Write::write_char(f, c))
+    }
+}
+
+/// Writes `value` in decimal, padded with leading zeros to at least `min_digits` digits
+// A digit is always below 10, so truncation cannot occur
+#[allow(clippy::cast_possible_truncation)]
+fn write_digits(
+    w: &mut impl core::fmt::Write,
+    mut value: u128,
+    min_digits: usize,
+) -> core::fmt::Result {
+    // `u128::MAX` has 39 digits
+    let mut buffer = [0u8; 39];
+    let mut len = 0;
+
+    for slot in buffer.iter_mut().rev() {
+        if value == 0 && len >= min_digits {
+            break;
+        }
+
+        *slot = b'0' + (value % 10) as u8;
+        value /= 10;
+        len += 1;
+    }
+
+    let digits = buffer
+        .get(buffer.len() - len..)
+        .and_then(|digits| core::str::from_utf8(digits).ok())
+        .ok_or(core::fmt::Error)?;
+    w.write_str(digits)
+}
+
+/// Writes `hz` scaled to GHz, MHz or kHz with two decimals, or returns `None` if it is below
+/// 1 kHz.
+// Precision loss is acceptable here
+#[allow(clippy::cast_precision_loss)]
+fn fmt_scaled(f: &mut core::fmt::Formatter<'_>, hz: f64) -> Option<core::fmt::Result> {
+    if hz >= GIGAHERTZ as f64 {
+        Some(write!(f, "{:.2} GHz", hz / GIGAHERTZ as f64))
+    } else if hz >= MEGAHERTZ as f64 {
+        Some(write!(f, "{:.2} MHz", hz / MEGAHERTZ as f64))
+    } else if hz >= KILOHERTZ as f64 {
+        Some(write!(f, "{:.2} kHz", hz / KILOHERTZ as f64))
+    } else {
+        None
+    }
 }
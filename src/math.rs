@@ -0,0 +1,178 @@
+use crate::Rounding;
+
+/// Returns the greatest common divisor of `a` and `b`.
+///
+/// `gcd(0, 0)` is defined as `0`.
+pub(crate) fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+/// Returns the relative error in parts per million of the rate `numerator / denominator` with
+/// respect to `target`, computed exactly before the final conversion to floating point.
+///
+/// Positive values mean the actual rate is above the target.
+// Precision loss is acceptable here
+#[allow(clippy::cast_precision_loss, clippy::cast_possible_wrap)]
+pub(crate) fn error_ppm(numerator: u128, denominator: u128, target: u128) -> f64 {
+    let expected = target * denominator;
+    let difference = numerator as i128 - expected as i128;
+    difference as f64 * 1e6 / expected as f64
+}
+
+/// Returns the fraction `(p, q)` with `q <= max_denominator` closest to `numerator / denominator`.
+///
+/// The result is in lowest terms. Both `denominator` and `max_denominator` must be non-zero.
+#[cfg(feature = "std")]
+pub(crate) fn limit_denominator(
+    numerator: u128,
+    denominator: u128,
+    max_denominator: u128,
+) -> (u128, u128) {
+    let divisor = gcd(numerator, denominator).max(1);
+    let (numerator, denominator) = (numerator / divisor, denominator / divisor);
+
+    if denominator <= max_denominator {
+        return (numerator, denominator);
+    }
+
+    // Walk the continued fraction expansion until the next convergent's denominator gets too big
+    let (mut p0, mut q0, mut p1, mut q1) = (0, 1, 1, 0);
+    let (mut n, mut d) = (numerator, denominator);
+
+    loop {
+        let a = n / d;
+        let q2 = q0 + a * q1;
+
+        if q2 > max_denominator {
+            break;
+        }
+
+        (p0, q0, p1, q1) = (p1, q1, p0 + a * p1, q2);
+        (n, d) = (d, n - a * d);
+    }
+
+    // The best approximation is either the last convergent or the largest semiconvergent
+    let k = (max_denominator - q0) / q1;
+    let semiconvergent = (p0 + k * p1, q0 + k * q1);
+    let convergent = (p1, q1);
+
+    // |p / q - x| compared by cross-multiplying with the other denominator
+    let distance = |(p, q): (u128, u128)| (p * denominator).abs_diff(numerator * q);
+    if distance(convergent) * semiconvergent.1 <= distance(semiconvergent) * convergent.1 {
+        convergent
+    } else {
+        semiconvergent
+    }
+}
+
+/// Parses a non-negative decimal number such as `"50.02"` and returns it multiplied by `scale`,
+/// rounded to the nearest integer with ties rounding up.
+///
+/// The value is parsed exactly, without going through floating point. Returns `None` if the
+/// value is not a plain decimal number or the result does not fit in a `u128`.
+pub(crate) fn parse_decimal(value: &str, scale: u128) -> Option<u128> {
+    let value = value.strip_prefix('+').unwrap_or(value);
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+
+    let is_digits = |digits: &str| digits.bytes().all(|b| b.is_ascii_digit());
+    if (whole.is_empty() && fraction.is_empty()) || !is_digits(whole) || !is_digits(fraction) {
+        return None;
+    }
+
+    let mut result = 0u128;
+    for digit in whole.bytes() {
+        result = result
+            .checked_mul(10)?
+            .checked_add(u128::from(digit - b'0'))?;
+    }
+    result = result.checked_mul(scale)?;
+
+    // Digits beyond the 24th cannot affect the rounded result for scales up to 10^12, and
+    // ignoring them keeps the intermediate product within a u128
+    let mut numerator = 0u128;
+    let mut denominator = 1u128;
+    for digit in fraction.bytes().take(24) {
+        numerator = numerator * 10 + u128::from(digit - b'0');
+        denominator *= 10;
+    }
+
+    let fraction = Rounding::Nearest.div(numerator.checked_mul(scale)?, denominator)?;
+    result.checked_add(fraction)
+}
+
+/// Parses a frequency with a plain decimal value such as `"2400 MHz"` or `" 2.437ghz"` in a
+/// single pass over its bytes, and returns it in hertz rounded to the nearest integer with ties
+/// rounding up.
+///
+/// This is the fast path for the common case of ASCII whitespace, an optional `+`, up to 19
+/// whole and 18 fractional digits and a unit. Anything else returns `None` so the caller can
+/// fall back to the general parser, which gives the same results for every input accepted
+/// here.
+#[inline]
+pub(crate) fn parse_plain_hz(s: &str) -> Option<u128> {
+    let mut bytes = s.as_bytes().trim_ascii_start();
+    if let [b'+', rest @ ..] = bytes {
+        bytes = rest;
+    }
+
+    let (whole, whole_digits, rest) = parse_digits(bytes, 19)?;
+    let (fraction, fraction_digits, rest) = match rest {
+        [b'.', rest @ ..] => parse_digits(rest, 18)?,
+        _ => (0, 0, rest),
+    };
+
+    if whole_digits + fraction_digits == 0 {
+        return None;
+    }
+
+    let multiplier: u64 = match rest.trim_ascii() {
+        [b'h' | b'H', b'z' | b'Z'] => 1,
+        [b'k' | b'K', b'h' | b'H', b'z' | b'Z'] => 1_000,
+        [b'm' | b'M', b'h' | b'H', b'z' | b'Z'] => 1_000_000,
+        [b'g' | b'G', b'h' | b'H', b'z' | b'Z'] => 1_000_000_000,
+        _ => return None,
+    };
+
+    // The whole part is below 2^64 and the multiplier below 2^30, so the product fits
+    let whole = u128::from(whole) * u128::from(multiplier);
+    if fraction_digits == 0 {
+        return Some(whole);
+    }
+
+    // Fractions with up to 9 digits stay within a u64 after scaling, which divides faster
+    let scale = 10u64.pow(fraction_digits);
+    let fraction = if let Some(scaled) = fraction.checked_mul(multiplier) {
+        // The checks cannot fail, but the optimizer cannot tell that a power of ten is non-zero
+        let (quotient, remainder) = (scaled.checked_div(scale)?, scaled.checked_rem(scale)?);
+        u128::from(quotient + u64::from(remainder >= scale - remainder))
+    } else {
+        let scaled = u128::from(fraction) * u128::from(multiplier);
+        Rounding::Nearest.div(scaled, u128::from(scale))?
+    };
+    Some(whole + fraction)
+}
+
+/// Parses the leading ASCII digits of `bytes`, and returns their value, their count and the
+/// rest of the bytes, or `None` if there are more than `max_digits` of them
+fn parse_digits(bytes: &[u8], max_digits: u32) -> Option<(u64, u32, &[u8])> {
+    let mut value = 0u64;
+    let mut digits = 0;
+    let mut rest = bytes;
+
+    while let [digit @ b'0'..=b'9', tail @ ..] = rest {
+        if digits == max_digits {
+            return None;
+        }
+
+        // At most 19 digits always fit in a u64
+        value = value * 10 + u64::from(digit - b'0');
+        digits += 1;
+        rest = tail;
+    }
+
+    Some((value, digits, rest))
+}
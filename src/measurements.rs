@@ -0,0 +1,23 @@
+use crate::{Error, Frequency, FrequencyF64, Rounding};
+
+impl From<Frequency> for measurements::Frequency {
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn from(freq: Frequency) -> Self {
+        Self::from_hertz(freq.0 as f64)
+    }
+}
+
+impl TryFrom<measurements::Frequency> for Frequency {
+    type Error = Error;
+
+    /// Converts to the nearest whole number of hertz.
+    ///
+    /// Fails for negative, NaN and out of range frequencies.
+    fn try_from(freq: measurements::Frequency) -> Result<Self, Self::Error> {
+        let hz = freq.as_hertz();
+        FrequencyF64::from_hz(hz)
+            .try_to_frequency(Rounding::Nearest)
+            .ok_or_else(|| Error::invalid_value(format_args!("{hz} Hz")))
+    }
+}
@@ -0,0 +1,47 @@
+//! Helpers to record frequencies with the `metrics` crate
+//!
+//! Frequencies are recorded in hertz, the base unit, and described with
+//! [`Unit::CountPerSecond`], the closest unit `metrics` has. Use the `describe_*` functions
+//! once at startup so exporters know the unit, and the `record_*` methods on [`Frequency`] to
+//! record values.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use parse_frequency::{Frequency, metrics::describe_gauge};
+//!
+//! describe_gauge("cpu_freq", "Current CPU clock frequency");
+//!
+//! let freq: Frequency = "3.6 GHz".parse().unwrap();
+//! freq.record_gauge("cpu_freq");
+//! ```
+
+use metrics::{KeyName, SharedString, Unit};
+
+use crate::Frequency;
+
+/// Describes a gauge of frequencies in hertz.
+pub fn describe_gauge(name: impl Into<KeyName>, description: impl Into<SharedString>) {
+    metrics::describe_gauge!(name, Unit::CountPerSecond, description);
+}
+
+/// Describes a histogram of frequencies in hertz.
+pub fn describe_histogram(name: impl Into<KeyName>, description: impl Into<SharedString>) {
+    metrics::describe_histogram!(name, Unit::CountPerSecond, description);
+}
+
+impl Frequency {
+    /// Sets the gauge `name` to this frequency in hertz.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record_gauge(&self, name: impl Into<KeyName>) {
+        metrics::gauge!(name).set(self.0 as f64);
+    }
+
+    /// Records this frequency in hertz in the histogram `name`.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record_histogram(&self, name: impl Into<KeyName>) {
+        metrics::histogram!(name).record(self.0 as f64);
+    }
+}
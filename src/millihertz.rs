@@ -0,0 +1,240 @@
+use core::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+use crate::{
+    Error, Frequency, GIGAHERTZ, KILOHERTZ, Lowercase, MEGAHERTZ, Result, Rounding, math,
+    parse_hz_with,
+};
+
+/// Millihertz in one hertz
+const MILLIHERTZ_PER_HERTZ: u64 = 1_000;
+
+/// Represents a frequency with millihertz resolution
+///
+/// This struct is a wrapper around a `u64` value representing the frequency in millihertz, which
+/// covers frequencies up to about 18 PHz. Converting to a [`Frequency`] requires choosing a
+/// rounding mode with [`FrequencyMilliHz::to_frequency`], while converting from a [`Frequency`]
+/// is lossless as long as the result fits.
+///
+/// Parsing is done exactly, without floating point. Values with more than three decimals of a
+/// hertz are rounded to the nearest millihertz.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyMilliHz, Rounding};
+///
+/// let grid: FrequencyMilliHz = "50.02 Hz".parse().unwrap();
+/// assert_eq!(grid.as_millihertz(), 50_020);
+/// assert_eq!(grid.to_string(), "50.020 Hz");
+/// assert_eq!(grid.to_frequency(Rounding::Nearest), Frequency::from_hz(50));
+///
+/// let slow: FrequencyMilliHz = "250 mHz".parse().unwrap();
+/// assert_eq!(slow, FrequencyMilliHz(250));
+///
+/// let exact = FrequencyMilliHz::try_from(Frequency::from_khz(20)).unwrap();
+/// assert_eq!(exact.to_string(), "20.000 kHz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct FrequencyMilliHz(pub u64);
+
+impl FrequencyMilliHz {
+    /// Equivalent to `0 mHz`
+    pub const ZERO: Self = Self(0);
+
+    /// Equivalent to `1 mHz`
+    pub const MILLIHERTZ: Self = Self(1);
+
+    /// Equivalent to `1 Hz`
+    pub const HERTZ: Self = Self(MILLIHERTZ_PER_HERTZ);
+
+    #[must_use]
+    #[doc(alias = "from_mhz")]
+    pub fn from_millihertz(millihertz: u64) -> Self {
+        Self(millihertz)
+    }
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. Use `FrequencyMilliHz::try_from(Frequency::from_hz(hz))` for
+    /// a checked alternative.
+    #[must_use]
+    #[doc(alias = "from_hertz")]
+    pub fn from_hz(hz: u64) -> Self {
+        Self(hz * MILLIHERTZ_PER_HERTZ)
+    }
+
+    #[must_use]
+    pub fn as_millihertz(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the frequency in hertz as a floating point number.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_hz_f64(&self) -> f64 {
+        self.0 as f64 / MILLIHERTZ_PER_HERTZ as f64
+    }
+
+    /// Converts to a whole number of hertz, rounding as requested.
+    #[must_use]
+    // The quotient is at most u64::MAX / 1000, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_frequency(&self, rounding: Rounding) -> Frequency {
+        let hz = rounding.div(u128::from(self.0), u128::from(MILLIHERTZ_PER_HERTZ));
+        Frequency(hz.unwrap_or_default() as u64)
+    }
+
+    /// Converts to a whole number of hertz if no precision is lost.
+    #[must_use]
+    pub fn to_frequency_exact(&self) -> Option<Frequency> {
+        self.0
+            .is_multiple_of(MILLIHERTZ_PER_HERTZ)
+            .then_some(Frequency(self.0 / MILLIHERTZ_PER_HERTZ))
+    }
+
+    /// Adds two frequencies, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtracts `other`, or returns `None` if the result would be negative.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// Divides by `rhs`, or returns `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        self.0.checked_div(rhs).map(Self)
+    }
+}
+
+impl TryFrom<Frequency> for FrequencyMilliHz {
+    type Error = Error;
+
+    /// Converts a frequency to millihertz, failing if the result does not fit in a `u64`.
+    fn try_from(freq: Frequency) -> Result<Self> {
+        freq.0
+            .checked_mul(MILLIHERTZ_PER_HERTZ)
+            .map(Self)
+            .ok_or_else(|| Error::invalid_value(freq))
+    }
+}
+
+impl Add for FrequencyMilliHz {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`FrequencyMilliHz::checked_add`] for a checked alternative.
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for FrequencyMilliHz {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be negative and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`FrequencyMilliHz::checked_sub`] for a checked
+    /// alternative.
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<u64> for FrequencyMilliHz {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`FrequencyMilliHz::checked_mul`] for a checked alternative.
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<u64> for FrequencyMilliHz {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`FrequencyMilliHz::checked_div`] for a checked alternative.
+    fn div(self, rhs: u64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Display for FrequencyMilliHz {
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let value = self.0;
+        let scaled = |unit: u64| value as f64 / (unit * MILLIHERTZ_PER_HERTZ) as f64;
+
+        if value >= GIGAHERTZ * MILLIHERTZ_PER_HERTZ {
+            write!(f, "{:.3} GHz", scaled(GIGAHERTZ))
+        } else if value >= MEGAHERTZ * MILLIHERTZ_PER_HERTZ {
+            write!(f, "{:.3} MHz", scaled(MEGAHERTZ))
+        } else if value >= KILOHERTZ * MILLIHERTZ_PER_HERTZ {
+            write!(f, "{:.3} kHz", scaled(KILOHERTZ))
+        } else if value >= MILLIHERTZ_PER_HERTZ {
+            // Below 1 kHz the value is printed exactly
+            let (hz, millihertz) = (value / MILLIHERTZ_PER_HERTZ, value % MILLIHERTZ_PER_HERTZ);
+            write!(f, "{hz}.{millihertz:03} Hz")
+        } else {
+            write!(f, "{value} mHz")
+        }
+    }
+}
+
+impl FromStr for FrequencyMilliHz {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // "mHz" and "MHz" only differ in case, so millihertz is matched case-sensitively
+        if let Some(value_str) = s.trim().strip_suffix("mHz") {
+            return math::parse_decimal(value_str.trim(), 1)
+                .and_then(|millihertz| u64::try_from(millihertz).ok())
+                .map(Self)
+                .ok_or_else(|| Error::invalid_value(Lowercase(value_str)));
+        }
+
+        // Parsed exactly rather than through floating point, so no millihertz are lost
+        parse_hz_with(s, |_, value, multiplier| {
+            math::parse_decimal(
+                value,
+                u128::from(multiplier) * u128::from(MILLIHERTZ_PER_HERTZ),
+            )
+            .and_then(|millihertz| u64::try_from(millihertz).ok())
+            .map(Self)
+        })
+    }
+}
+
+impl TryFrom<&str> for FrequencyMilliHz {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
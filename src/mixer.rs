@@ -0,0 +1,123 @@
+use std::fmt::Display;
+
+use crate::Frequency;
+
+/// A mixing or intermodulation product `|m * f1 + n * f2|` of two signals
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, intermodulation_products};
+///
+/// let f1 = Frequency::from_mhz(100);
+/// let f2 = Frequency::from_mhz(101);
+///
+/// // Third-order products land right next to the wanted signals
+/// let third: Vec<String> = intermodulation_products(f1, f2, 3)
+///     .filter(|product| product.order == 3)
+///     .filter(|product| product.frequency < Frequency::from_mhz(200))
+///     .map(|product| format!("{product}: {}", product.frequency))
+///     .collect();
+///
+/// assert_eq!(third, ["2f1 - f2: 99.00 MHz", "f1 - 2f2: 102.00 MHz"]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MixerProduct {
+    /// The multiple of the first signal, `m`
+    pub f1_coefficient: i32,
+
+    /// The multiple of the second signal, `n`
+    pub f2_coefficient: i32,
+
+    /// The order of the product, `|m| + |n|`
+    pub order: u32,
+
+    /// The resulting frequency, `|m * f1 + n * f2|`, saturating at `u64::MAX` hertz
+    pub frequency: Frequency,
+}
+
+impl Display for MixerProduct {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut first = true;
+
+        for (coefficient, name) in [(self.f1_coefficient, "f1"), (self.f2_coefficient, "f2")] {
+            if coefficient == 0 {
+                continue;
+            }
+
+            match (first, coefficient < 0) {
+                (true, true) => write!(f, "-")?,
+                (false, true) => write!(f, " - ")?,
+                (false, false) => write!(f, " + ")?,
+                (true, false) => {}
+            }
+
+            if coefficient.unsigned_abs() != 1 {
+                write!(f, "{}", coefficient.unsigned_abs())?;
+            }
+            write!(f, "{name}")?;
+            first = false;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the sum and difference frequencies `(f1 + f2, |f1 - f2|)` produced by an ideal mixer.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, mixer_products};
+///
+/// let rf = Frequency::from_khz(100_700);
+/// let lo = Frequency::from_khz(90_000);
+///
+/// let (sum, difference) = mixer_products(rf, lo);
+/// assert_eq!(sum, Frequency::from_khz(190_700));
+/// assert_eq!(difference, Frequency::from_khz(10_700));
+/// ```
+#[must_use]
+pub fn mixer_products(f1: Frequency, f2: Frequency) -> (Frequency, Frequency) {
+    (
+        Frequency(f1.0.saturating_add(f2.0)),
+        Frequency(f1.0.abs_diff(f2.0)),
+    )
+}
+
+/// Returns every product `|m * f1 + n * f2|` up to `max_order` (`|m| + |n|`), including the
+/// fundamentals and harmonics of each signal.
+///
+/// Products are yielded by ascending order. Since `m * f1 + n * f2` and its negation give the
+/// same frequency, only combinations whose first non-zero coefficient is positive are
+/// included.
+pub fn intermodulation_products(
+    f1: Frequency,
+    f2: Frequency,
+    max_order: u32,
+) -> impl Iterator<Item = MixerProduct> {
+    // Orders beyond i32 cannot be represented by the coefficients
+    let max_order = max_order.min(i32::MAX.unsigned_abs());
+
+    (1..=max_order).flat_map(move |order| {
+        (0..=order).rev().flat_map(move |m| {
+            let n = order - m;
+            let signs: &[i32] = if m == 0 || n == 0 { &[1] } else { &[1, -1] };
+
+            signs.iter().map(move |&sign| {
+                // Both values are at most max_order, which fits in an i32
+                #[allow(clippy::cast_possible_wrap)]
+                let (m, n) = (m as i32, sign * n as i32);
+                let hz = (i128::from(m) * i128::from(f1.0) + i128::from(n) * i128::from(f2.0))
+                    .unsigned_abs();
+
+                MixerProduct {
+                    f1_coefficient: m,
+                    f2_coefficient: n,
+                    order,
+                    frequency: Frequency(u64::try_from(hz).unwrap_or(u64::MAX)),
+                }
+            })
+        })
+    })
+}
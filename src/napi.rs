@@ -0,0 +1,163 @@
+use napi::{Error, Result, Status, bindgen_prelude::BigInt};
+use napi_derive::napi;
+
+use crate::{Frequency, FrequencyF64, FrequencyUnit};
+
+/// Parses a frequency string such as `"2.4 GHz"` into a `BigInt` number of hertz.
+///
+/// Exported to Node.js as `parseFrequency`.
+///
+/// # Errors
+///
+/// Throws the parse error message for invalid strings.
+#[napi(js_name = "parseFrequency")]
+pub fn napi_parse_frequency(s: String) -> Result<BigInt> {
+    parse(&s).map(|freq| BigInt::from(freq.0))
+}
+
+/// Formats a `BigInt` number of hertz like `"2.40 GHz"`.
+///
+/// Exported to Node.js as `formatFrequency`.
+///
+/// # Errors
+///
+/// Throws for negative or out of range numbers.
+#[napi(js_name = "formatFrequency")]
+pub fn napi_format_frequency(hz: BigInt) -> Result<String> {
+    from_bigint(&hz).map(|freq| freq.to_string())
+}
+
+/// Formats a `BigInt` number of hertz exactly, like `"2.400000001 GHz"`.
+///
+/// Exported to Node.js as `formatFrequencyExact`.
+///
+/// # Errors
+///
+/// Throws for negative or out of range numbers.
+#[napi(js_name = "formatFrequencyExact")]
+pub fn napi_format_frequency_exact(hz: BigInt) -> Result<String> {
+    from_bigint(&hz).map(|freq| freq.to_exact_string())
+}
+
+/// Converts a `BigInt` number of hertz to a number of `unit`, such as `"MHz"`, with a
+/// fractional part.
+///
+/// Exported to Node.js as `convertFrequency`.
+///
+/// # Errors
+///
+/// Throws for negative or out of range numbers and unknown units.
+#[napi(js_name = "convertFrequency")]
+pub fn napi_convert_frequency(hz: BigInt, unit: String) -> Result<f64> {
+    let freq = from_bigint(&hz)?;
+    let unit: FrequencyUnit = unit.parse().map_err(invalid_arg)?;
+    Ok(in_unit(freq, unit))
+}
+
+/// A [`Frequency`] exported to Node.js as the `Frequency` class
+///
+/// ```js
+/// const { Frequency } = require("parse-frequency");
+///
+/// const freq = new Frequency("2.4 GHz");
+/// freq.asHz(); // 2400000000n
+/// freq.in("MHz"); // 2400
+/// Frequency.fromHz(2400000001n).toExactString(); // "2.400000001 GHz"
+/// ```
+#[napi(js_name = "Frequency")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NapiFrequency(Frequency);
+
+#[napi]
+impl NapiFrequency {
+    /// Parses a frequency string such as `"2.4 GHz"`.
+    ///
+    /// # Errors
+    ///
+    /// Throws the parse error message for invalid strings.
+    #[napi(constructor)]
+    pub fn new(s: String) -> Result<Self> {
+        parse(&s).map(Self)
+    }
+
+    /// Creates a frequency from a `BigInt` number of hertz.
+    ///
+    /// # Errors
+    ///
+    /// Throws for negative or out of range numbers.
+    #[napi(factory)]
+    pub fn from_hz(hz: BigInt) -> Result<Self> {
+        from_bigint(&hz).map(Self)
+    }
+
+    /// Returns the frequency as a `BigInt` number of hertz.
+    #[must_use]
+    #[napi]
+    pub fn as_hz(&self) -> BigInt {
+        BigInt::from(self.0.0)
+    }
+
+    /// Returns the frequency as a number of `unit`, such as `"MHz"`, with a fractional part.
+    ///
+    /// # Errors
+    ///
+    /// Throws for unknown units.
+    #[napi(js_name = "in")]
+    pub fn in_unit(&self, unit: String) -> Result<f64> {
+        let unit: FrequencyUnit = unit.parse().map_err(invalid_arg)?;
+        Ok(in_unit(self.0, unit))
+    }
+
+    /// Formats the frequency like `"2.40 GHz"`.
+    #[must_use]
+    #[napi(js_name = "toString")]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Formats the frequency exactly, like `"2.400000001 GHz"`.
+    #[must_use]
+    #[napi]
+    pub fn to_exact_string(&self) -> String {
+        self.0.to_exact_string()
+    }
+}
+
+impl From<Frequency> for NapiFrequency {
+    fn from(freq: Frequency) -> Self {
+        Self(freq)
+    }
+}
+
+impl From<NapiFrequency> for Frequency {
+    fn from(freq: NapiFrequency) -> Self {
+        freq.0
+    }
+}
+
+fn parse(s: &str) -> Result<Frequency> {
+    s.parse().map_err(invalid_arg)
+}
+
+fn from_bigint(hz: &BigInt) -> Result<Frequency> {
+    let (signed, hz, lossless) = hz.get_u64();
+    if signed || !lossless {
+        return Err(Error::new(
+            Status::InvalidArg,
+            "frequency must be a non-negative number of hertz below 2^64",
+        ));
+    }
+
+    Ok(Frequency(hz))
+}
+
+// Precision loss is acceptable here
+#[allow(clippy::cast_precision_loss)]
+fn in_unit(freq: Frequency, unit: FrequencyUnit) -> f64 {
+    FrequencyF64::from_frequency(freq).as_hz() / unit.multiplier() as f64
+}
+
+#[allow(clippy::needless_pass_by_value)]
+fn invalid_arg(err: crate::Error) -> Error {
+    Error::new(Status::InvalidArg, err.to_string())
+}
@@ -0,0 +1,152 @@
+use core::{fmt::Display, num::NonZeroU64, ops::Div, str::FromStr, time::Duration};
+
+use crate::{Error, Frequency, GIGAHERTZ, Result};
+
+/// Represents a frequency that is never `0 Hz`
+///
+/// Periods and dividers are only meaningful for non-zero frequencies, so with this type they
+/// cannot fail and need no special case for `0 Hz`. Like [`NonZeroU64`], which it wraps,
+/// `Option<NonZeroFrequency>` is the same size as a `u64`.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{Frequency, NonZeroFrequency};
+///
+/// let tick: NonZeroFrequency = "1 kHz".parse().unwrap();
+/// assert_eq!(tick.period(), Duration::from_millis(1));
+/// assert_eq!(Frequency::from_mhz(1) / tick, 1_000);
+///
+/// assert!(NonZeroFrequency::try_from(Frequency::ZERO).is_err());
+/// assert!("0 Hz".parse::<NonZeroFrequency>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct NonZeroFrequency(NonZeroU64);
+
+impl NonZeroFrequency {
+    /// Equivalent to `1 Hz`, the lowest non-zero frequency
+    pub const HERTZ: Self = Self(NonZeroU64::MIN);
+
+    /// The highest representable frequency
+    pub const MAX: Self = Self(NonZeroU64::MAX);
+
+    /// Returns `freq` as a non-zero frequency, or `None` if it is `0 Hz`.
+    #[must_use]
+    pub const fn new(freq: Frequency) -> Option<Self> {
+        match NonZeroU64::new(freq.0) {
+            Some(hz) => Some(Self(hz)),
+            None => None,
+        }
+    }
+
+    #[must_use]
+    #[doc(alias = "from_hertz")]
+    pub const fn from_hz(hz: NonZeroU64) -> Self {
+        Self(hz)
+    }
+
+    /// Returns the frequency as a [`Frequency`].
+    #[must_use]
+    pub const fn get(self) -> Frequency {
+        Frequency(self.0.get())
+    }
+
+    #[must_use]
+    #[doc(alias = "as_hertz")]
+    pub const fn as_hz(self) -> NonZeroU64 {
+        self.0
+    }
+
+    /// Returns the period, rounded down to whole nanoseconds like [`Frequency::as_duration`].
+    ///
+    /// Periods of frequencies above `1 GHz` are shorter than a nanosecond and round down to
+    /// zero; see [`NonZeroFrequency::period_secs_f64`] for those.
+    #[must_use]
+    pub const fn period(self) -> Duration {
+        Duration::from_nanos(GIGAHERTZ / self.0.get())
+    }
+
+    /// Returns the period in seconds.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn period_secs_f64(self) -> f64 {
+        1.0 / self.0.get() as f64
+    }
+
+    /// Returns the frequency divided by `divider`, rounded down to the nearest hertz.
+    ///
+    /// The result is a [`Frequency`], as dividing by more than the frequency gives `0 Hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::num::NonZeroU64;
+    /// use parse_frequency::{Frequency, NonZeroFrequency};
+    ///
+    /// let bus = NonZeroFrequency::try_from(Frequency::from_mhz(72)).unwrap();
+    /// assert_eq!(bus.divide(NonZeroU64::new(8).unwrap()), Frequency::from_mhz(9));
+    /// ```
+    #[must_use]
+    pub const fn divide(self, divider: NonZeroU64) -> Frequency {
+        Frequency(self.0.get() / divider.get())
+    }
+}
+
+impl Default for NonZeroFrequency {
+    fn default() -> Self {
+        Self::HERTZ
+    }
+}
+
+impl TryFrom<Frequency> for NonZeroFrequency {
+    type Error = Error;
+
+    fn try_from(freq: Frequency) -> Result<Self> {
+        Self::new(freq).ok_or_else(|| Error::invalid_value(freq))
+    }
+}
+
+impl From<NonZeroFrequency> for Frequency {
+    fn from(freq: NonZeroFrequency) -> Self {
+        freq.get()
+    }
+}
+
+impl From<NonZeroU64> for NonZeroFrequency {
+    fn from(hz: NonZeroU64) -> Self {
+        Self(hz)
+    }
+}
+
+impl From<NonZeroFrequency> for NonZeroU64 {
+    fn from(freq: NonZeroFrequency) -> Self {
+        freq.0
+    }
+}
+
+impl Div<NonZeroFrequency> for Frequency {
+    type Output = u64;
+
+    /// Returns how many whole times `rhs` fits in the frequency, such as the integer divider
+    /// from a source clock down to `rhs`.
+    fn div(self, rhs: NonZeroFrequency) -> Self::Output {
+        self.0 / rhs.0.get()
+    }
+}
+
+impl Display for NonZeroFrequency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.get().fmt(f)
+    }
+}
+
+impl FromStr for NonZeroFrequency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::try_from(s.parse::<Frequency>()?)
+    }
+}
@@ -4,6 +4,10 @@ use std::ops::{Div, Mul, Rem};
 impl Mul for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See `num_traits::CheckedMul` for a checked alternative.
     fn mul(self, rhs: Self) -> Self::Output {
         Frequency(self.0 * rhs.0)
     }
@@ -12,6 +16,9 @@ impl Mul for Frequency {
 impl Div for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See `num_traits::CheckedDiv` for a checked alternative.
     fn div(self, rhs: Self) -> Self::Output {
         Frequency(self.0 / rhs.0)
     }
@@ -20,6 +27,9 @@ impl Div for Frequency {
 impl Rem for Frequency {
     type Output = Self;
 
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See `num_traits::CheckedRem` for a checked alternative.
     fn rem(self, rhs: Self) -> Self::Output {
         Frequency(self.0 % rhs.0)
     }
@@ -48,3 +58,112 @@ impl num_traits::Num for Frequency {
         s.parse::<u64>().map(Frequency)
     }
 }
+
+impl num_traits::Bounded for Frequency {
+    fn min_value() -> Self {
+        Frequency::ZERO
+    }
+
+    fn max_value() -> Self {
+        Frequency(u64::MAX)
+    }
+}
+
+impl num_traits::CheckedAdd for Frequency {
+    fn checked_add(&self, v: &Self) -> Option<Self> {
+        self.0.checked_add(v.0).map(Frequency)
+    }
+}
+
+impl num_traits::CheckedSub for Frequency {
+    fn checked_sub(&self, v: &Self) -> Option<Self> {
+        self.0.checked_sub(v.0).map(Frequency)
+    }
+}
+
+impl num_traits::CheckedMul for Frequency {
+    fn checked_mul(&self, v: &Self) -> Option<Self> {
+        self.0.checked_mul(v.0).map(Frequency)
+    }
+}
+
+impl num_traits::CheckedDiv for Frequency {
+    fn checked_div(&self, v: &Self) -> Option<Self> {
+        self.0.checked_div(v.0).map(Frequency)
+    }
+}
+
+impl num_traits::CheckedRem for Frequency {
+    fn checked_rem(&self, v: &Self) -> Option<Self> {
+        self.0.checked_rem(v.0).map(Frequency)
+    }
+}
+
+impl num_traits::SaturatingAdd for Frequency {
+    fn saturating_add(&self, v: &Self) -> Self {
+        Frequency(self.0.saturating_add(v.0))
+    }
+}
+
+impl num_traits::SaturatingSub for Frequency {
+    fn saturating_sub(&self, v: &Self) -> Self {
+        Frequency(self.0.saturating_sub(v.0))
+    }
+}
+
+impl num_traits::SaturatingMul for Frequency {
+    fn saturating_mul(&self, v: &Self) -> Self {
+        Frequency(self.0.saturating_mul(v.0))
+    }
+}
+
+impl num_traits::ToPrimitive for Frequency {
+    fn to_i64(&self) -> Option<i64> {
+        self.0.to_i64()
+    }
+
+    fn to_u64(&self) -> Option<u64> {
+        Some(self.0)
+    }
+
+    fn to_i128(&self) -> Option<i128> {
+        Some(i128::from(self.0))
+    }
+
+    fn to_u128(&self) -> Option<u128> {
+        Some(u128::from(self.0))
+    }
+
+    fn to_f64(&self) -> Option<f64> {
+        self.0.to_f64()
+    }
+}
+
+impl num_traits::FromPrimitive for Frequency {
+    fn from_i64(n: i64) -> Option<Self> {
+        u64::try_from(n).ok().map(Frequency)
+    }
+
+    fn from_u64(n: u64) -> Option<Self> {
+        Some(Frequency(n))
+    }
+
+    fn from_i128(n: i128) -> Option<Self> {
+        u64::try_from(n).ok().map(Frequency)
+    }
+
+    fn from_u128(n: u128) -> Option<Self> {
+        u64::try_from(n).ok().map(Frequency)
+    }
+
+    /// Converts a number of hertz, truncated toward zero like `u64::from_f64`.
+    fn from_f64(n: f64) -> Option<Self> {
+        u64::from_f64(n).map(Frequency)
+    }
+}
+
+impl num_traits::NumCast for Frequency {
+    fn from<T: num_traits::ToPrimitive>(n: T) -> Option<Self> {
+        n.to_u64().map(Frequency)
+    }
+}
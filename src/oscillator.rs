@@ -0,0 +1,153 @@
+use std::{ops::RangeInclusive, time::Duration};
+
+use crate::{Frequency, FrequencyRange, Ppm};
+
+/// Reference temperature for initial tolerance and temperature coefficients, in °C
+pub const REFERENCE_CELSIUS: f64 = 25.0;
+
+/// Describes how an oscillator's frequency changes with temperature
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Tempco {
+    /// Frequency does not depend on temperature
+    #[default]
+    None,
+
+    /// Frequency deviates linearly from the 25 °C value, e.g. a ceramic resonator
+    Linear {
+        /// Deviation in ppm per °C away from 25 °C
+        ppm_per_celsius: f64,
+    },
+
+    /// Frequency follows a parabola around a turnover temperature, e.g. a 32.768 kHz tuning fork
+    /// crystal (typically -0.034 ppm/°C² with a turnover at 25 °C)
+    Parabolic {
+        /// Curvature in ppm per °C²
+        ppm_per_celsius_squared: f64,
+
+        /// Temperature at which the frequency peaks, in °C
+        turnover_celsius: f64,
+    },
+
+    /// The deviation stays within a fixed bound over the whole temperature range, as specified
+    /// for TCXOs and OCXOs
+    Bounded(Ppm),
+}
+
+impl Tempco {
+    /// Returns the worst-case deviation magnitude over `temperatures` (in °C).
+    fn worst_case(&self, temperatures: &RangeInclusive<f64>) -> f64 {
+        let (low, high) = (*temperatures.start(), *temperatures.end());
+
+        match *self {
+            Tempco::None => 0.0,
+            Tempco::Linear { ppm_per_celsius } => {
+                let distance = (low - REFERENCE_CELSIUS)
+                    .abs()
+                    .max((high - REFERENCE_CELSIUS).abs());
+                ppm_per_celsius.abs() * distance
+            }
+            Tempco::Parabolic {
+                ppm_per_celsius_squared,
+                turnover_celsius,
+            } => {
+                // The deviation is relative to the value at the reference temperature, so check
+                // both ends of the range as well as the turnover point itself
+                let deviation = |celsius: f64| {
+                    ppm_per_celsius_squared
+                        * ((celsius - turnover_celsius).powi(2)
+                            - (REFERENCE_CELSIUS - turnover_celsius).powi(2))
+                };
+
+                let mut worst = deviation(low).abs().max(deviation(high).abs());
+                if temperatures.contains(&turnover_celsius) {
+                    worst = worst.max(deviation(turnover_celsius).abs());
+                }
+
+                worst
+            }
+            Tempco::Bounded(bound) => bound.0.abs(),
+        }
+    }
+}
+
+/// Models the frequency error budget of an oscillator (crystal, TCXO, RTC, ...)
+///
+/// The worst-case deviation is the linear sum of the initial tolerance, the aging accumulated
+/// so far and the temperature-induced deviation.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{Frequency, Oscillator, Ppm, Tempco};
+///
+/// let rtc = Oscillator {
+///     nominal: Frequency::from_hz(32_768),
+///     tolerance: Ppm(20.0),
+///     aging_per_year: Ppm(3.0),
+///     tempco: Tempco::Parabolic { ppm_per_celsius_squared: -0.034, turnover_celsius: 25.0 },
+/// };
+///
+/// // After 2 years between 0 °C and 50 °C: 20 + 6 + 0.034 * 25² = 47.25 ppm
+/// let worst = rtc.worst_case(2.0, 0.0..=50.0);
+/// assert!((worst.0 - 47.25).abs() < 1e-9);
+///
+/// // That is up to about 4.08 seconds per day
+/// let drift = rtc.timing_error(Duration::from_secs(86_400), 2.0, 0.0..=50.0);
+/// assert_eq!(drift.as_millis(), 4_082);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oscillator {
+    /// Nominal frequency
+    pub nominal: Frequency,
+
+    /// Initial tolerance at 25 °C
+    pub tolerance: Ppm,
+
+    /// Worst-case aging per year
+    pub aging_per_year: Ppm,
+
+    /// Temperature behavior
+    pub tempco: Tempco,
+}
+
+impl Oscillator {
+    /// Creates an ideal oscillator with no tolerance, aging or temperature dependence.
+    #[must_use]
+    pub fn new(nominal: Frequency) -> Self {
+        Self {
+            nominal,
+            tolerance: Ppm::ZERO,
+            aging_per_year: Ppm::ZERO,
+            tempco: Tempco::None,
+        }
+    }
+
+    /// Returns the worst-case deviation after `years` of operation anywhere within
+    /// `temperatures` (in °C).
+    #[must_use]
+    pub fn worst_case(&self, years: f64, temperatures: RangeInclusive<f64>) -> Ppm {
+        Ppm(self.tolerance.0.abs()
+            + self.aging_per_year.0.abs() * years.max(0.0)
+            + self.tempco.worst_case(&temperatures))
+    }
+
+    /// Returns the window the actual frequency is guaranteed to lie in after `years` of operation
+    /// anywhere within `temperatures` (in °C).
+    #[must_use]
+    pub fn window(&self, years: f64, temperatures: RangeInclusive<f64>) -> FrequencyRange {
+        self.nominal * self.worst_case(years, temperatures)
+    }
+
+    /// Returns how far a clock driven by this oscillator may drift over `elapsed`, in the worst
+    /// case after `years` of operation anywhere within `temperatures` (in °C).
+    #[must_use]
+    pub fn timing_error(
+        &self,
+        elapsed: Duration,
+        years: f64,
+        temperatures: RangeInclusive<f64>,
+    ) -> Duration {
+        elapsed.mul_f64(self.worst_case(years, temperatures).as_fraction())
+    }
+}
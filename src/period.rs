@@ -0,0 +1,86 @@
+use core::{fmt::Display, time::Duration};
+
+use crate::{Frequency, Rounding};
+
+const PICOS_PER_SECOND: u128 = 1_000_000_000_000;
+const PICOS_PER_NANO: u128 = 1_000;
+
+/// Represents the period of a frequency with picosecond resolution
+///
+/// `Duration` only resolves whole nanoseconds, which truncates the period of anything above
+/// `1 GHz` and drops the fraction of periods like `1 / 3 Hz`. A `Period` keeps three more digits
+/// and makes the rounding explicit whenever it is narrowed back down.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Period, Rounding};
+///
+/// let period = Frequency::from_hz(3).period(Rounding::Nearest).unwrap();
+/// assert_eq!(period.as_picos(), 333_333_333_333);
+/// assert_eq!(period.as_nanos(Rounding::Down), 333_333_333);
+///
+/// let period = Frequency::from_mhz(1_500).period(Rounding::Nearest).unwrap();
+/// assert_eq!(period, Period::from_picos(667));
+/// assert_eq!(period.as_nanos(Rounding::Nearest), 1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct Period(u64);
+
+impl Period {
+    pub const ZERO: Self = Self(0);
+
+    #[must_use]
+    pub const fn from_picos(picos: u64) -> Self {
+        Self(picos)
+    }
+
+    #[must_use]
+    pub const fn as_picos(&self) -> u64 {
+        self.0
+    }
+
+    /// Returns the period in whole nanoseconds, rounding as requested.
+    #[must_use]
+    // The quotient is at most u64::MAX / 1000, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn as_nanos(&self, rounding: Rounding) -> u64 {
+        let nanos = rounding.div(u128::from(self.0), PICOS_PER_NANO);
+        nanos.unwrap_or_default() as u64
+    }
+
+    /// Returns the period as a `Duration`, rounded to whole nanoseconds as requested.
+    #[must_use]
+    pub fn as_duration(&self, rounding: Rounding) -> Duration {
+        Duration::from_nanos(self.as_nanos(rounding))
+    }
+
+    /// Returns the period in seconds.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_secs_f64(&self) -> f64 {
+        self.0 as f64 / PICOS_PER_SECOND as f64
+    }
+}
+
+impl Frequency {
+    /// Returns the period with picosecond resolution, rounding as requested.
+    ///
+    /// Returns `None` for `0 Hz`, which has no period.
+    #[must_use]
+    // The period of 1 Hz is 10^12 ps, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn period(&self, rounding: Rounding) -> Option<Period> {
+        let picos = rounding.div(PICOS_PER_SECOND, u128::from(self.0))?;
+        Some(Period(picos as u64))
+    }
+}
+
+impl Display for Period {
+    /// Formats the period in picoseconds, e.g. `"667 ps"`.
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ps", self.0)
+    }
+}
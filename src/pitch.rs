@@ -0,0 +1,223 @@
+use crate::{Frequency, consts};
+
+/// MIDI note number of A4, the note the reference pitch of a [`Tuning`] applies to
+const A4_NOTE: u8 = 69;
+
+/// MIDI note number of C4 (middle C)
+const C4_NOTE: u8 = 60;
+
+/// Frequency ratios of the twelve scale degrees in 5-limit just intonation, relative to the tonic
+pub const JUST_INTONATION_RATIOS: [f64; 12] = [
+    1.0,
+    16.0 / 15.0,
+    9.0 / 8.0,
+    6.0 / 5.0,
+    5.0 / 4.0,
+    4.0 / 3.0,
+    45.0 / 32.0,
+    3.0 / 2.0,
+    8.0 / 5.0,
+    5.0 / 3.0,
+    9.0 / 5.0,
+    15.0 / 8.0,
+];
+
+/// Describes how the octave is divided into twelve notes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum Temperament {
+    /// Twelve-tone equal temperament, every semitone is a ratio of `2^(1/12)`
+    #[default]
+    Equal,
+
+    /// 5-limit just intonation (see [`JUST_INTONATION_RATIOS`]) built on the given tonic pitch
+    /// class, where `0` is C, `1` is C♯ and so on up to `11` for B
+    Just {
+        /// Pitch class of the tonic
+        tonic: u8,
+    },
+}
+
+/// Maps between MIDI notes and frequencies for a given reference pitch and temperament
+///
+/// The reference pitch is the frequency of A4 (MIDI note 69). In just intonation, the tonic is
+/// derived from the reference pitch so that A4 still sounds exactly at the reference.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Temperament, Tuning};
+///
+/// let baroque = Tuning::new(415.0);
+/// assert_eq!(baroque.note_to_frequency(69), Frequency::from_hz(415));
+///
+/// let just_c = Tuning::A440.with_temperament(Temperament::Just { tonic: 0 });
+/// assert!((just_c.note_to_hz(64) - 330.0).abs() < 1e-9); // E4 is a pure major third above C4
+///
+/// let (note, cents) = Tuning::A432.frequency_to_note(Frequency::from_hz(440));
+/// assert_eq!(note, 69);
+/// assert!((cents - 31.77).abs() < 0.01);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tuning {
+    /// Frequency of A4 in hertz
+    pub reference: f64,
+
+    /// How the octave is divided
+    pub temperament: Temperament,
+}
+
+impl Tuning {
+    /// A4 = 432 Hz in equal temperament
+    pub const A432: Self = Self::new(432.0);
+
+    /// A4 = 440 Hz in equal temperament, the standard concert pitch
+    pub const A440: Self = Self::new(440.0);
+
+    /// A4 = 442 Hz in equal temperament, common in European orchestras
+    pub const A442: Self = Self::new(442.0);
+
+    /// Creates an equal tempered tuning with A4 sounding at `reference` hertz.
+    #[must_use]
+    pub const fn new(reference: f64) -> Self {
+        Self {
+            reference,
+            temperament: Temperament::Equal,
+        }
+    }
+
+    /// Returns a copy of this tuning using the given temperament.
+    #[must_use]
+    pub const fn with_temperament(self, temperament: Temperament) -> Self {
+        Self {
+            temperament,
+            ..self
+        }
+    }
+
+    /// Returns the exact frequency in hertz of a MIDI note.
+    #[must_use]
+    pub fn note_to_hz(&self, note: u8) -> f64 {
+        match self.temperament {
+            Temperament::Equal => {
+                self.reference * ((f64::from(note) - f64::from(A4_NOTE)) / 12.0).exp2()
+            }
+            Temperament::Just { tonic } => {
+                // Place the tonic in the octave of middle C and derive it from the reference
+                let tonic_note = i32::from(C4_NOTE) + i32::from(tonic % 12);
+                let tonic_hz = self.reference / just_ratio(i32::from(A4_NOTE) - tonic_note);
+                tonic_hz * just_ratio(i32::from(note) - tonic_note)
+            }
+        }
+    }
+
+    /// Returns the frequency of a MIDI note, rounded to the nearest hertz.
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn note_to_frequency(&self, note: u8) -> Frequency {
+        Frequency(self.note_to_hz(note).round().max(0.0) as u64)
+    }
+
+    /// Returns the nearest MIDI note to `freq`, along with how far off `freq` is from it in cents.
+    ///
+    /// Frequencies outside the MIDI range are clamped to note `0` or `127`, with the cents
+    /// offset reflecting the full distance. A frequency of `0 Hz` results in
+    /// `(0, f64::NEG_INFINITY)`.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn frequency_to_note(&self, freq: Frequency) -> (u8, f64) {
+        let hz = freq.0 as f64;
+        let cents_from = |note: u8| 1200.0 * (hz / self.note_to_hz(note)).log2();
+
+        // Start from the nearest equal tempered note, then look at the neighbours since other
+        // temperaments deviate from it by less than a semitone
+        let exact = f64::from(A4_NOTE) + 12.0 * (hz / self.reference).log2();
+        let (approx, _) = split_note(exact);
+
+        [
+            approx.saturating_sub(1),
+            approx,
+            approx.saturating_add(1).min(127),
+        ]
+        .into_iter()
+        .map(|note| (note, cents_from(note)))
+        .min_by(|(_, a), (_, b)| a.abs().total_cmp(&b.abs()))
+        .unwrap_or((approx, cents_from(approx)))
+    }
+}
+
+impl Default for Tuning {
+    fn default() -> Self {
+        Self::A440
+    }
+}
+
+/// Returns the just intonation ratio of an interval of `semitones` above (or below) the tonic.
+fn just_ratio(semitones: i32) -> f64 {
+    // rem_euclid always yields 0..12, so the index is in bounds
+    #[allow(clippy::cast_sign_loss)]
+    let degree = semitones.rem_euclid(12) as usize;
+    JUST_INTONATION_RATIOS[degree] * f64::from(semitones.div_euclid(12)).exp2()
+}
+
+/// Splits a fractional MIDI note number into the nearest valid note and the offset in cents.
+// The note is clamped to 0..=127 before casting
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+fn split_note(exact: f64) -> (u8, f64) {
+    let note = exact.round().clamp(0.0, 127.0);
+    (note as u8, (exact - note) * 100.0)
+}
+
+impl Frequency {
+    /// Equivalent to `440 Hz`, concert pitch A4 (MIDI note 69)
+    ///
+    /// ```rust
+    /// # use parse_frequency::Frequency;
+    /// assert_eq!(Frequency::A440, Frequency::from_midi_note(69));
+    /// ```
+    pub const A440: Self = consts::CONCERT_PITCH;
+
+    /// Returns the frequency of a MIDI note in twelve-tone equal temperament, tuned to
+    /// [`Frequency::A440`].
+    ///
+    /// The result is rounded to the nearest hertz. Use [`Tuning`] for other reference pitches,
+    /// temperaments or sub-hertz precision.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::from_midi_note(69), Frequency::from_hz(440));
+    /// assert_eq!(Frequency::from_midi_note(81), Frequency::from_hz(880));
+    /// assert_eq!(Frequency::from_midi_note(60), Frequency::from_hz(262)); // Middle C
+    /// ```
+    #[must_use]
+    pub fn from_midi_note(note: u8) -> Self {
+        Tuning::A440.note_to_frequency(note)
+    }
+
+    /// Returns the nearest MIDI note in twelve-tone equal temperament (tuned to
+    /// [`Frequency::A440`]), along with how far off this frequency is from it in cents.
+    ///
+    /// Frequencies outside the MIDI range are clamped to note `0` or `127`, with the cents
+    /// offset reflecting the full distance. A frequency of `0 Hz` results in
+    /// `(0, f64::NEG_INFINITY)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// assert_eq!(Frequency::A440.to_midi_note(), (69, 0.0));
+    ///
+    /// let (note, cents) = Frequency::from_hz(445).to_midi_note();
+    /// assert_eq!(note, 69);
+    /// assert!((cents - 19.56).abs() < 0.01);
+    /// ```
+    #[must_use]
+    pub fn to_midi_note(&self) -> (u8, f64) {
+        Tuning::A440.frequency_to_note(*self)
+    }
+}
@@ -0,0 +1,181 @@
+use std::ops::RangeInclusive;
+
+use crate::{
+    Frequency, FrequencyRange, Rounding,
+    math::{error_ppm, limit_denominator},
+};
+
+/// Hardware limits of a fractional-N PLL, used to search for register settings
+///
+/// The PLL is modelled as `vco = reference * (n + frac / den)` followed by an integer output
+/// divider, so `output = vco / output_divider`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyRange, PllConstraints};
+///
+/// // Si5351: 25 MHz crystal, feedback multiplier 15..=90, 20-bit denominator, 600-900 MHz VCO
+/// let si5351 = PllConstraints::new(
+///     15..=90,
+///     20,
+///     FrequencyRange::new(Frequency::from_mhz(600), Frequency::from_mhz(900)),
+/// )
+/// .with_output_divider(4..=2048);
+///
+/// let settings = si5351.solve(Frequency::from_mhz(25), Frequency::from_hz(14_074_000)).unwrap();
+/// assert_eq!(settings.output, Frequency::from_hz(14_074_000));
+/// assert_eq!(settings.error_ppm, 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PllConstraints {
+    /// Allowed values of the integer part of the feedback multiplier
+    pub n_range: RangeInclusive<u32>,
+
+    /// Width of the fractional denominator register in bits, so the denominator is at most
+    /// `2^denominator_bits - 1`. Zero bits results in an integer-N PLL.
+    pub denominator_bits: u32,
+
+    /// Frequencies the VCO is able to run at
+    pub vco_range: FrequencyRange,
+
+    /// Allowed values of the integer output divider
+    pub output_divider: RangeInclusive<u32>,
+}
+
+/// Register settings found by [`PllConstraints::solve`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PllSettings {
+    /// Integer part of the feedback multiplier
+    pub n: u32,
+
+    /// Numerator of the fractional part of the feedback multiplier
+    pub frac: u64,
+
+    /// Denominator of the fractional part of the feedback multiplier
+    pub den: u64,
+
+    /// Integer output divider
+    pub output_divider: u32,
+
+    /// VCO frequency, rounded to the nearest hertz
+    pub vco: Frequency,
+
+    /// Output frequency, rounded to the nearest hertz
+    pub output: Frequency,
+
+    /// The relative error of the exact output frequency in parts per million (positive if too
+    /// fast)
+    pub error_ppm: f64,
+
+    /// Exact output frequency in hertz as a fraction `(numerator, denominator)`
+    pub exact_output: (u128, u128),
+}
+
+impl PllConstraints {
+    /// Creates constraints for a PLL without an output divider.
+    #[must_use]
+    pub fn new(
+        n_range: RangeInclusive<u32>,
+        denominator_bits: u32,
+        vco_range: FrequencyRange,
+    ) -> Self {
+        Self {
+            n_range,
+            denominator_bits,
+            vco_range,
+            output_divider: 1..=1,
+        }
+    }
+
+    /// Returns a copy of these constraints with the given range of output dividers.
+    #[must_use]
+    pub fn with_output_divider(self, output_divider: RangeInclusive<u32>) -> Self {
+        Self {
+            output_divider,
+            ..self
+        }
+    }
+
+    /// Finds the settings that produce an output frequency closest to `target` from `reference`.
+    ///
+    /// For every usable output divider the fractional part is chosen as the best rational
+    /// approximation within the denominator limit, and the candidate with the smallest error
+    /// wins (ties go to the smallest output divider). All arithmetic is exact.
+    ///
+    /// Returns `None` if the reference or target is zero, or if no combination satisfies the
+    /// constraints.
+    #[must_use]
+    pub fn solve(&self, reference: Frequency, target: Frequency) -> Option<PllSettings> {
+        let (reference_hz, target_hz) = (u128::from(reference.0), u128::from(target.0));
+
+        if reference_hz == 0 || target_hz == 0 {
+            return None;
+        }
+
+        // Only dividers that put the ideal VCO frequency within its range are worth looking at
+        let (vco_min, vco_max) = (
+            u128::from(self.vco_range.min.0),
+            u128::from(self.vco_range.max.0),
+        );
+        let first = Rounding::Up
+            .div(vco_min, target_hz)?
+            .max(u128::from(*self.output_divider.start()));
+        let last = Rounding::Down
+            .div(vco_max, target_hz)?
+            .min(u128::from(*self.output_divider.end()));
+
+        (first..=last)
+            .filter_map(|divider| self.solve_for_divider(reference_hz, target_hz, divider))
+            .min_by(|a, b| {
+                // |num / den - target| compared by cross-multiplying with the other denominator
+                let distance = |(num, den): (u128, u128)| num.abs_diff(target_hz * den);
+                let (a, b) = (a.exact_output, b.exact_output);
+                (distance(a) * b.1).cmp(&(distance(b) * a.1))
+            })
+    }
+
+    /// Finds the best feedback multiplier for a single output divider.
+    // All values are bounded by their u32/u64 register limits before casting
+    #[allow(clippy::cast_possible_truncation)]
+    fn solve_for_divider(
+        &self,
+        reference_hz: u128,
+        target_hz: u128,
+        divider: u128,
+    ) -> Option<PllSettings> {
+        let max_den = (1u128 << self.denominator_bits.min(64))
+            .saturating_sub(1)
+            .max(1);
+        let vco_target = target_hz * divider;
+
+        let mut n = vco_target / reference_hz;
+        let (mut frac, mut den) =
+            limit_denominator(vco_target % reference_hz, reference_hz, max_den);
+
+        // Rounding the fraction up may carry into the integer part
+        if frac == den {
+            (n, frac, den) = (n + 1, 0, 1);
+        }
+
+        let n = u32::try_from(n).ok().filter(|n| self.n_range.contains(n))?;
+
+        let vco_num = reference_hz * (u128::from(n) * den + frac);
+        let vco = Frequency(Rounding::Nearest.div(vco_num, den)? as u64);
+        if !self.vco_range.contains(vco) {
+            return None;
+        }
+
+        let (output_num, output_den) = (vco_num, den * divider);
+        Some(PllSettings {
+            n,
+            frac: frac as u64,
+            den: den as u64,
+            output_divider: divider as u32,
+            vco,
+            output: Frequency(Rounding::Nearest.div(output_num, output_den)? as u64),
+            error_ppm: error_ppm(output_num, output_den, target_hz),
+            exact_output: (output_num, output_den),
+        })
+    }
+}
@@ -0,0 +1,89 @@
+use std::error::Error as StdError;
+
+use bytes::BytesMut;
+use postgres_types::{FromSql, IsNull, ToSql, Type, accepts, to_sql_checked};
+
+use crate::{Error, Frequency};
+
+impl ToSql for Frequency {
+    /// Writes the frequency as an `int8` number of hertz.
+    ///
+    /// Frequencies above `i64::MAX` Hz do not fit and fail with [`Error::InvalidValue`].
+    fn to_sql(
+        &self,
+        ty: &Type,
+        out: &mut BytesMut,
+    ) -> Result<IsNull, Box<dyn StdError + Sync + Send>> {
+        let hz = i64::try_from(self.0).map_err(|_| Error::invalid_value(self.to_exact_string()))?;
+        hz.to_sql(ty, out)
+    }
+
+    accepts!(INT8);
+    to_sql_checked!();
+}
+
+impl<'a> FromSql<'a> for Frequency {
+    /// Reads a non-negative `int8` or `numeric` number of hertz, rounded to the nearest hertz,
+    /// or a frequency string such as `"2.4 GHz"` from a text column.
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn StdError + Sync + Send>> {
+        match *ty {
+            Type::INT8 => {
+                let hz = i64::from_sql(ty, raw)?;
+                let hz = u64::try_from(hz).map_err(|_| Error::invalid_value(hz))?;
+                Ok(Self(hz))
+            }
+            Type::NUMERIC => Ok(format!("{} Hz", numeric_to_string(raw)?).parse()?),
+            _ => Ok(<&str>::from_sql(ty, raw)?.parse()?),
+        }
+    }
+
+    accepts!(INT8, NUMERIC, TEXT, VARCHAR, BPCHAR, NAME, UNKNOWN);
+}
+
+/// Decodes a `numeric` in binary format into a decimal string
+///
+/// The format is a header of four 16-bit fields (digit count, weight of the first digit in powers
+/// of 10000, sign and display scale) followed by the base 10000 digits.
+fn numeric_to_string(raw: &[u8]) -> Result<String, Error> {
+    let invalid = || Error::invalid_value("numeric");
+
+    let fields: Vec<u16> = raw
+        .chunks(2)
+        .map(|chunk| <[u8; 2]>::try_from(chunk).map(u16::from_be_bytes))
+        .collect::<Result<_, _>>()
+        .map_err(|_| invalid())?;
+    let [count, weight, sign, _scale, digits @ ..] = fields.as_slice() else {
+        return Err(invalid());
+    };
+
+    if *sign != 0 || usize::from(*count) != digits.len() {
+        return Err(Error::invalid_value("negative or non-finite numeric"));
+    }
+
+    // The weight is a signed 16-bit field
+    #[allow(clippy::cast_possible_wrap)]
+    let weight = i32::from(*weight as i16);
+    let digit = |i: i32| {
+        usize::try_from(i)
+            .ok()
+            .and_then(|i| digits.get(i))
+            .copied()
+            .unwrap_or(0)
+    };
+
+    let whole = (0..=weight)
+        .map(|i| {
+            if i == 0 {
+                digit(i).to_string()
+            } else {
+                format!("{:04}", digit(i))
+            }
+        })
+        .collect::<String>();
+    let fraction = (weight + 1..i32::from(*count))
+        .map(|i| format!("{:04}", digit(i)))
+        .collect::<String>();
+
+    let whole = if whole.is_empty() { "0" } else { &whole };
+    Ok(format!("{whole}.{fraction}0"))
+}
@@ -0,0 +1,149 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Mul},
+    str::FromStr,
+};
+
+use crate::{Error, Frequency, FrequencyRange, Result, parse_suffixed_f64};
+
+/// Represents a symmetric frequency tolerance in parts per million (±ppm)
+///
+/// Multiplying a nominal [`Frequency`] by a `Ppm` yields the [`FrequencyRange`] the actual
+/// frequency is guaranteed to lie in. The lower bound is rounded down and the upper bound is
+/// rounded up, so the window never understates the tolerance.
+///
+/// Tolerances can be combined either linearly (worst case, using `+`) or as a root sum of
+/// squares (statistically independent sources, using [`Ppm::rss`]).
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Ppm};
+///
+/// let crystal = Frequency::from_mhz(25);
+/// let window = crystal * Ppm(20.0);
+/// assert_eq!(window.min, Frequency::from_hz(24_999_500));
+/// assert_eq!(window.max, Frequency::from_hz(25_000_500));
+///
+/// let tolerance: Ppm = "±20 ppm".parse().unwrap();
+/// assert_eq!(tolerance + Ppm(10.0), Ppm(30.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, PartialOrd, Default)]
+#[repr(transparent)]
+pub struct Ppm(pub f64);
+
+impl Ppm {
+    /// Equivalent to `±0 ppm`
+    pub const ZERO: Self = Self(0.0);
+
+    /// Returns the tolerance as a fraction (e.g. `20 ppm` is `0.000_02`).
+    #[must_use]
+    pub fn as_fraction(&self) -> f64 {
+        self.0 / 1e6
+    }
+
+    /// Returns the maximum deviation in hertz from `nominal` allowed by this tolerance.
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, Ppm};
+    ///
+    /// assert_eq!(Ppm(50.0).deviation(Frequency::from_mhz(10)), 500.0);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn deviation(&self, nominal: Frequency) -> f64 {
+        nominal.0 as f64 * self.as_fraction().abs()
+    }
+
+    /// Returns the window of frequencies around `nominal` allowed by this tolerance.
+    #[must_use]
+    // It is OK to lose sign and precision here
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn window(&self, nominal: Frequency) -> FrequencyRange {
+        let deviation = self.deviation(nominal);
+        let hz = nominal.0 as f64;
+
+        FrequencyRange::new(
+            Frequency((hz - deviation).floor().max(0.0) as u64),
+            Frequency((hz + deviation).ceil() as u64),
+        )
+    }
+
+    /// Combines two independent tolerances as a root sum of squares (`√(a² + b²)`).
+    ///
+    /// ```rust
+    /// use parse_frequency::Ppm;
+    ///
+    /// assert_eq!(Ppm(30.0).rss(Ppm(40.0)), Ppm(50.0));
+    /// ```
+    #[must_use]
+    pub fn rss(self, other: Self) -> Self {
+        Self(self.0.hypot(other.0))
+    }
+
+    /// Combines any number of independent tolerances as a root sum of squares.
+    ///
+    /// ```rust
+    /// use parse_frequency::Ppm;
+    ///
+    /// let total = Ppm::rss_all([Ppm(20.0), Ppm(20.0), Ppm(10.0)]);
+    /// assert_eq!(total, Ppm(30.0));
+    /// ```
+    #[must_use]
+    pub fn rss_all<I: IntoIterator<Item = Self>>(tolerances: I) -> Self {
+        Self(
+            tolerances
+                .into_iter()
+                .map(|t| t.0 * t.0)
+                .sum::<f64>()
+                .sqrt(),
+        )
+    }
+}
+
+impl Add for Ppm {
+    type Output = Self;
+
+    /// Combines two tolerances linearly (worst case).
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Mul<Ppm> for Frequency {
+    type Output = FrequencyRange;
+
+    fn mul(self, rhs: Ppm) -> Self::Output {
+        rhs.window(self)
+    }
+}
+
+impl Display for Ppm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "±{:.2} ppm", self.0)
+    }
+}
+
+impl FromStr for Ppm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Tolerances are often written with a plus-minus sign, which adds nothing to the value
+        let trimmed = s.trim_start();
+        let s = trimmed
+            .strip_prefix('±')
+            .or_else(|| trimmed.strip_prefix("+/-"))
+            .or_else(|| trimmed.strip_prefix("+-"))
+            .unwrap_or(s);
+
+        parse_suffixed_f64(s, &["ppm"], |value| {
+            // Negative values are not allowed
+            (!value.is_sign_negative()).then_some(Self(value))
+        })
+    }
+}
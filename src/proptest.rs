@@ -0,0 +1,105 @@
+//! Proptest strategies for [`Frequency`]
+//!
+//! With the `proptest` feature, `any::<Frequency>()` generates any frequency. The functions in
+//! here narrow that down for property tests of parsers and configs.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use parse_frequency::{Frequency, FrequencyRange, proptest::frequency_in_range};
+//! use proptest::prelude::*;
+//!
+//! let band = FrequencyRange::new(Frequency::from_mhz(88), Frequency::from_mhz(108));
+//!
+//! proptest!(|(freq in frequency_in_range(band))| {
+//!     prop_assert!(band.contains(freq));
+//! });
+//! ```
+
+use ::proptest::{
+    arbitrary::Arbitrary,
+    prelude::{Strategy, any},
+    sample::select,
+    strategy::{Just, Map, Union},
+};
+
+use crate::{Frequency, FrequencyRange, FrequencyUnit};
+
+impl Arbitrary for Frequency {
+    type Parameters = ();
+    type Strategy = Map<<u64 as Arbitrary>::Strategy, fn(u64) -> Self>;
+
+    fn arbitrary_with((): Self::Parameters) -> Self::Strategy {
+        any::<u64>().prop_map(Frequency)
+    }
+}
+
+/// Generates frequencies within `range`, bounds included.
+pub fn frequency_in_range(range: FrequencyRange) -> impl Strategy<Value = Frequency> {
+    (range.min.0..=range.max.0).prop_map(Frequency)
+}
+
+/// Generates strings that parse to any frequency, paired with that frequency.
+///
+/// The value is written exactly in a random unit, in random case and with random whitespace,
+/// such as `"2400000.001 kHZ"` or `"  0.000000044ghz"`.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, proptest::frequency_string_with_value};
+/// use proptest::prelude::*;
+///
+/// proptest!(|((s, freq) in frequency_string_with_value())| {
+///     prop_assert_eq!(s.parse::<Frequency>(), Ok(freq));
+/// });
+/// ```
+pub fn frequency_string_with_value() -> impl Strategy<Value = (String, Frequency)> {
+    let spaces = || Union::new([Just(""), Just(" "), Just("  "), Just("\t")]);
+
+    (
+        any::<Frequency>(),
+        select(FrequencyUnit::ALL.to_vec()),
+        any::<u8>(),
+        spaces(),
+        spaces(),
+        spaces(),
+    )
+        .prop_map(|(freq, unit, case, lead, middle, trail)| {
+            let symbol: String = unit
+                .symbol()
+                .chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    if case & (1 << i) == 0 {
+                        c.to_ascii_lowercase()
+                    } else {
+                        c.to_ascii_uppercase()
+                    }
+                })
+                .collect();
+
+            let number = exact_in(freq, unit);
+            (format!("{lead}{number}{middle}{symbol}{trail}"), freq)
+        })
+}
+
+/// Generates strings that parse to a frequency, such as `"2400000.001 kHZ"`.
+///
+/// See [`frequency_string_with_value`] to also get the expected frequency.
+pub fn frequency_string() -> impl Strategy<Value = String> {
+    frequency_string_with_value().prop_map(|(s, _)| s)
+}
+
+/// Formats `freq` exactly as a decimal number of `unit`
+fn exact_in(freq: Frequency, unit: FrequencyUnit) -> String {
+    let multiplier = unit.multiplier();
+    let (whole, fraction) = (freq.0 / multiplier, freq.0 % multiplier);
+    if fraction == 0 {
+        return whole.to_string();
+    }
+
+    let digits = multiplier.ilog10() as usize;
+    let fraction = format!("{fraction:0digits$}");
+    format!("{whole}.{}", fraction.trim_end_matches('0'))
+}
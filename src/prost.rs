@@ -0,0 +1,106 @@
+//! Protobuf support for [`Frequency`]
+//!
+//! With the `prost` feature, a frequency travels through gRPC services as a `uint64` number of
+//! hertz, either in a plain field or wrapped in the [`ProtoFrequency`] message, whose definition
+//! [`proto_message`] generates for your `.proto` files. Periods convert to and from
+//! `google.protobuf.Duration` through [`prost_types::Duration`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use parse_frequency::{Frequency, prost::ProtoFrequency};
+//! use prost::Message;
+//!
+//! let bytes = ProtoFrequency::from(Frequency::from_mhz(915)).encode_to_vec();
+//! let decoded = ProtoFrequency::decode(bytes.as_slice()).unwrap();
+//! assert_eq!(Frequency::from(decoded), Frequency::from_mhz(915));
+//!
+//! let period = prost_types::Duration::try_from(Frequency::from_khz(1)).unwrap();
+//! assert_eq!((period.seconds, period.nanos), (0, 1_000_000));
+//! assert_eq!(Frequency::try_from(period), Ok(Frequency::from_khz(1)));
+//! ```
+
+use prost_types::Duration;
+
+use crate::{Error, Frequency, GIGAHERTZ};
+
+/// A frequency message holding a `uint64` number of hertz
+///
+/// Its definition is `message Frequency { uint64 hz = 1; }`, as generated by [`proto_message`].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ProtoFrequency {
+    #[prost(uint64, tag = "1")]
+    pub hz: u64,
+}
+
+impl From<Frequency> for ProtoFrequency {
+    fn from(freq: Frequency) -> Self {
+        Self { hz: freq.0 }
+    }
+}
+
+impl From<ProtoFrequency> for Frequency {
+    fn from(message: ProtoFrequency) -> Self {
+        Self(message.hz)
+    }
+}
+
+impl TryFrom<Frequency> for Duration {
+    type Error = Error;
+
+    /// Converts a frequency to its period, truncated to whole nanoseconds.
+    ///
+    /// Fails for `0 Hz` and for frequencies above `1 GHz`, whose period is shorter than a
+    /// nanosecond.
+    fn try_from(freq: Frequency) -> Result<Self, Self::Error> {
+        let period = freq.as_duration();
+        if period.is_zero() {
+            return Err(Error::invalid_value(freq.to_exact_string()));
+        }
+
+        Self::try_from(period).map_err(Error::invalid_value)
+    }
+}
+
+impl TryFrom<Duration> for Frequency {
+    type Error = Error;
+
+    /// Converts a period to its frequency, rounded to the nearest hertz.
+    ///
+    /// Fails for zero and negative periods, and for periods longer than two seconds, which
+    /// round to `0 Hz`.
+    fn try_from(period: Duration) -> Result<Self, Self::Error> {
+        let invalid = || Error::invalid_value(period);
+
+        let seconds = u128::try_from(period.seconds).map_err(|_| invalid())?;
+        let nanos = u128::try_from(period.nanos).map_err(|_| invalid())?;
+        let nanos = seconds * u128::from(GIGAHERTZ) + nanos;
+        if nanos == 0 {
+            return Err(invalid());
+        }
+
+        let hz = (u128::from(GIGAHERTZ) + nanos / 2) / nanos;
+        match u64::try_from(hz) {
+            Ok(0) | Err(_) => Err(invalid()),
+            Ok(hz) => Ok(Self(hz)),
+        }
+    }
+}
+
+/// Returns a `.proto` definition of a frequency message named `name`, matching
+/// [`ProtoFrequency`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::prost::proto_message;
+///
+/// assert_eq!(
+///     proto_message("Frequency"),
+///     "// A frequency in hertz\nmessage Frequency {\n  uint64 hz = 1;\n}\n",
+/// );
+/// ```
+#[must_use]
+pub fn proto_message(name: &str) -> String {
+    format!("// A frequency in hertz\nmessage {name} {{\n  uint64 hz = 1;\n}}\n")
+}
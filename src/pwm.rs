@@ -0,0 +1,198 @@
+use std::{fmt::Display, time::Duration};
+
+use crate::{Frequency, Rounding, math::gcd};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Resolution used by [`DutyCycle::from_percent`]
+const PERCENT_RESOLUTION: u64 = 1_000_000;
+
+/// Represents the fraction of a period a PWM signal is high, stored as an exact ratio
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::DutyCycle;
+///
+/// let duty = DutyCycle::from_ratio(1, 4).unwrap();
+/// assert_eq!(duty, DutyCycle::from_percent(25.0));
+/// assert_eq!(duty.to_string(), "25.00 %");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DutyCycle {
+    numerator: u64,
+    denominator: u64,
+}
+
+impl DutyCycle {
+    /// Equivalent to `0 %`, always low
+    pub const ZERO: Self = Self {
+        numerator: 0,
+        denominator: 1,
+    };
+
+    /// Equivalent to `50 %`, a square wave
+    pub const HALF: Self = Self {
+        numerator: 1,
+        denominator: 2,
+    };
+
+    /// Equivalent to `100 %`, always high
+    pub const FULL: Self = Self {
+        numerator: 1,
+        denominator: 1,
+    };
+
+    /// Creates a duty cycle of `on / total`, reduced to lowest terms.
+    ///
+    /// Returns `None` if `total` is zero or smaller than `on`.
+    #[must_use]
+    // The reduced values never exceed the original u64 values
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_ratio(on: u64, total: u64) -> Option<Self> {
+        if total == 0 || on > total {
+            return None;
+        }
+
+        let divisor = gcd(u128::from(on), u128::from(total)) as u64;
+        Some(Self {
+            numerator: on / divisor,
+            denominator: total / divisor,
+        })
+    }
+
+    /// Creates a duty cycle from a percentage, rounded to the nearest `0.0001 %`. Values are
+    /// clamped to `0..=100`.
+    #[must_use]
+    // The value is clamped before casting
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_sign_loss,
+        clippy::cast_precision_loss
+    )]
+    pub fn from_percent(percent: f64) -> Self {
+        let percent = if percent.is_nan() {
+            0.0
+        } else {
+            percent.clamp(0.0, 100.0)
+        };
+        let parts = (percent / 100.0 * PERCENT_RESOLUTION as f64).round() as u64;
+        Self::from_ratio(parts, PERCENT_RESOLUTION).unwrap_or(Self::ZERO)
+    }
+
+    /// Returns the duty cycle as an exact ratio `(on, total)` in lowest terms.
+    #[must_use]
+    pub fn as_ratio(&self) -> (u64, u64) {
+        (self.numerator, self.denominator)
+    }
+
+    /// Returns the duty cycle as a percentage.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn as_percent(&self) -> f64 {
+        self.numerator as f64 * 100.0 / self.denominator as f64
+    }
+}
+
+impl Default for DutyCycle {
+    fn default() -> Self {
+        Self::HALF
+    }
+}
+
+impl Display for DutyCycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:.2} %", self.as_percent())
+    }
+}
+
+/// The timing of a PWM signal: its carrier frequency and the resulting high and low times
+///
+/// Durations are calculated with exact integer arithmetic and rounded to the nearest
+/// nanosecond. The low time is derived from the rounded period, so `on_time + off_time` always
+/// equals [`PwmTiming::period`].
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{DutyCycle, Frequency, PwmTiming};
+///
+/// let pwm = PwmTiming::new(Frequency::from_khz(20), DutyCycle::from_percent(25.0)).unwrap();
+/// assert_eq!(pwm.on_time, Duration::from_nanos(12_500));
+/// assert_eq!(pwm.off_time, Duration::from_nanos(37_500));
+///
+/// let back = PwmTiming::from_durations(pwm.on_time, pwm.off_time).unwrap();
+/// assert_eq!(back.carrier, Frequency::from_khz(20));
+/// assert_eq!(back.duty_cycle(), DutyCycle::from_percent(25.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PwmTiming {
+    /// The PWM carrier frequency
+    pub carrier: Frequency,
+
+    /// How long the signal is high in each period
+    pub on_time: Duration,
+
+    /// How long the signal is low in each period
+    pub off_time: Duration,
+}
+
+impl PwmTiming {
+    /// Calculates the high and low times for a carrier frequency and duty cycle.
+    ///
+    /// Returns `None` if the carrier frequency is zero.
+    #[must_use]
+    // Periods of a non-zero frequency never exceed one second
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn new(carrier: Frequency, duty: DutyCycle) -> Option<Self> {
+        let hz = u128::from(carrier.0);
+        let period = Rounding::Nearest.div(NANOS_PER_SECOND, hz)?;
+
+        let (num, den) = duty.as_ratio();
+        let on = Rounding::Nearest.div(NANOS_PER_SECOND * u128::from(num), hz * u128::from(den))?;
+
+        Some(Self {
+            carrier,
+            on_time: Duration::from_nanos(on as u64),
+            off_time: Duration::from_nanos(period.saturating_sub(on) as u64),
+        })
+    }
+
+    /// Derives the carrier frequency (rounded to the nearest hertz) from the high and low times.
+    ///
+    /// Returns `None` if both durations are zero.
+    #[must_use]
+    // The frequency never exceeds 1 GHz
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn from_durations(on_time: Duration, off_time: Duration) -> Option<Self> {
+        let period = on_time.as_nanos() + off_time.as_nanos();
+        let hz = Rounding::Nearest.div(NANOS_PER_SECOND, period)?;
+
+        Some(Self {
+            carrier: Frequency(hz as u64),
+            on_time,
+            off_time,
+        })
+    }
+
+    /// Returns the length of one period (`on_time + off_time`).
+    #[must_use]
+    pub fn period(&self) -> Duration {
+        self.on_time + self.off_time
+    }
+
+    /// Returns the exact duty cycle of the high and low times.
+    #[must_use]
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn duty_cycle(&self) -> DutyCycle {
+        let clamp = |nanos: u128| nanos.min(u128::from(u64::MAX)) as u64;
+        let (on, period) = (
+            clamp(self.on_time.as_nanos()),
+            clamp(self.period().as_nanos()),
+        );
+        DutyCycle::from_ratio(on, period).unwrap_or(DutyCycle::ZERO)
+    }
+}
@@ -0,0 +1,66 @@
+use pyo3::{
+    exceptions::{PyTypeError, PyValueError},
+    prelude::*,
+    types::{PyBool, PyFloat, PyString},
+};
+
+use crate::{Error, Frequency, FrequencyF64, Rounding};
+
+impl<'py> FromPyObject<'py> for Frequency {
+    /// Extracts a non-negative `int` or `float` number of hertz, rounded to the nearest hertz,
+    /// or a `str` such as `"2.4 GHz"`.
+    ///
+    /// `bool` is rejected even though Python treats it as an `int`.
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = ob.downcast::<PyString>() {
+            return Ok(s.to_cow()?.parse()?);
+        }
+
+        if ob.is_instance_of::<PyBool>() {
+            return Err(PyTypeError::new_err(
+                "expected a frequency string or a number of hertz, got bool",
+            ));
+        }
+
+        if let Ok(hz) = ob.downcast::<PyFloat>() {
+            let hz = hz.value();
+            return FrequencyF64::from_hz(hz)
+                .try_to_frequency(Rounding::Nearest)
+                .ok_or_else(|| Error::invalid_value(hz).into());
+        }
+
+        ob.extract::<u64>().map(Self).map_err(|e| {
+            if e.is_instance_of::<PyTypeError>(ob.py()) {
+                PyTypeError::new_err(format!(
+                    "expected a frequency string or a number of hertz, got {}",
+                    ob.get_type()
+                        .name()
+                        .map_or_else(|_| "?".into(), |n| n.to_string())
+                ))
+            } else {
+                e
+            }
+        })
+    }
+}
+
+impl IntoPy<PyObject> for Frequency {
+    /// Converts to a Python `int` number of hertz.
+    fn into_py(self, py: Python<'_>) -> PyObject {
+        self.0.into_py(py)
+    }
+}
+
+impl ToPyObject for Frequency {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.0.to_object(py)
+    }
+}
+
+impl From<Error> for PyErr {
+    /// Raises a `ValueError` with the error message, so functions returning
+    /// [`crate::Result`] can be exposed to Python directly.
+    fn from(err: Error) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
@@ -0,0 +1,60 @@
+use quickcheck::{Arbitrary, Gen};
+
+use crate::{Frequency, FrequencyShift, GIGAHERTZ, KILOHERTZ, MEGAHERTZ};
+
+impl Arbitrary for Frequency {
+    fn arbitrary(g: &mut Gen) -> Self {
+        Self(u64::arbitrary(g))
+    }
+
+    /// Shrinks toward `0 Hz`, then toward whole gigahertz, megahertz and kilohertz, then like
+    /// an integer number of hertz.
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let hz = self.0;
+        let boundaries = [0, GIGAHERTZ, MEGAHERTZ, KILOHERTZ].map(|unit| match unit {
+            0 => 0,
+            unit => hz - hz % unit,
+        });
+
+        Box::new(shrink_candidates(boundaries, hz.shrink(), hz).map(Self))
+    }
+}
+
+impl Arbitrary for FrequencyShift {
+    /// Generates a finite shift.
+    fn arbitrary(g: &mut Gen) -> Self {
+        let hz = f64::arbitrary(g);
+        Self(if hz.is_finite() { hz } else { 0.0 })
+    }
+
+    /// Shrinks toward `0 Hz`, then toward whole gigahertz, megahertz, kilohertz and hertz, then
+    /// like a floating point number of hertz.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
+        let hz = self.0;
+        let boundaries = [0, GIGAHERTZ, MEGAHERTZ, KILOHERTZ, 1].map(|unit| match unit {
+            0 => 0.0,
+            unit => (hz / unit as f64).trunc() * unit as f64,
+        });
+
+        Box::new(shrink_candidates(boundaries, hz.shrink(), hz).map(Self))
+    }
+}
+
+/// Returns `boundaries` followed by `rest`, without duplicates and without `current`
+fn shrink_candidates<T: PartialEq + Copy + 'static, const N: usize>(
+    boundaries: [T; N],
+    rest: Box<dyn Iterator<Item = T>>,
+    current: T,
+) -> impl Iterator<Item = T> {
+    let mut seen = Vec::with_capacity(N);
+    boundaries.into_iter().chain(rest).filter(move |candidate| {
+        if *candidate == current || seen.contains(candidate) {
+            return false;
+        }
+
+        seen.push(*candidate);
+        true
+    })
+}
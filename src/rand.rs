@@ -0,0 +1,58 @@
+use rand::{
+    Rng,
+    distributions::{
+        Distribution, Standard,
+        uniform::{SampleBorrow, SampleUniform, UniformInt, UniformSampler},
+    },
+};
+
+use crate::Frequency;
+
+impl Distribution<Frequency> for Standard {
+    /// Samples a frequency uniformly over every possible number of hertz.
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Frequency {
+        Frequency(rng.r#gen())
+    }
+}
+
+/// Samples frequencies uniformly from a range, used by `Rng::gen_range`
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use rand::Rng;
+///
+/// let freq = rand::thread_rng().gen_range(Frequency::from_mhz(80)..Frequency::from_mhz(110));
+/// assert!(freq >= Frequency::from_mhz(80) && freq < Frequency::from_mhz(110));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct UniformFrequency(UniformInt<u64>);
+
+impl UniformSampler for UniformFrequency {
+    type X = Frequency;
+
+    fn new<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new(low.borrow().0, high.borrow().0))
+    }
+
+    fn new_inclusive<B1, B2>(low: B1, high: B2) -> Self
+    where
+        B1: SampleBorrow<Self::X> + Sized,
+        B2: SampleBorrow<Self::X> + Sized,
+    {
+        Self(UniformInt::new_inclusive(low.borrow().0, high.borrow().0))
+    }
+
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> Self::X {
+        Frequency(self.0.sample(rng))
+    }
+}
+
+impl SampleUniform for Frequency {
+    type Sampler = UniformFrequency;
+}
@@ -0,0 +1,315 @@
+use core::{
+    fmt::Display,
+    iter::FusedIterator,
+    ops::{Range, RangeInclusive},
+};
+
+use crate::Frequency;
+#[cfg(feature = "std")]
+use crate::FrequencyShift;
+
+/// Represents an inclusive range of frequencies
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyRange};
+///
+/// let band = FrequencyRange::new(Frequency::from_mhz(2400), Frequency::from_mhz(2500));
+/// assert!(band.contains(Frequency::from_mhz(2450)));
+/// assert_eq!(band.width(), Frequency::from_mhz(100));
+/// assert_eq!(band.to_string(), "2.40 GHz - 2.50 GHz");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub struct FrequencyRange {
+    /// The lowest frequency in the range
+    pub min: Frequency,
+
+    /// The highest frequency in the range
+    pub max: Frequency,
+}
+
+impl FrequencyRange {
+    /// Creates a new range. The bounds are swapped if `min` is greater than `max`.
+    #[must_use]
+    pub fn new(min: Frequency, max: Frequency) -> Self {
+        if min <= max {
+            Self { min, max }
+        } else {
+            Self { min: max, max: min }
+        }
+    }
+
+    /// Returns `true` if `freq` lies within the range, bounds included.
+    #[must_use]
+    pub fn contains(&self, freq: Frequency) -> bool {
+        self.min <= freq && freq <= self.max
+    }
+
+    /// Returns the width of the range (`max - min`).
+    #[must_use]
+    pub fn width(&self) -> Frequency {
+        self.max - self.min
+    }
+
+    /// Returns the center of the range, rounded down to the nearest hertz.
+    #[must_use]
+    pub fn center(&self) -> Frequency {
+        Frequency(self.min.0 + (self.max.0 - self.min.0) / 2)
+    }
+
+    /// Returns the range moved by `shift`, with both edges rounded to the nearest hertz.
+    /// Edges that would fall below `0 Hz` saturate at [`Frequency::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange, FrequencyShift};
+    ///
+    /// let channel = FrequencyRange::new(Frequency::from_khz(100), Frequency::from_khz(200));
+    /// let shifted = channel.shift_by(FrequencyShift(-25_000.0));
+    /// assert_eq!(shifted, FrequencyRange::new(Frequency::from_khz(75), Frequency::from_khz(175)));
+    /// ```
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn shift_by(&self, shift: FrequencyShift) -> Self {
+        Self::new(self.min + shift, self.max + shift)
+    }
+
+    /// Returns the range widened by `bandwidth` in total, split evenly between both edges so the
+    /// center stays put. An odd extra hertz goes to the upper edge, and the edges saturate at
+    /// `0 Hz` and `u64::MAX` Hz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange};
+    ///
+    /// // Add a 1 MHz guard band around a 20 MHz channel
+    /// let channel = FrequencyRange::new(Frequency::from_mhz(2_402), Frequency::from_mhz(2_422));
+    /// let guarded = channel.widen_by(Frequency::from_mhz(2));
+    /// assert_eq!(guarded.min, Frequency::from_mhz(2_401));
+    /// assert_eq!(guarded.max, Frequency::from_mhz(2_423));
+    /// ```
+    #[must_use]
+    pub fn widen_by(&self, bandwidth: Frequency) -> Self {
+        let below = bandwidth.0 / 2;
+        Self {
+            min: Frequency(self.min.0.saturating_sub(below)),
+            max: Frequency(self.max.0.saturating_add(bandwidth.0 - below)),
+        }
+    }
+
+    /// Returns an iterator over `n` adjacent sub-ranges of (nearly) equal width covering the
+    /// range from low to high. Neighbouring sub-ranges share their edge, and edges are rounded
+    /// down to the nearest hertz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange};
+    ///
+    /// let band = FrequencyRange::new(Frequency::from_mhz(100), Frequency::from_mhz(160));
+    /// let parts: Vec<_> = band.split_into(3).map(|part| part.center()).collect();
+    /// assert_eq!(parts, [Frequency::from_mhz(110), Frequency::from_mhz(130), Frequency::from_mhz(150)]);
+    /// ```
+    // The edges never exceed the maximum, which fits in a u64
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn split_into(&self, n: u32) -> impl Iterator<Item = Self> + use<> {
+        let (min, width) = (self.min.0, u128::from(self.width().0));
+        let edge = move |i: u32| Frequency(min + (width * u128::from(i) / u128::from(n)) as u64);
+
+        (0..n).map(move |i| Self {
+            min: edge(i),
+            max: edge(i + 1),
+        })
+    }
+
+    /// Returns an iterator over the frequencies from the minimum up to at most the maximum, `step`
+    /// apart. A `step` of `0 Hz` yields no frequencies.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange};
+    ///
+    /// let band = FrequencyRange::new(Frequency::from_khz(446_000), Frequency::from_khz(446_050));
+    /// let channels: Vec<_> = band.step_channels(Frequency::from_hz(12_500)).collect();
+    /// assert_eq!(channels.len(), 5);
+    /// assert_eq!(channels[4], Frequency::from_khz(446_050));
+    /// ```
+    pub fn step_channels(&self, step: Frequency) -> impl Iterator<Item = Frequency> + use<> {
+        let max = self.max;
+        let first = (step.0 > 0).then_some(self.min);
+
+        core::iter::successors(first, move |freq| {
+            freq.0
+                .checked_add(step.0)
+                .map(Frequency)
+                .filter(|next| *next <= max)
+        })
+    }
+
+    /// Returns an iterator over every hertz in the range, bounds included.
+    ///
+    /// Combine it with [`Iterator::step_by`] to scan the range in steps, which skips ahead
+    /// without visiting the frequencies in between.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, FrequencyRange};
+    ///
+    /// let band = FrequencyRange::new(Frequency::from_mhz(100), Frequency::from_mhz(110));
+    /// let scan: Vec<_> = band.iter().step_by(5_000_000).collect();
+    /// assert_eq!(scan, [Frequency::from_mhz(100), Frequency::from_mhz(105), Frequency::from_mhz(110)]);
+    /// ```
+    #[must_use]
+    pub fn iter(&self) -> FrequencyRangeIter {
+        FrequencyRangeIter::from(self.min..=self.max)
+    }
+}
+
+impl IntoIterator for FrequencyRange {
+    type Item = Frequency;
+    type IntoIter = FrequencyRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl IntoIterator for &FrequencyRange {
+    type Item = Frequency;
+    type IntoIter = FrequencyRangeIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+impl Display for FrequencyRange {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} - {}", self.min, self.max)
+    }
+}
+
+/// An iterator over every hertz from one frequency to another
+///
+/// `Frequency` cannot implement the unstable `Step` trait, so ranges such as
+/// `Frequency::from_mhz(100)..=Frequency::from_mhz(110)` are not iterators themselves. Convert
+/// them into this iterator instead, or use [`FrequencyRange::iter`]. Skipping ahead with
+/// [`Iterator::nth`], and so [`Iterator::step_by`], takes constant time.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyRangeIter};
+///
+/// let scan = FrequencyRangeIter::from(Frequency::from_mhz(100)..=Frequency::from_mhz(110));
+/// for freq in scan.step_by(100_000) {
+///     // Tune to 100.0 MHz, 100.1 MHz, ..., 110.0 MHz
+///     # assert_eq!(freq.as_hz() % 100_000, 0);
+/// }
+///
+/// let half_open = FrequencyRangeIter::from(Frequency::from_hz(1)..Frequency::from_hz(4));
+/// assert_eq!(half_open.rev().collect::<Vec<_>>(), [Frequency::from_hz(3), Frequency::from_hz(2), Frequency::from_hz(1)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct FrequencyRangeIter {
+    /// The remaining frequencies in hertz, bounds included, or `None` when exhausted
+    remaining: Option<(u64, u64)>,
+}
+
+impl FrequencyRangeIter {
+    /// Returns the number of remaining frequencies minus one
+    fn span(&self) -> Option<u64> {
+        self.remaining.map(|(start, end)| end - start)
+    }
+}
+
+impl From<RangeInclusive<Frequency>> for FrequencyRangeIter {
+    fn from(range: RangeInclusive<Frequency>) -> Self {
+        let (start, end) = range.into_inner();
+        Self {
+            remaining: (start <= end).then_some((start.0, end.0)),
+        }
+    }
+}
+
+impl From<Range<Frequency>> for FrequencyRangeIter {
+    fn from(range: Range<Frequency>) -> Self {
+        Self {
+            remaining: (range.start < range.end).then_some((range.start.0, range.end.0 - 1)),
+        }
+    }
+}
+
+impl Iterator for FrequencyRangeIter {
+    type Item = Frequency;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nth(0)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let (start, end) = self.remaining?;
+        let Some(freq) = u64::try_from(n)
+            .ok()
+            .and_then(|n| start.checked_add(n))
+            .filter(|freq| *freq <= end)
+        else {
+            self.remaining = None;
+            return None;
+        };
+
+        self.remaining = (freq < end).then_some((freq + 1, end));
+        Some(Frequency(freq))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self
+            .span()
+            .map(|span| usize::try_from(span).ok()?.checked_add(1))
+        {
+            None => (0, Some(0)),
+            Some(Some(len)) => (len, Some(len)),
+            Some(None) => (usize::MAX, None),
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+
+    fn min(mut self) -> Option<Self::Item> {
+        self.next()
+    }
+
+    fn max(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for FrequencyRangeIter {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nth_back(0)
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        let (start, end) = self.remaining?;
+        let Some(freq) = u64::try_from(n)
+            .ok()
+            .and_then(|n| end.checked_sub(n))
+            .filter(|freq| *freq >= start)
+        else {
+            self.remaining = None;
+            return None;
+        };
+
+        self.remaining = (freq > start).then_some((start, freq - 1));
+        Some(Frequency(freq))
+    }
+}
+
+impl FusedIterator for FrequencyRangeIter {}
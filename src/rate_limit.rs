@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use crate::{Frequency, Rounding};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Converts nanoseconds to a `Duration`, saturating at `u64::MAX` nanoseconds.
+// Saturating at u64::MAX is acceptable here
+#[allow(clippy::cast_possible_truncation)]
+fn saturating_nanos(nanos: u128) -> Duration {
+    Duration::from_nanos(nanos.min(u128::from(u64::MAX)) as u64)
+}
+
+impl Frequency {
+    /// Returns the minimum time between two events when this frequency is used as a rate limit,
+    /// rounded up to whole nanoseconds so the limit is never exceeded.
+    ///
+    /// Unlike [`Frequency::as_duration`], a rate of `0 Hz` results in [`Duration::MAX`], as no
+    /// event is ever allowed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::Frequency;
+    ///
+    /// let limit: Frequency = "500 Hz".parse().unwrap();
+    /// assert_eq!(limit.interval(), Duration::from_millis(2));
+    ///
+    /// // 1/3 s rounds up to the next nanosecond
+    /// assert_eq!(Frequency::from_hz(3).interval(), Duration::from_nanos(333_333_334));
+    /// ```
+    #[must_use]
+    pub fn interval(&self) -> Duration {
+        Rounding::Up
+            .div(NANOS_PER_SECOND, u128::from(self.0))
+            .map_or(Duration::MAX, saturating_nanos)
+    }
+
+    /// Returns how many events a limit of this rate allows within `window`, i.e.
+    /// `floor(rate * window)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::Frequency;
+    ///
+    /// let limit = Frequency::from_hz(500);
+    /// assert_eq!(limit.max_events_in(Duration::from_secs(60)), 30_000);
+    /// assert_eq!(limit.max_events_in(Duration::from_micros(4_500)), 2);
+    /// ```
+    #[must_use]
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn max_events_in(&self, window: Duration) -> u64 {
+        let events = window.as_nanos().saturating_mul(u128::from(self.0)) / NANOS_PER_SECOND;
+        events.min(u128::from(u64::MAX)) as u64
+    }
+
+    /// Returns how many events a token bucket refilling at this rate allows within `window`,
+    /// when it starts out full with `burst` tokens.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::Frequency;
+    ///
+    /// // 10 requests per second with bursts of up to 20
+    /// let limit = Frequency::from_hz(10);
+    /// assert_eq!(limit.max_events_with_burst(Duration::from_secs(1), 20), 30);
+    /// ```
+    #[must_use]
+    pub fn max_events_with_burst(&self, window: Duration, burst: u64) -> u64 {
+        self.max_events_in(window).saturating_add(burst)
+    }
+
+    /// Returns how long a token bucket refilling at this rate takes to accumulate `tokens`
+    /// tokens, rounded up to whole nanoseconds.
+    ///
+    /// A rate of `0 Hz` results in [`Duration::MAX`] unless no tokens are needed.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::Frequency;
+    ///
+    /// let limit = Frequency::from_hz(10);
+    /// assert_eq!(limit.time_to_refill(20), Duration::from_secs(2));
+    /// ```
+    #[must_use]
+    pub fn time_to_refill(&self, tokens: u64) -> Duration {
+        if tokens == 0 {
+            return Duration::ZERO;
+        }
+
+        Rounding::Up
+            .div(u128::from(tokens) * NANOS_PER_SECOND, u128::from(self.0))
+            .map_or(Duration::MAX, saturating_nanos)
+    }
+}
@@ -0,0 +1,271 @@
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+#[cfg(feature = "std")]
+use crate::FrequencyF64;
+use crate::{Error, Frequency, Lowercase, Result, Rounding, math, split_unit};
+
+/// Represents a frequency as an exact fraction of hertz
+///
+/// Rates such as NTSC video at 30000/1001 Hz (29.97 fps) cannot be represented in whole hertz.
+/// The fraction is always kept in lowest terms with a non-zero denominator, so equal
+/// frequencies compare and hash equal.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, RationalFrequency, Rounding};
+///
+/// let ntsc: RationalFrequency = "30000/1001 Hz".parse().unwrap();
+/// assert_eq!((ntsc.num(), ntsc.den()), (30_000, 1_001));
+/// assert_eq!(ntsc.to_string(), "30000/1001 Hz");
+///
+/// // Doubling the frame rate gives the field rate, still exact
+/// let fields = ntsc * 2;
+/// assert_eq!(fields, RationalFrequency::new(60_000, 1_001).unwrap());
+/// assert_eq!(fields.to_frequency(Rounding::Nearest), Frequency::from_hz(60));
+///
+/// let decimal: RationalFrequency = "29.97 Hz".parse().unwrap();
+/// assert_eq!((decimal.num(), decimal.den()), (2_997, 100));
+/// assert!(decimal < ntsc);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RationalFrequency {
+    num: u64,
+    den: u64,
+}
+
+impl RationalFrequency {
+    /// Equivalent to `0 Hz`
+    pub const ZERO: Self = Self { num: 0, den: 1 };
+
+    /// Creates the frequency `num / den` hertz, reduced to lowest terms.
+    ///
+    /// Returns `None` if `den` is zero.
+    #[must_use]
+    pub fn new(num: u64, den: u64) -> Option<Self> {
+        Self::from_u128(u128::from(num), u128::from(den))
+    }
+
+    /// Reduces `num / den` to lowest terms, or returns `None` if `den` is zero or the reduced
+    /// fraction does not fit in `u64`s.
+    fn from_u128(num: u128, den: u128) -> Option<Self> {
+        if den == 0 {
+            return None;
+        }
+
+        let divisor = math::gcd(num, den);
+        Some(Self {
+            num: u64::try_from(num / divisor).ok()?,
+            den: u64::try_from(den / divisor).ok()?,
+        })
+    }
+
+    /// Returns the numerator of the fraction in lowest terms.
+    #[must_use]
+    #[doc(alias = "numerator")]
+    pub fn num(&self) -> u64 {
+        self.num
+    }
+
+    /// Returns the denominator of the fraction in lowest terms, which is never zero.
+    #[must_use]
+    #[doc(alias = "denominator")]
+    pub fn den(&self) -> u64 {
+        self.den
+    }
+
+    /// Converts to a whole number of hertz, rounding as requested.
+    #[must_use]
+    // The quotient never exceeds the numerator, which fits in a u64
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_frequency(&self, rounding: Rounding) -> Frequency {
+        let hz = rounding.div(u128::from(self.num), u128::from(self.den));
+        Frequency(hz.unwrap_or_default() as u64)
+    }
+
+    /// Converts to a whole number of hertz if no precision is lost.
+    #[must_use]
+    pub fn to_frequency_exact(&self) -> Option<Frequency> {
+        (self.den == 1).then_some(Frequency(self.num))
+    }
+
+    /// Converts to a floating point frequency.
+    #[cfg(feature = "std")]
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn to_frequency_f64(&self) -> FrequencyF64 {
+        FrequencyF64(self.num as f64 / self.den as f64)
+    }
+
+    /// Adds two frequencies, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.widen(), other.widen());
+        Self::from_u128((a.0 * b.1).checked_add(b.0 * a.1)?, a.1 * b.1)
+    }
+
+    /// Subtracts `other`, or returns `None` if the result is negative or does not fit.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        let (a, b) = (self.widen(), other.widen());
+        Self::from_u128((a.0 * b.1).checked_sub(b.0 * a.1)?, a.1 * b.1)
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        Self::from_u128(u128::from(self.num) * u128::from(rhs), u128::from(self.den))
+    }
+
+    /// Divides by `rhs`, or returns `None` if `rhs` is zero or the result does not fit.
+    #[must_use]
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        Self::from_u128(u128::from(self.num), u128::from(self.den) * u128::from(rhs))
+    }
+
+    fn widen(self) -> (u128, u128) {
+        (u128::from(self.num), u128::from(self.den))
+    }
+}
+
+impl Default for RationalFrequency {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl From<Frequency> for RationalFrequency {
+    fn from(freq: Frequency) -> Self {
+        Self {
+            num: freq.0,
+            den: 1,
+        }
+    }
+}
+
+impl PartialOrd for RationalFrequency {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for RationalFrequency {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.widen(), other.widen());
+        (a.0 * b.1).cmp(&(b.0 * a.1))
+    }
+}
+
+impl Add for RationalFrequency {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit. See [`RationalFrequency::checked_add`] for a checked
+    /// alternative.
+    fn add(self, other: Self) -> Self::Output {
+        self.checked_add(other)
+            .expect("attempt to add with overflow")
+    }
+}
+
+impl Sub for RationalFrequency {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result is negative or does not fit. See
+    /// [`RationalFrequency::checked_sub`] for a checked alternative.
+    fn sub(self, other: Self) -> Self::Output {
+        self.checked_sub(other)
+            .expect("attempt to subtract with overflow")
+    }
+}
+
+impl Mul<u64> for RationalFrequency {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit. See [`RationalFrequency::checked_mul`] for a checked
+    /// alternative.
+    fn mul(self, rhs: u64) -> Self::Output {
+        self.checked_mul(rhs)
+            .expect("attempt to multiply with overflow")
+    }
+}
+
+impl Div<u64> for RationalFrequency {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero or the result does not fit. See
+    /// [`RationalFrequency::checked_div`] for a checked alternative.
+    fn div(self, rhs: u64) -> Self::Output {
+        self.checked_div(rhs)
+            .expect("attempt to divide by zero or with overflow")
+    }
+}
+
+impl Display for RationalFrequency {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if self.den == 1 {
+            write!(f, "{} Hz", self.num)
+        } else {
+            write!(f, "{}/{} Hz", self.num, self.den)
+        }
+    }
+}
+
+/// Parses a non-negative decimal number such as `"29.97"` into an exact fraction.
+fn parse_exact_decimal(value: &str) -> Option<(u128, u128)> {
+    let fraction = value.split_once('.').map_or("", |(_, fraction)| fraction);
+
+    // The fraction is scaled by 10^digits, which has to fit in a u64
+    if fraction.len() > 19 {
+        return None;
+    }
+
+    let den = 10u128.pow(u32::try_from(fraction.len()).ok()?);
+    Some((math::parse_decimal(value, den)?, den))
+}
+
+impl FromStr for RationalFrequency {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+
+        let (value_str, multiplier) =
+            split_unit(s).ok_or_else(|| Error::unknown_unit(Lowercase(s)))?;
+
+        let value_str = value_str.trim();
+        let (num_str, den_str) = value_str.split_once('/').unwrap_or((value_str, "1"));
+
+        let parse = || {
+            let (num, num_scale) = parse_exact_decimal(num_str.trim())?;
+            let (den, den_scale) = parse_exact_decimal(den_str.trim())?;
+            let num = num
+                .checked_mul(den_scale)?
+                .checked_mul(u128::from(multiplier))?;
+            Self::from_u128(num, den.checked_mul(num_scale)?)
+        };
+
+        parse().ok_or_else(|| Error::invalid_value(Lowercase(value_str)))
+    }
+}
+
+impl TryFrom<&str> for RationalFrequency {
+    type Error = Error;
+
+    fn try_from(s: &str) -> Result<Self> {
+        s.parse()
+    }
+}
@@ -0,0 +1,34 @@
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+use crate::{Frequency, Result, parse_frequency};
+
+/// Parses each string of `inputs` like [`parse_frequency`], in parallel on the rayon thread
+/// pool.
+///
+/// This is the parallel counterpart of [`parse_many`](crate::parse_many). Collecting the results
+/// into a `Vec` keeps them in the order of `inputs`, and works with unindexed sources such as
+/// `par_lines`. Parsing a single string only takes nanoseconds, so this only pays off for
+/// large batches such as whole CSV dumps.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, par_parse_many};
+/// use rayon::prelude::*;
+///
+/// let dump = "2412 MHz\n2437 MHz\n2.462 GHz\n";
+/// let freqs: Vec<Frequency> = par_parse_many(dump.par_lines())
+///     .collect::<Result<_, _>>()
+///     .unwrap();
+/// assert_eq!(freqs[0], Frequency::from_mhz(2_412));
+/// assert_eq!(freqs[2], Frequency::from_mhz(2_462));
+/// ```
+pub fn par_parse_many<I>(inputs: I) -> impl ParallelIterator<Item = Result<Frequency>>
+where
+    I: IntoParallelIterator,
+    I::Item: AsRef<str>,
+{
+    inputs
+        .into_par_iter()
+        .map(|input| parse_frequency(input.as_ref()))
+}
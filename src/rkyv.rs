@@ -0,0 +1,61 @@
+use rkyv::{
+    Archive, Archived, Deserialize, Place, Portable, Serialize, bytecheck::CheckBytes,
+    munge::munge, rancor::Fallible,
+};
+
+use crate::Frequency;
+
+/// An archived [`Frequency`], laid out exactly like an archived `u64`
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{ArchivedFrequency, Frequency};
+///
+/// let freqs = vec![Frequency::from_khz(1), Frequency::from_mhz(2_400)];
+/// let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&freqs).unwrap();
+///
+/// let archived =
+///     rkyv::access::<rkyv::Archived<Vec<Frequency>>, rkyv::rancor::Error>(&bytes).unwrap();
+/// assert_eq!(archived[1].get(), Frequency::from_mhz(2_400));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Portable, CheckBytes)]
+#[bytecheck(crate = rkyv::bytecheck)]
+#[repr(transparent)]
+pub struct ArchivedFrequency(Archived<u64>);
+
+impl ArchivedFrequency {
+    /// Returns the archived frequency.
+    #[must_use]
+    pub fn get(&self) -> Frequency {
+        Frequency(self.0.to_native())
+    }
+}
+
+impl PartialEq<Frequency> for ArchivedFrequency {
+    fn eq(&self, other: &Frequency) -> bool {
+        self.get() == *other
+    }
+}
+
+impl Archive for Frequency {
+    type Archived = ArchivedFrequency;
+    type Resolver = ();
+
+    fn resolve(&self, (): Self::Resolver, out: Place<Self::Archived>) {
+        munge!(let ArchivedFrequency(hz) = out);
+        self.0.resolve((), hz);
+    }
+}
+
+impl<S: Fallible + ?Sized> Serialize<S> for Frequency {
+    fn serialize(&self, _: &mut S) -> Result<Self::Resolver, S::Error> {
+        Ok(())
+    }
+}
+
+impl<D: Fallible + ?Sized> Deserialize<Frequency, D> for ArchivedFrequency {
+    fn deserialize(&self, _: &mut D) -> Result<Frequency, D::Error> {
+        Ok(self.get())
+    }
+}
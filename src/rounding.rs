@@ -0,0 +1,31 @@
+/// Describes how to round the result of a conversion that cannot be represented exactly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub enum Rounding {
+    /// Round towards zero
+    Down,
+
+    /// Round to the nearest value, with ties rounding up
+    #[default]
+    Nearest,
+
+    /// Round away from zero
+    Up,
+}
+
+impl Rounding {
+    /// Divides `numerator` by `denominator`, rounding the quotient as requested.
+    ///
+    /// Returns `None` if `denominator` is zero.
+    pub(crate) fn div(self, numerator: u128, denominator: u128) -> Option<u128> {
+        let quotient = numerator.checked_div(denominator)?;
+        let remainder = numerator % denominator;
+
+        let round_up = match self {
+            Rounding::Down => false,
+            Rounding::Nearest => remainder >= denominator - remainder,
+            Rounding::Up => remainder != 0,
+        };
+
+        Some(quotient + u128::from(round_up))
+    }
+}
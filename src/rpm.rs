@@ -0,0 +1,158 @@
+use std::{
+    fmt::Display,
+    ops::{Add, Div, Mul, Sub},
+    str::FromStr,
+};
+
+use crate::{Error, Frequency, Result, Rounding, parse_suffixed_f64, round_to_u64};
+
+/// Represents a rotational speed in revolutions per minute
+///
+/// Converting a [`Frequency`] to `Rpm` is lossless. Converting back divides by 60, so the
+/// rounding mode has to be chosen explicitly with [`Rpm::to_frequency`], or the conversion can
+/// be checked for exactness with [`Rpm::to_frequency_exact`].
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Rounding, Rpm};
+///
+/// let fan: Rpm = "3000 rpm".parse().unwrap();
+/// assert_eq!(fan.to_frequency_exact(), Some(Frequency::from_hz(50)));
+///
+/// let motor = Rpm::from(Frequency::from_hz(25));
+/// assert_eq!(motor, Rpm(1500));
+///
+/// assert_eq!(Rpm(90).to_frequency(Rounding::Down), Frequency::from_hz(1));
+/// assert_eq!(Rpm(90).to_frequency(Rounding::Up), Frequency::from_hz(2));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct Rpm(pub u64);
+
+impl Rpm {
+    /// Equivalent to `0 rpm`
+    pub const ZERO: Self = Self(0);
+
+    #[must_use]
+    pub fn as_rpm(&self) -> u64 {
+        self.0
+    }
+
+    /// Converts the rotational speed to a frequency, rounding as requested.
+    #[must_use]
+    // The quotient is at most u64::MAX / 60, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn to_frequency(&self, rounding: Rounding) -> Frequency {
+        Frequency(rounding.div(u128::from(self.0), 60).unwrap_or_default() as u64)
+    }
+
+    /// Converts the rotational speed to a frequency if it is a whole number of hertz.
+    #[must_use]
+    pub fn to_frequency_exact(&self) -> Option<Frequency> {
+        self.0.is_multiple_of(60).then_some(Frequency(self.0 / 60))
+    }
+
+    /// Converts a frequency to a rotational speed, or returns `None` if it does not fit in a
+    /// `u64`.
+    #[must_use]
+    pub fn checked_from_frequency(freq: Frequency) -> Option<Self> {
+        freq.0.checked_mul(60).map(Self)
+    }
+
+    /// Adds two rotational speeds, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.0.checked_add(other.0).map(Self)
+    }
+
+    /// Subtracts `other`, or returns `None` if the result would be negative.
+    #[must_use]
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        self.0.checked_sub(other.0).map(Self)
+    }
+
+    /// Multiplies by `rhs`, or returns `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_mul(self, rhs: u64) -> Option<Self> {
+        self.0.checked_mul(rhs).map(Self)
+    }
+
+    /// Divides by `rhs`, or returns `None` if `rhs` is zero.
+    #[must_use]
+    pub fn checked_div(self, rhs: u64) -> Option<Self> {
+        self.0.checked_div(rhs).map(Self)
+    }
+}
+
+impl From<Frequency> for Rpm {
+    /// # Panics
+    ///
+    /// Panics if the result does not fit in a `u64` and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Rpm::checked_from_frequency`] for a checked
+    /// alternative.
+    fn from(freq: Frequency) -> Self {
+        Self(freq.0 * 60)
+    }
+}
+
+impl Add for Rpm {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`Rpm::checked_add`] for a checked alternative.
+    fn add(self, other: Self) -> Self::Output {
+        Self(self.0 + other.0)
+    }
+}
+
+impl Sub for Rpm {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result would be negative and overflow checks are enabled, as in debug
+    /// builds, and wraps otherwise. See [`Rpm::checked_sub`] for a checked alternative.
+    fn sub(self, other: Self) -> Self::Output {
+        Self(self.0 - other.0)
+    }
+}
+
+impl Mul<u64> for Rpm {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if the result does not fit and overflow checks are enabled, as in debug builds,
+    /// and wraps otherwise. See [`Rpm::checked_mul`] for a checked alternative.
+    fn mul(self, rhs: u64) -> Self::Output {
+        Self(self.0 * rhs)
+    }
+}
+
+impl Div<u64> for Rpm {
+    type Output = Self;
+
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero. See [`Rpm::checked_div`] for a checked alternative.
+    fn div(self, rhs: u64) -> Self::Output {
+        Self(self.0 / rhs)
+    }
+}
+
+impl Display for Rpm {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} rpm", self.0)
+    }
+}
+
+impl FromStr for Rpm {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_suffixed_f64(s, &["rpm"], |value| round_to_u64(value).map(Self))
+    }
+}
@@ -0,0 +1,32 @@
+use rusqlite::{
+    ToSql,
+    types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef},
+};
+
+use crate::Frequency;
+
+impl ToSql for Frequency {
+    /// Binds the frequency as an `INTEGER` number of hertz.
+    ///
+    /// Frequencies above `i64::MAX` Hz do not fit and fail to bind.
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        self.0.to_sql()
+    }
+}
+
+impl FromSql for Frequency {
+    /// Reads a non-negative `INTEGER` number of hertz, or a frequency string such as `"2.4 GHz"`
+    /// from a `TEXT` value.
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value {
+            ValueRef::Integer(hz) => u64::try_from(hz)
+                .map(Self)
+                .map_err(|_| FromSqlError::OutOfRange(hz)),
+            ValueRef::Text(text) => std::str::from_utf8(text)
+                .map_err(|e| FromSqlError::Other(Box::new(e)))?
+                .parse()
+                .map_err(|e| FromSqlError::Other(Box::new(e))),
+            _ => Err(FromSqlError::InvalidType),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+use std::{fmt::Display, str::FromStr, time::Duration};
+
+use crate::{Error, Frequency, Result, consts, math::gcd, parse_frequency};
+
+const NANOS_PER_SECOND: u128 = 1_000_000_000;
+
+/// Represents a sampling rate, as used in audio and DSP code
+///
+/// This is a thin wrapper around a [`Frequency`] that adds domain-specific helpers such as
+/// [`SampleRate::nyquist`] and conversions between sample counts and durations.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::time::Duration;
+/// use parse_frequency::{Frequency, SampleRate};
+///
+/// let rate = SampleRate::KHZ_48;
+/// assert_eq!(rate.nyquist(), Frequency::from_khz(24));
+/// assert_eq!(rate.samples_in(Duration::from_millis(10)), 480);
+///
+/// let rate: SampleRate = "44.1 kHz".parse().unwrap();
+/// assert_eq!(rate, SampleRate::KHZ_44_1);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+#[repr(transparent)]
+pub struct SampleRate(pub Frequency);
+
+impl SampleRate {
+    /// 8 kHz, narrowband telephony
+    pub const KHZ_8: Self = Self(Frequency(8_000));
+
+    /// 16 kHz, wideband speech
+    pub const KHZ_16: Self = Self(Frequency(16_000));
+
+    /// 44.1 kHz, CD audio
+    pub const KHZ_44_1: Self = Self(consts::AUDIO_44K1);
+
+    /// 48 kHz, professional audio and video
+    pub const KHZ_48: Self = Self(consts::AUDIO_48K);
+
+    /// 96 kHz, high resolution audio
+    pub const KHZ_96: Self = Self(consts::AUDIO_96K);
+
+    /// 192 kHz, high resolution audio
+    pub const KHZ_192: Self = Self(Frequency(192_000));
+
+    #[must_use]
+    pub fn new(freq: Frequency) -> Self {
+        Self(freq)
+    }
+
+    #[must_use]
+    #[doc(alias = "from_hertz")]
+    pub fn from_hz(hz: u64) -> Self {
+        Self(Frequency(hz))
+    }
+
+    #[must_use]
+    pub fn as_frequency(&self) -> Frequency {
+        self.0
+    }
+
+    #[must_use]
+    #[doc(alias = "as_hertz")]
+    pub fn as_hz(&self) -> u64 {
+        self.0.as_hz()
+    }
+
+    /// Returns the Nyquist frequency, the highest frequency that can be represented at this
+    /// sample rate (half the sample rate, rounded down).
+    #[must_use]
+    pub fn nyquist(&self) -> Frequency {
+        self.0 / 2
+    }
+
+    /// Returns the number of whole samples that fit in `duration`.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::SampleRate;
+    ///
+    /// assert_eq!(SampleRate::KHZ_44_1.samples_in(Duration::from_secs(2)), 88_200);
+    /// ```
+    #[must_use]
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn samples_in(&self, duration: Duration) -> u64 {
+        // A saturated product still leaves more than `u64::MAX` samples after dividing
+        let samples =
+            duration.as_nanos().saturating_mul(u128::from(self.as_hz())) / NANOS_PER_SECOND;
+        samples.min(u128::from(u64::MAX)) as u64
+    }
+
+    /// Returns the duration of a single sample, truncated to whole nanoseconds.
+    ///
+    /// A sample rate of `0 Hz` results in [`Duration::ZERO`].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::SampleRate;
+    ///
+    /// assert_eq!(SampleRate::KHZ_8.sample_period(), Duration::from_micros(125));
+    /// ```
+    #[must_use]
+    pub fn sample_period(&self) -> Duration {
+        self.duration_of(1)
+    }
+
+    /// Returns the duration of `samples` samples, truncated to whole nanoseconds.
+    ///
+    /// A sample rate of `0 Hz` results in [`Duration::ZERO`].
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use parse_frequency::SampleRate;
+    ///
+    /// assert_eq!(SampleRate::KHZ_48.duration_of(480), Duration::from_millis(10));
+    /// ```
+    #[must_use]
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn duration_of(&self, samples: u64) -> Duration {
+        let Some(nanos) =
+            (u128::from(samples) * NANOS_PER_SECOND).checked_div(u128::from(self.as_hz()))
+        else {
+            return Duration::ZERO;
+        };
+
+        Duration::from_nanos(nanos.min(u128::from(u64::MAX)) as u64)
+    }
+}
+
+impl Frequency {
+    /// Returns the exact ratio between two rates as a reduced fraction `(from, to)`.
+    ///
+    /// Resampling from `from` to `to` means upsampling by the second value and then decimating
+    /// by the first. If both rates are zero, `(0, 0)` is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let ratio = Frequency::resample_ratio(Frequency::from_hz(44_100), Frequency::from_khz(48));
+    /// assert_eq!(ratio, (147, 160));
+    /// ```
+    #[must_use]
+    // The quotients never exceed the original rates, so truncation cannot occur
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn resample_ratio(from: impl Into<Frequency>, to: impl Into<Frequency>) -> (u64, u64) {
+        let (from, to) = (u128::from(from.into().0), u128::from(to.into().0));
+
+        // The divisor is only zero if both rates are zero
+        let divisor = gcd(from, to).max(1);
+        ((from / divisor) as u64, (to / divisor) as u64)
+    }
+
+    /// Returns the resampling factor `to / from` as a floating point value.
+    ///
+    /// A `from` rate of zero results in `f64::INFINITY` (or `NaN` if both rates are zero).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let factor = Frequency::resample_factor(Frequency::from_khz(48), Frequency::from_khz(96));
+    /// assert_eq!(factor, 2.0);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn resample_factor(from: impl Into<Frequency>, to: impl Into<Frequency>) -> f64 {
+        let (from, to) = Self::resample_ratio(from, to);
+        to as f64 / from as f64
+    }
+}
+
+impl From<Frequency> for SampleRate {
+    fn from(freq: Frequency) -> Self {
+        Self(freq)
+    }
+}
+
+impl From<SampleRate> for Frequency {
+    fn from(rate: SampleRate) -> Self {
+        rate.0
+    }
+}
+
+impl Display for SampleRate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl FromStr for SampleRate {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        parse_frequency(s).map(Self)
+    }
+}
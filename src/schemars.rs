@@ -1,7 +1,10 @@
-use super::Frequency;
+use super::{FREQUENCY_DESCRIPTION, FREQUENCY_EXAMPLES, FREQUENCY_PATTERN, Frequency};
 use schemars::{
-    JsonSchema, SchemaGenerator,
-    schema::{InstanceType, Metadata, Schema, SchemaObject},
+    _serde_json, JsonSchema, SchemaGenerator,
+    schema::{
+        InstanceType, Metadata, NumberValidation, Schema, SchemaObject, StringValidation,
+        SubschemaValidation,
+    },
 };
 
 impl JsonSchema for Frequency {
@@ -10,13 +13,40 @@ impl JsonSchema for Frequency {
     }
 
     fn json_schema(_gen: &mut SchemaGenerator) -> Schema {
-        Schema::Object(SchemaObject {
+        let hertz = SchemaObject {
+            instance_type: Some(InstanceType::Integer.into()),
+            format: Some("uint64".to_string()),
+            number: Some(Box::new(NumberValidation {
+                minimum: Some(0.0),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        let string = SchemaObject {
             instance_type: Some(InstanceType::String.into()),
             format: Some("frequency".to_string()),
+            string: Some(Box::new(StringValidation {
+                pattern: Some(FREQUENCY_PATTERN.to_string()),
+                ..Default::default()
+            })),
+            metadata: Some(Box::new(Metadata {
+                examples: FREQUENCY_EXAMPLES
+                    .into_iter()
+                    .map(_serde_json::Value::from)
+                    .collect(),
+                ..Default::default()
+            })),
+            ..Default::default()
+        };
+
+        Schema::Object(SchemaObject {
+            subschemas: Some(Box::new(SubschemaValidation {
+                one_of: Some(vec![hertz.into(), string.into()]),
+                ..Default::default()
+            })),
             metadata: Some(Box::new(Metadata {
-                description: Some(
-                    "A frequency value like \"2.4 GHz\", \"100 kHz\", or \"440Hz\"".to_string(),
-                ),
+                description: Some(FREQUENCY_DESCRIPTION.to_string()),
                 ..Default::default()
             })),
             ..Default::default()
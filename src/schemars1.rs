@@ -0,0 +1,32 @@
+use super::{FREQUENCY_DESCRIPTION, FREQUENCY_EXAMPLES, FREQUENCY_PATTERN, Frequency};
+use ::schemars1::{JsonSchema, Schema, SchemaGenerator, json_schema};
+use std::borrow::Cow;
+
+impl JsonSchema for Frequency {
+    fn schema_name() -> Cow<'static, str> {
+        "Frequency".into()
+    }
+
+    fn schema_id() -> Cow<'static, str> {
+        "parse_frequency::Frequency".into()
+    }
+
+    fn json_schema(_generator: &mut SchemaGenerator) -> Schema {
+        json_schema!({
+            "description": FREQUENCY_DESCRIPTION,
+            "oneOf": [
+                {
+                    "type": "integer",
+                    "format": "uint64",
+                    "minimum": 0,
+                },
+                {
+                    "type": "string",
+                    "format": "frequency",
+                    "pattern": FREQUENCY_PATTERN,
+                    "examples": FREQUENCY_EXAMPLES,
+                },
+            ],
+        })
+    }
+}
@@ -0,0 +1,61 @@
+use sea_orm::{
+    ColIdx, DbErr, QueryResult, TryGetError, TryGetable, Value,
+    sea_query::{ArrayType, ColumnType, Nullable, StringLen, ValueType, ValueTypeErr},
+};
+
+use crate::Frequency;
+
+impl From<Frequency> for Value {
+    /// Converts the frequency to its exact string, such as `"145.5 MHz"`.
+    ///
+    /// A `BigInteger` column cannot hold frequencies above `i64::MAX` Hz, so frequencies are
+    /// stored as text, which keeps every value up to `u64::MAX` Hz exactly.
+    fn from(freq: Frequency) -> Self {
+        Value::String(Some(Box::new(freq.to_exact_string())))
+    }
+}
+
+impl Nullable for Frequency {
+    fn null() -> Value {
+        Value::String(None)
+    }
+}
+
+impl ValueType for Frequency {
+    /// Accepts a frequency string such as `"2.4 GHz"`, or a non-negative integer number of
+    /// hertz.
+    fn try_from(value: Value) -> Result<Self, ValueTypeErr> {
+        match value {
+            Value::String(Some(s)) => s.parse().map_err(|_| ValueTypeErr),
+            Value::BigInt(Some(hz)) => <u64 as TryFrom<i64>>::try_from(hz)
+                .map(Self)
+                .map_err(|_| ValueTypeErr),
+            Value::BigUnsigned(Some(hz)) => Ok(Self(hz)),
+            _ => Err(ValueTypeErr),
+        }
+    }
+
+    fn type_name() -> String {
+        "Frequency".to_string()
+    }
+
+    fn array_type() -> ArrayType {
+        ArrayType::String
+    }
+
+    fn column_type() -> ColumnType {
+        ColumnType::String(StringLen::None)
+    }
+}
+
+impl TryGetable for Frequency {
+    /// Reads a frequency string such as `"2.4 GHz"`.
+    fn try_get_by<I: ColIdx>(res: &QueryResult, index: I) -> Result<Self, TryGetError> {
+        let s = String::try_get_by(res, index)?;
+        s.parse().map_err(|err| {
+            TryGetError::DbErr(DbErr::Type(format!(
+                "{s:?} is not a valid frequency: {err}"
+            )))
+        })
+    }
+}
@@ -1,19 +1,42 @@
-use super::{Frequency, parse_frequency, serde};
-use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de};
+//! Serde support for [`Frequency`] and related types
+//!
+//! By default a frequency is serialized losslessly: as an exact string such as
+//! `"2.400000001 GHz"` in human-readable formats, and as an integer number of hertz in compact
+//! binary formats. The modules in here
+//! can be used with `#[serde(with = "...")]` to pick a different representation for a single
+//! field.
+
+use super::{
+    Frequency, FrequencyF64, FrequencyMilliHz, FrequencyRange, FrequencyShift, FrequencyUnit,
+    RationalFrequency, Rounding, SampleRate, parse_frequency, serde,
+};
+use ::serde::{Deserialize, Deserializer, Serialize, Serializer, de, ser::SerializeStruct};
 
 impl<'de> Deserialize<'de> for Frequency {
+    /// Deserializes a frequency string such as `"2.4 GHz"` or a number of hertz such as
+    /// `2400000000` or `2.4e9` from human-readable formats, and an integer number of hertz from
+    /// compact formats.
+    ///
+    /// Strings are parsed where they are, borrowed from the input when the format allows, so
+    /// deserializing a frequency never allocates unless it fails.
     fn deserialize<D>(
         deserializer: D,
     ) -> std::result::Result<Frequency, <D as serde::Deserializer<'de>>::Error>
     where
         D: Deserializer<'de>,
     {
-        let s = String::deserialize(deserializer)?;
-        parse_frequency(&s).map_err(de::Error::custom)
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FrequencyVisitor)
+        } else {
+            deserializer.deserialize_u64(FrequencyVisitor)
+        }
     }
 }
 
 impl Serialize for Frequency {
+    /// Serializes an exact frequency string such as `"2.400000001 GHz"` for human-readable
+    /// formats such as JSON and TOML, and an integer number of hertz for compact formats such as
+    /// bincode and postcard. Both round-trip without losing precision.
     fn serialize<S>(
         &self,
         serializer: S,
@@ -21,6 +44,736 @@ impl Serialize for Frequency {
     where
         S: Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        if serializer.is_human_readable() {
+            serializer.serialize_str(&self.to_exact_string())
+        } else {
+            serializer.serialize_u64(self.0)
+        }
+    }
+}
+
+/// Accepts a frequency string or a number of hertz, rounded to the nearest hertz
+struct FrequencyVisitor;
+
+impl de::Visitor<'_> for FrequencyVisitor {
+    type Value = Frequency;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a frequency string or a non-negative number of hertz")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Frequency, E> {
+        parse_frequency(value).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Frequency, E> {
+        Ok(Frequency(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Frequency, E> {
+        u64::try_from(value)
+            .map(Frequency)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> std::result::Result<Frequency, E> {
+        ScaledVisitor {
+            unit: 1,
+            name: "hertz",
+        }
+        .visit_f64(value)
+    }
+}
+
+impl Serialize for FrequencyRange {
+    /// Serializes the range as a struct with `min` and `max` fields.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut range = serializer.serialize_struct("FrequencyRange", 2)?;
+        range.serialize_field("min", &self.min)?;
+        range.serialize_field("max", &self.max)?;
+        range.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for FrequencyRange {
+    /// Deserializes the range from a struct with `min` and `max` fields, swapping them if `min`
+    /// is greater than `max`.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("FrequencyRange", RANGE_FIELDS, RangeVisitor)
+    }
+}
+
+const RANGE_FIELDS: &[&str] = &["min", "max"];
+
+/// Accepts a `FrequencyRange` as a map or, in compact formats, a sequence of its fields
+struct RangeVisitor;
+
+impl<'de> de::Visitor<'de> for RangeVisitor {
+    type Value = FrequencyRange;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a frequency range with `min` and `max` fields")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<FrequencyRange, A::Error> {
+        let min = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let max = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Ok(FrequencyRange::new(min, max))
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<FrequencyRange, A::Error> {
+        let (mut min, mut max) = (None, None);
+
+        while let Some(index) = map.next_key_seed(FieldSeed(RANGE_FIELDS))? {
+            let field = if index == 0 { &mut min } else { &mut max };
+
+            if field.is_some() {
+                return Err(de::Error::duplicate_field(RANGE_FIELDS[index]));
+            }
+            *field = Some(map.next_value()?);
+        }
+
+        let min = min.ok_or_else(|| de::Error::missing_field("min"))?;
+        let max = max.ok_or_else(|| de::Error::missing_field("max"))?;
+        Ok(FrequencyRange::new(min, max))
+    }
+}
+
+/// Identifies a struct field by its index in a list of field names, borrowing the name from the
+/// input instead of allocating a `String` for every key
+struct FieldSeed(&'static [&'static str]);
+
+impl<'de> de::DeserializeSeed<'de> for FieldSeed {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_identifier(self)
+    }
+}
+
+impl de::Visitor<'_> for FieldSeed {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "one of the fields {:?}", self.0)
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|field| *field == value)
+            .ok_or_else(|| E::unknown_field(value, self.0))
+    }
+}
+
+impl Serialize for FrequencyShift {
+    /// Serializes the shift as a floating point number of hertz.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FrequencyShift {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for SampleRate {
+    /// Serializes the sample rate like a [`Frequency`].
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for SampleRate {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Frequency::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for FrequencyF64 {
+    /// Serializes the frequency as a floating point number of hertz.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FrequencyF64 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        f64::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for FrequencyMilliHz {
+    /// Serializes the frequency as an integer number of millihertz.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for FrequencyMilliHz {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        u64::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Serialize for RationalFrequency {
+    /// Serializes the frequency as a string such as `"30000/1001 Hz"` for human-readable formats,
+    /// and as a `(numerator, denominator)` tuple for compact formats.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        if serializer.is_human_readable() {
+            serializer.collect_str(self)
+        } else {
+            (self.num(), self.den()).serialize(serializer)
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for RationalFrequency {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_str(RationalVisitor)
+        } else {
+            let (num, den) = <(u64, u64)>::deserialize(deserializer)?;
+            Self::new(num, den).ok_or_else(|| de::Error::custom("denominator must not be zero"))
+        }
+    }
+}
+
+impl Serialize for FrequencyUnit {
+    /// Serializes the unit as its symbol, such as `"kHz"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.symbol())
+    }
+}
+
+impl<'de> Deserialize<'de> for FrequencyUnit {
+    /// Deserializes the unit from its symbol, ignoring case.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(UnitVisitor)
+    }
+}
+
+const UNIT_SYMBOLS: &[&str] = &["Hz", "kHz", "MHz", "GHz"];
+
+/// Accepts a unit symbol, ignoring case
+struct UnitVisitor;
+
+impl de::Visitor<'_> for UnitVisitor {
+    type Value = FrequencyUnit;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "one of the units {UNIT_SYMBOLS:?}")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<FrequencyUnit, E> {
+        value
+            .parse()
+            .map_err(|_| E::unknown_variant(value, UNIT_SYMBOLS))
+    }
+}
+
+/// Accepts a rational frequency string such as `"30000/1001 Hz"`
+struct RationalVisitor;
+
+impl de::Visitor<'_> for RationalVisitor {
+    type Value = RationalFrequency;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a rational frequency string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<RationalFrequency, E> {
+        value.parse().map_err(E::custom)
+    }
+}
+
+/// Accepts an integer or floating point number of `unit` hertz, rounded to the nearest hertz
+struct ScaledVisitor {
+    unit: u64,
+    name: &'static str,
+}
+
+impl de::Visitor<'_> for ScaledVisitor {
+    type Value = Frequency;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "a non-negative number of {}", self.name)
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Frequency, E> {
+        value
+            .checked_mul(self.unit)
+            .map(Frequency)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(value), &self))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Frequency, E> {
+        u64::try_from(value)
+            .map_err(|_| E::invalid_value(de::Unexpected::Signed(value), &self))
+            .and_then(|value| self.visit_u64(value))
+    }
+
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn visit_f64<E: de::Error>(self, value: f64) -> std::result::Result<Frequency, E> {
+        FrequencyF64::from_hz(value * self.unit as f64)
+            .try_to_frequency(Rounding::Nearest)
+            .ok_or_else(|| E::invalid_value(de::Unexpected::Float(value), &self))
+    }
+}
+
+/// Serializes a [`Frequency`] as an integer number of hertz, which is lossless and compact
+///
+/// Deserializing accepts both a number of hertz and a frequency string, so existing data keeps
+/// working.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize, PartialEq, Debug)]
+/// struct Config {
+///     #[serde(with = "parse_frequency::serde::hz")]
+///     clock: Frequency,
+/// }
+///
+/// let config = Config { clock: Frequency::from_hz(2_400_000_001) };
+/// let json = serde_json::to_string(&config).unwrap();
+/// assert_eq!(json, r#"{"clock":2400000001}"#);
+///
+/// let old: Config = serde_json::from_str(r#"{"clock":"2.4 GHz"}"#).unwrap();
+/// assert_eq!(old.clock, Frequency::from_mhz(2_400));
+/// ```
+pub mod hz {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::FrequencyVisitor;
+    use crate::Frequency;
+
+    /// Serializes the frequency as an integer number of hertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(freq.0)
+    }
+
+    /// Deserializes a frequency from a number of hertz or a frequency string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is neither a non-negative number nor a valid frequency
+    /// string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(FrequencyVisitor)
+        } else {
+            deserializer.deserialize_u64(FrequencyVisitor)
+        }
+    }
+}
+
+/// Serializes a [`Frequency`] as an integer number of kilohertz
+///
+/// Serializing fails for frequencies that are not a whole number of kilohertz, so no precision
+/// is ever lost silently. Deserializing accepts integer and floating point numbers of kilohertz.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Uart {
+///     #[serde(with = "parse_frequency::serde::khz")]
+///     baud_clock: Frequency,
+/// }
+///
+/// let uart = Uart { baud_clock: Frequency::from_khz(1_843) };
+/// assert_eq!(serde_json::to_string(&uart).unwrap(), r#"{"baud_clock":1843}"#);
+///
+/// let uart: Uart = serde_json::from_str(r#"{"baud_clock":1843.2}"#).unwrap();
+/// assert_eq!(uart.baud_clock, Frequency::from_hz(1_843_200));
+/// ```
+pub mod khz {
+    use ::serde::{Deserializer, Serializer, ser};
+
+    use super::ScaledVisitor;
+    use crate::{Frequency, KILOHERTZ};
+
+    /// Serializes the frequency as an integer number of kilohertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the frequency is not a whole number of kilohertz or the serializer
+    /// fails.
+    pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        if !freq.0.is_multiple_of(KILOHERTZ) {
+            return Err(ser::Error::custom(format!(
+                "{} Hz is not a whole number of kilohertz",
+                freq.0
+            )));
+        }
+
+        serializer.serialize_u64(freq.as_khz())
+    }
+
+    /// Deserializes a frequency from a number of kilohertz, rounded to the nearest hertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a non-negative number or does not fit.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        let visitor = ScaledVisitor {
+            unit: KILOHERTZ,
+            name: "kilohertz",
+        };
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_u64(visitor)
+        }
+    }
+}
+
+/// Serializes a [`Frequency`] as a floating point number of megahertz
+///
+/// Convenient for radio configuration, but frequencies above 2^53 Hz lose precision.
+/// Deserializing rounds to the nearest hertz.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Radio {
+///     #[serde(with = "parse_frequency::serde::mhz_float")]
+///     tune: Frequency,
+/// }
+///
+/// let radio = Radio { tune: Frequency::from_khz(145_500) };
+/// assert_eq!(serde_json::to_string(&radio).unwrap(), r#"{"tune":145.5}"#);
+///
+/// let radio: Radio = serde_json::from_str(r#"{"tune":433.92}"#).unwrap();
+/// assert_eq!(radio.tune, Frequency::from_khz(433_920));
+/// ```
+pub mod mhz_float {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::ScaledVisitor;
+    use crate::{Frequency, MEGAHERTZ};
+
+    /// Serializes the frequency as a floating point number of megahertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(freq.0 as f64 / MEGAHERTZ as f64)
+    }
+
+    /// Deserializes a frequency from a number of megahertz, rounded to the nearest hertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a non-negative number or does not fit.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        let visitor = ScaledVisitor {
+            unit: MEGAHERTZ,
+            name: "megahertz",
+        };
+
+        if deserializer.is_human_readable() {
+            deserializer.deserialize_any(visitor)
+        } else {
+            deserializer.deserialize_f64(visitor)
+        }
+    }
+}
+
+/// Serializes a [`Frequency`] as a pretty string such as `"1.34 GHz"`, in every format
+///
+/// This uses the [`Display`](std::fmt::Display) format, which rounds to two decimals and is
+/// therefore lossy. Unlike the default implementation, it also uses strings in compact binary
+/// formats.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Config {
+///     #[serde(with = "parse_frequency::serde::string")]
+///     clock: Frequency,
+/// }
+///
+/// let config: Config = serde_json::from_str(r#"{"clock":"16 MHz"}"#).unwrap();
+/// assert_eq!(config.clock, Frequency::from_mhz(16));
+/// assert_eq!(serde_json::to_string(&config).unwrap(), r#"{"clock":"16.00 MHz"}"#);
+/// ```
+pub mod string {
+    use ::serde::{Deserializer, Serializer};
+
+    use super::FrequencyVisitor;
+    use crate::Frequency;
+
+    /// Serializes the frequency as a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(freq)
+    }
+
+    /// Deserializes a frequency from a string.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the value is not a valid frequency string.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        deserializer.deserialize_str(FrequencyVisitor)
+    }
+}
+
+/// Serializes a [`Frequency`] as a struct such as `{ "value": 2.5, "unit": "GHz" }`
+///
+/// The largest unit the frequency reaches is used, like the [`Display`](std::fmt::Display)
+/// format. Deserializing accepts any [`FrequencyUnit`] symbol, ignoring case, and rejects
+/// unknown units. The value is a floating point number, so frequencies above 2^53 Hz lose
+/// precision.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::Frequency;
+/// use serde::{Deserialize, Serialize};
+///
+/// #[derive(Serialize, Deserialize)]
+/// struct Channel {
+///     #[serde(with = "parse_frequency::serde::structured")]
+///     center: Frequency,
+/// }
+///
+/// let channel = Channel { center: Frequency::from_mhz(2_500) };
+/// let json = serde_json::to_string(&channel).unwrap();
+/// assert_eq!(json, r#"{"center":{"value":2.5,"unit":"GHz"}}"#);
+///
+/// let channel: Channel = serde_json::from_str(r#"{"center":{"value":433.92,"unit":"MHz"}}"#).unwrap();
+/// assert_eq!(channel.center, Frequency::from_khz(433_920));
+///
+/// assert!(serde_json::from_str::<Channel>(r#"{"center":{"value":1,"unit":"THz"}}"#).is_err());
+/// ```
+pub mod structured {
+    use ::serde::{Deserializer, Serializer, ser::SerializeStruct};
+
+    use super::{STRUCTURED_FIELDS, StructuredVisitor};
+    use crate::{Frequency, FrequencyUnit};
+
+    /// Serializes the frequency as a struct with `value` and `unit` fields.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the serializer fails.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn serialize<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        let unit = FrequencyUnit::for_frequency(*freq);
+        let mut structured = serializer.serialize_struct("Frequency", 2)?;
+        structured.serialize_field("value", &(freq.0 as f64 / unit.multiplier() as f64))?;
+        structured.serialize_field("unit", &unit)?;
+        structured.end()
+    }
+
+    /// Deserializes a frequency from a struct with `value` and `unit` fields, rounded to the
+    /// nearest hertz.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a field is missing, the unit is unknown, or the value is not a
+    /// non-negative number that fits.
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        deserializer.deserialize_struct("Frequency", STRUCTURED_FIELDS, StructuredVisitor)
+    }
+}
+
+const STRUCTURED_FIELDS: &[&str] = &["value", "unit"];
+
+/// Accepts a structured frequency as a map or, in compact formats, a sequence of its fields
+struct StructuredVisitor;
+
+impl StructuredVisitor {
+    fn build<E: de::Error>(value: f64, unit: FrequencyUnit) -> Result<Frequency, E> {
+        let visitor = ScaledVisitor {
+            unit: unit.multiplier(),
+            name: unit.symbol(),
+        };
+        de::Visitor::visit_f64(visitor, value)
+    }
+}
+
+impl<'de> de::Visitor<'de> for StructuredVisitor {
+    type Value = Frequency;
+
+    fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.write_str("a frequency with `value` and `unit` fields")
+    }
+
+    fn visit_seq<A: de::SeqAccess<'de>>(self, mut seq: A) -> Result<Frequency, A::Error> {
+        let value = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let unit = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        Self::build(value, unit)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut map: A) -> Result<Frequency, A::Error> {
+        let (mut value, mut unit) = (None, None);
+
+        while let Some(index) = map.next_key_seed(FieldSeed(STRUCTURED_FIELDS))? {
+            match index {
+                0 if value.is_none() => value = Some(map.next_value()?),
+                1 if unit.is_none() => unit = Some(map.next_value()?),
+                _ => return Err(de::Error::duplicate_field(STRUCTURED_FIELDS[index])),
+            }
+        }
+
+        let value = value.ok_or_else(|| de::Error::missing_field("value"))?;
+        let unit = unit.ok_or_else(|| de::Error::missing_field("unit"))?;
+        Self::build(value, unit)
+    }
+}
+
+/// [`serde_with`] adapter that serializes a [`Frequency`] like the [`hz`] module
+///
+/// Unlike the module, it also works for frequencies nested in `Vec`, `Option` or maps.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, serde::FrequencyAsHz};
+/// use serde::{Deserialize, Serialize};
+/// use serde_with::serde_as;
+///
+/// #[serde_as]
+/// #[derive(Serialize, Deserialize)]
+/// struct Scan {
+///     #[serde_as(as = "Vec<FrequencyAsHz>")]
+///     channels: Vec<Frequency>,
+/// }
+///
+/// let scan = Scan { channels: vec![Frequency::from_khz(1), Frequency::from_hz(1_500)] };
+/// assert_eq!(serde_json::to_string(&scan).unwrap(), r#"{"channels":[1000,1500]}"#);
+/// ```
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct FrequencyAsHz;
+
+#[cfg(feature = "serde_with")]
+impl serde_with::SerializeAs<Frequency> for FrequencyAsHz {
+    fn serialize_as<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        hz::serialize(freq, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de> serde_with::DeserializeAs<'de, Frequency> for FrequencyAsHz {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        hz::deserialize(deserializer)
+    }
+}
+
+/// [`serde_with`] adapter that serializes a [`Frequency`] like the [`khz`] module
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct FrequencyAsKhz;
+
+#[cfg(feature = "serde_with")]
+impl serde_with::SerializeAs<Frequency> for FrequencyAsKhz {
+    fn serialize_as<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        khz::serialize(freq, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de> serde_with::DeserializeAs<'de, Frequency> for FrequencyAsKhz {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        khz::deserialize(deserializer)
+    }
+}
+
+/// [`serde_with`] adapter that serializes a [`Frequency`] like the [`string`] module
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct FrequencyAsString;
+
+#[cfg(feature = "serde_with")]
+impl serde_with::SerializeAs<Frequency> for FrequencyAsString {
+    fn serialize_as<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        string::serialize(freq, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de> serde_with::DeserializeAs<'de, Frequency> for FrequencyAsString {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        string::deserialize(deserializer)
+    }
+}
+
+/// [`serde_with`] adapter that serializes a [`Frequency`] like the [`mhz_float`] module
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct FrequencyAsMhzF64;
+
+#[cfg(feature = "serde_with")]
+impl serde_with::SerializeAs<Frequency> for FrequencyAsMhzF64 {
+    fn serialize_as<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        mhz_float::serialize(freq, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de> serde_with::DeserializeAs<'de, Frequency> for FrequencyAsMhzF64 {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        mhz_float::deserialize(deserializer)
+    }
+}
+
+/// [`serde_with`] adapter that serializes a [`Frequency`] like the [`structured`] module
+#[cfg(feature = "serde_with")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Hash)]
+pub struct FrequencyAsStructured;
+
+#[cfg(feature = "serde_with")]
+impl serde_with::SerializeAs<Frequency> for FrequencyAsStructured {
+    fn serialize_as<S: Serializer>(freq: &Frequency, serializer: S) -> Result<S::Ok, S::Error> {
+        structured::serialize(freq, serializer)
+    }
+}
+
+#[cfg(feature = "serde_with")]
+impl<'de> serde_with::DeserializeAs<'de, Frequency> for FrequencyAsStructured {
+    fn deserialize_as<D: Deserializer<'de>>(deserializer: D) -> Result<Frequency, D::Error> {
+        structured::deserialize(deserializer)
     }
 }
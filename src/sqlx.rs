@@ -0,0 +1,52 @@
+use sqlx::{Database, Decode, Encode, Type, ValueRef, encode::IsNull, error::BoxDynError};
+
+use crate::Frequency;
+
+impl<DB: Database> Type<DB> for Frequency
+where
+    i64: Type<DB>,
+    for<'a> &'a str: Type<DB>,
+{
+    /// Frequencies are stored as a `BIGINT` number of hertz.
+    fn type_info() -> DB::TypeInfo {
+        <i64 as Type<DB>>::type_info()
+    }
+
+    /// Accepts integer columns, and text columns holding frequency strings.
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <i64 as Type<DB>>::compatible(ty) || <&str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB: Database> Encode<'q, DB> for Frequency
+where
+    i64: Encode<'q, DB>,
+{
+    /// Encodes the frequency as a `BIGINT` number of hertz.
+    ///
+    /// Frequencies above `i64::MAX` Hz do not fit and fail to encode.
+    fn encode_by_ref(
+        &self,
+        buf: &mut <DB as Database>::ArgumentBuffer<'q>,
+    ) -> Result<IsNull, BoxDynError> {
+        i64::try_from(self.0)?.encode_by_ref(buf)
+    }
+}
+
+impl<'r, DB: Database> Decode<'r, DB> for Frequency
+where
+    i64: Decode<'r, DB> + Type<DB>,
+    &'r str: Decode<'r, DB> + Type<DB>,
+{
+    /// Decodes a non-negative integer number of hertz, or a frequency string such as
+    /// `"2.4 GHz"` from a text column.
+    fn decode(value: <DB as Database>::ValueRef<'r>) -> Result<Self, BoxDynError> {
+        let ty = value.type_info();
+        if !<i64 as Type<DB>>::compatible(&ty) && <&str as Type<DB>>::compatible(&ty) {
+            return Ok(<&str as Decode<DB>>::decode(value)?.parse()?);
+        }
+
+        let hz = <i64 as Decode<DB>>::decode(value)?;
+        Ok(Self(u64::try_from(hz)?))
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{Frequency, Ppm, Rounding};
+
+/// Accumulates statistics over a stream of frequency samples
+///
+/// The count, minimum, maximum and mean are tracked exactly with integer arithmetic. The
+/// standard deviation uses Welford's online algorithm, so samples can be recorded one at a
+/// time without storing them.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyStats};
+///
+/// let stats: FrequencyStats = [999_990, 1_000_010, 1_000_000, 1_000_000]
+///     .into_iter()
+///     .map(Frequency::from_hz)
+///     .collect();
+///
+/// assert_eq!(stats.count(), 4);
+/// assert_eq!(stats.min(), Some(Frequency::from_hz(999_990)));
+/// assert_eq!(stats.mean(), Some(Frequency::from_mhz(1)));
+/// assert!((stats.std_dev_ppm().unwrap().0 - 8.165).abs() < 0.001);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct FrequencyStats {
+    count: u64,
+    min: Option<Frequency>,
+    max: Option<Frequency>,
+    sum: u128,
+
+    /// Running mean in hertz for Welford's algorithm
+    mean: f64,
+
+    /// Running sum of squared differences from the mean in hertz²
+    m2: f64,
+}
+
+impl FrequencyStats {
+    /// Creates an empty accumulator.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a sample.
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn record(&mut self, freq: Frequency) {
+        self.count += 1;
+        self.sum += u128::from(freq.0);
+        self.min = Some(self.min.map_or(freq, |min| min.min(freq)));
+        self.max = Some(self.max.map_or(freq, |max| max.max(freq)));
+
+        let hz = freq.0 as f64;
+        let delta = hz - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (hz - self.mean);
+    }
+
+    /// Returns the number of samples recorded.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns the lowest sample, or `None` if no samples were recorded.
+    #[must_use]
+    pub fn min(&self) -> Option<Frequency> {
+        self.min
+    }
+
+    /// Returns the highest sample, or `None` if no samples were recorded.
+    #[must_use]
+    pub fn max(&self) -> Option<Frequency> {
+        self.max
+    }
+
+    /// Returns the exact mean of the samples rounded to the nearest hertz, or `None` if no
+    /// samples were recorded.
+    #[must_use]
+    // The mean never exceeds the largest sample, which fits in a u64
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn mean(&self) -> Option<Frequency> {
+        Rounding::Nearest
+            .div(self.sum, u128::from(self.count))
+            .map(|hz| Frequency(hz as u64))
+    }
+
+    /// Returns the sample standard deviation in hertz, or `None` if fewer than two samples were
+    /// recorded.
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn std_dev_hz(&self) -> Option<f64> {
+        (self.count >= 2).then(|| (self.m2 / (self.count - 1) as f64).sqrt())
+    }
+
+    /// Returns the sample standard deviation rounded to the nearest hertz, or `None` if fewer
+    /// than two samples were recorded.
+    #[must_use]
+    // The standard deviation is never negative and never exceeds the largest sample
+    #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+    pub fn std_dev(&self) -> Option<Frequency> {
+        self.std_dev_hz().map(|hz| Frequency(hz.round() as u64))
+    }
+
+    /// Returns the sample standard deviation relative to the mean, or `None` if fewer than two
+    /// samples were recorded or the mean is `0 Hz`.
+    #[must_use]
+    pub fn std_dev_ppm(&self) -> Option<Ppm> {
+        let std_dev = self.std_dev_hz()?;
+        (self.mean > 0.0).then(|| Ppm(std_dev / self.mean * 1e6))
+    }
+}
+
+impl Extend<Frequency> for FrequencyStats {
+    fn extend<I: IntoIterator<Item = Frequency>>(&mut self, iter: I) {
+        for freq in iter {
+            self.record(freq);
+        }
+    }
+}
+
+impl FromIterator<Frequency> for FrequencyStats {
+    fn from_iter<I: IntoIterator<Item = Frequency>>(iter: I) -> Self {
+        let mut stats = Self::new();
+        stats.extend(iter);
+        stats
+    }
+}
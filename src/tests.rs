@@ -121,4 +121,4428 @@ fn test_serde_roundtrip() {
 
     let parsed: Frequency = serde_json::from_str(&json).unwrap();
     assert_eq!(parsed, freq);
+
+    // Serialization is lossless
+    for hz in [0, 999, 2_400_000_001, 12_345_678, u64::MAX] {
+        let freq = Frequency::from_hz(hz);
+        let json = serde_json::to_string(&freq).unwrap();
+        assert_eq!(serde_json::from_str::<Frequency>(&json).unwrap(), freq);
+    }
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_numbers() {
+    let parse = |json: &str| serde_json::from_str::<Frequency>(json);
+
+    assert_eq!(parse("\"2.4 GHz\"").unwrap(), Frequency::from_mhz(2_400));
+    assert_eq!(parse("2400000000").unwrap(), Frequency::from_mhz(2_400));
+    assert_eq!(parse("2.4e9").unwrap(), Frequency::from_mhz(2_400));
+    assert_eq!(parse("0.6").unwrap(), Frequency::HERTZ);
+
+    assert!(parse("-5").is_err());
+    assert!(parse("-2.4e9").is_err());
+    assert!(parse("1e30").is_err());
+    assert!(parse("true").is_err());
+}
+
+#[test]
+fn test_unit_parse_errors() {
+    use crate::{AngularFrequency, BaudRate, Bpm, Cents, Error, Ppm, Rpm};
+
+    // Every unit reports the whole input, as it was given
+    assert_eq!(
+        " 12 Hz ".parse::<Ppm>(),
+        Err(Error::UnknownUnit(" 12 Hz ".into()))
+    );
+    assert_eq!(
+        "-1 Rad/s".parse::<AngularFrequency>(),
+        Err(Error::InvalidValue("-1 Rad/s".into()))
+    );
+    assert_eq!(
+        "inf cents".parse::<Cents>(),
+        Err(Error::InvalidValue("inf cents".into()))
+    );
+    assert_eq!(
+        "9600.5 baud".parse::<BaudRate>(),
+        Err(Error::InvalidValue("9600.5 baud".into()))
+    );
+    assert!("1e30 BAUD".parse::<BaudRate>().is_err());
+    assert!("1e30 rpm".parse::<Rpm>().is_err());
+    assert!("NaN bpm".parse::<Bpm>().is_err());
+
+    assert_eq!("9.6e3 Bd".parse::<BaudRate>(), Ok(BaudRate::B9600));
+    assert_eq!("-1200 ¢".parse::<Cents>(), Ok(Cents(-1200.0)));
+    assert_eq!("± 20 PPM".parse::<Ppm>(), Ok(Ppm(20.0)));
+}
+
+#[test]
+fn test_angular_frequency() {
+    use crate::AngularFrequency;
+    use std::f64::consts::TAU;
+
+    let omega = Frequency::from_hz(50).as_angular();
+    assert!((omega.as_rad_per_sec() - 50.0 * TAU).abs() < f64::EPSILON * 1000.0);
+    assert_eq!(omega.to_frequency(), Frequency::from_hz(50));
+    assert_eq!(Frequency::from(omega), Frequency::from_hz(50));
+    assert_eq!(omega.to_string(), "314.16 rad/s");
+
+    assert_eq!(
+        "314.16 rad/s".parse::<AngularFrequency>().unwrap(),
+        AngularFrequency(314.16)
+    );
+    assert!("314.16 Hz".parse::<AngularFrequency>().is_err());
+    assert!("-1 rad/s".parse::<AngularFrequency>().is_err());
+    assert_eq!(AngularFrequency(-1.0).to_frequency(), Frequency::ZERO);
+}
+
+#[test]
+fn test_wavelength() {
+    use crate::{SPEED_OF_LIGHT, SPEED_OF_SOUND};
+
+    let freq = Frequency::from_mhz(100);
+    assert!((freq.wavelength() - 2.997_924_58).abs() < 1e-9);
+    assert!((freq.wavelength_in(SPEED_OF_LIGHT * 0.5) - 1.498_962_29).abs() < 1e-9);
+    assert_eq!(Frequency::ZERO.wavelength(), f64::INFINITY);
+
+    assert_eq!(
+        Frequency::from_wavelength(freq.wavelength(), SPEED_OF_LIGHT),
+        freq
+    );
+    assert_eq!(
+        Frequency::from_wavelength(0.5, SPEED_OF_SOUND),
+        Frequency::from_hz(686)
+    );
+    assert_eq!(
+        Frequency::from_wavelength(0.0, SPEED_OF_LIGHT),
+        Frequency::ZERO
+    );
+    assert_eq!(
+        Frequency::from_wavelength(-1.0, SPEED_OF_LIGHT),
+        Frequency::ZERO
+    );
+}
+
+#[test]
+fn test_ppm_window() {
+    use crate::{FrequencyRange, Ppm};
+
+    let window = Frequency::from_mhz(25) * Ppm(20.0);
+    assert_eq!(
+        window,
+        FrequencyRange::new(
+            Frequency::from_hz(24_999_500),
+            Frequency::from_hz(25_000_500)
+        )
+    );
+    assert!(window.contains(Frequency::from_mhz(25)));
+    assert!(!window.contains(Frequency::from_hz(25_000_501)));
+    assert_eq!(window.center(), Frequency::from_mhz(25));
+
+    // Bounds are rounded outwards
+    let window = Frequency::from_hz(32_768) * Ppm(20.0);
+    assert_eq!(window.min, Frequency::from_hz(32_767));
+    assert_eq!(window.max, Frequency::from_hz(32_769));
+}
+
+#[test]
+fn test_ppm_parse_and_compose() {
+    use crate::Ppm;
+
+    assert_eq!("±20 ppm".parse::<Ppm>().unwrap(), Ppm(20.0));
+    assert_eq!("+/-20ppm".parse::<Ppm>().unwrap(), Ppm(20.0));
+    assert_eq!("2.5 PPM".parse::<Ppm>().unwrap(), Ppm(2.5));
+    assert!("20 ppb".parse::<Ppm>().is_err());
+    assert!("±-20 ppm".parse::<Ppm>().is_err());
+    assert_eq!(Ppm(20.0).to_string(), "±20.00 ppm");
+
+    assert_eq!(Ppm(20.0) + Ppm(10.0), Ppm(30.0));
+    assert_eq!(Ppm(3.0).rss(Ppm(4.0)), Ppm(5.0));
+    assert_eq!(Ppm::rss_all([Ppm(3.0), Ppm(4.0)]), Ppm(5.0));
+}
+
+#[test]
+fn test_sample_rate() {
+    use crate::SampleRate;
+    use std::time::Duration;
+
+    assert_eq!(SampleRate::KHZ_44_1.as_hz(), 44_100);
+    assert_eq!(SampleRate::KHZ_44_1.nyquist(), Frequency::from_hz(22_050));
+    assert_eq!(SampleRate::KHZ_192.nyquist(), Frequency::from_khz(96));
+
+    assert_eq!(
+        SampleRate::KHZ_16.samples_in(Duration::from_millis(20)),
+        320
+    );
+    assert_eq!(
+        SampleRate::KHZ_44_1.samples_in(Duration::from_micros(30)),
+        1
+    );
+    assert_eq!(
+        SampleRate::from(Frequency(u64::MAX)).samples_in(Duration::MAX),
+        u64::MAX
+    );
+    assert_eq!(SampleRate::KHZ_48.samples_in(Duration::MAX), u64::MAX);
+    assert_eq!(SampleRate::KHZ_44_1.sample_period().as_nanos(), 22_675);
+    assert_eq!(
+        SampleRate::KHZ_44_1.duration_of(44_100),
+        Duration::from_secs(1)
+    );
+    assert_eq!(SampleRate::default().sample_period(), Duration::ZERO);
+
+    assert_eq!("96kHz".parse::<SampleRate>().unwrap(), SampleRate::KHZ_96);
+    assert_eq!(SampleRate::KHZ_8.to_string(), "8.00 kHz");
+}
+
+#[test]
+fn test_fft_bins() {
+    use crate::{SampleRate, bin_for_frequency, fft_bin_frequencies, fft_bin_frequency};
+
+    let rate = SampleRate::KHZ_44_1;
+    assert_eq!(fft_bin_frequency(rate, 1024, 0), Frequency::ZERO);
+    assert_eq!(fft_bin_frequency(rate, 1024, 1), Frequency::from_hz(43));
+    assert_eq!(
+        fft_bin_frequency(rate, 1024, 512),
+        Frequency::from_hz(22_050)
+    );
+    assert_eq!(fft_bin_frequency(rate, 0, 1), Frequency::ZERO);
+
+    assert_eq!(
+        bin_for_frequency(rate, 1024, Frequency::from_hz(43)),
+        Some(1)
+    );
+    assert_eq!(
+        bin_for_frequency(rate, 1024, Frequency::from_hz(22_050)),
+        Some(512)
+    );
+    assert_eq!(
+        bin_for_frequency(rate, 1024, Frequency::from_hz(22_051)),
+        None
+    );
+    assert_eq!(
+        bin_for_frequency(SampleRate::default(), 1024, Frequency::ZERO),
+        None
+    );
+
+    // Works with plain frequencies too
+    assert_eq!(
+        fft_bin_frequencies(Frequency::from_khz(8), 1024).count(),
+        513
+    );
+    assert_eq!(fft_bin_frequencies(rate, 0).count(), 0);
+}
+
+#[test]
+fn test_resample_ratio() {
+    use crate::SampleRate;
+
+    assert_eq!(
+        Frequency::resample_ratio(SampleRate::KHZ_44_1, SampleRate::KHZ_48),
+        (147, 160)
+    );
+    assert_eq!(
+        Frequency::resample_ratio(SampleRate::KHZ_48, SampleRate::KHZ_44_1),
+        (160, 147)
+    );
+    assert_eq!(
+        Frequency::resample_ratio(SampleRate::KHZ_192, SampleRate::KHZ_48),
+        (4, 1)
+    );
+    assert_eq!(
+        Frequency::resample_ratio(Frequency::ZERO, Frequency::ZERO),
+        (0, 0)
+    );
+
+    let factor = Frequency::resample_factor(SampleRate::KHZ_44_1, SampleRate::KHZ_48);
+    assert!((factor - 160.0 / 147.0).abs() < f64::EPSILON);
+}
+
+#[test]
+fn test_midi_notes() {
+    assert_eq!(Frequency::from_midi_note(69), Frequency::A440);
+    assert_eq!(Frequency::from_midi_note(57), Frequency::from_hz(220));
+    assert_eq!(Frequency::from_midi_note(0), Frequency::from_hz(8));
+    assert_eq!(Frequency::from_midi_note(127), Frequency::from_hz(12_544));
+
+    for note in 40..=127 {
+        let (back, cents) = Frequency::from_midi_note(note).to_midi_note();
+        assert_eq!(back, note);
+        assert!(cents.abs() < 50.0);
+    }
+
+    let (note, cents) = Frequency::from_hz(430).to_midi_note();
+    assert_eq!(note, 69);
+    assert!(cents < -39.0 && cents > -40.0);
+
+    // Out of range frequencies clamp to the nearest valid note
+    assert_eq!(Frequency::from_ghz(1).to_midi_note().0, 127);
+    assert_eq!(Frequency::ZERO.to_midi_note(), (0, f64::NEG_INFINITY));
+}
+
+#[test]
+fn test_tuning() {
+    use crate::{Temperament, Tuning};
+
+    assert_eq!(Tuning::default(), Tuning::A440);
+    assert_eq!(Tuning::A432.note_to_frequency(69), Frequency::from_hz(432));
+    assert_eq!(Tuning::A442.note_to_frequency(81), Frequency::from_hz(884));
+    assert_eq!(
+        Tuning::A432.frequency_to_note(Frequency::from_hz(432)),
+        (69, 0.0)
+    );
+
+    // In just intonation the reference pitch is preserved
+    for tonic in 0..12 {
+        let tuning = Tuning::A440.with_temperament(Temperament::Just { tonic });
+        assert!(
+            (tuning.note_to_hz(69) - 440.0).abs() < 1e-9,
+            "tonic {tonic}"
+        );
+        assert!(
+            (tuning.note_to_hz(57) - 220.0).abs() < 1e-9,
+            "tonic {tonic}"
+        );
+    }
+
+    // A major: C#5 is a pure major third above A4, E5 a pure fifth
+    let just_a = Tuning::A440.with_temperament(Temperament::Just { tonic: 9 });
+    assert!((just_a.note_to_hz(73) - 550.0).abs() < 1e-9);
+    assert!((just_a.note_to_hz(76) - 660.0).abs() < 1e-9);
+
+    let (note, cents) = just_a.frequency_to_note(Frequency::from_hz(660));
+    assert_eq!(note, 76);
+    assert!(cents.abs() < 1e-9);
+
+    // The same frequency is about 2 cents sharp in equal temperament
+    let (note, cents) = Tuning::A440.frequency_to_note(Frequency::from_hz(660));
+    assert_eq!(note, 76);
+    assert!((cents - 1.955).abs() < 0.001);
+}
+
+#[test]
+fn test_cents() {
+    use crate::Cents;
+
+    let a4 = Frequency::A440;
+    assert_eq!(a4.interval_to(a4), Cents::ZERO);
+    assert_eq!(a4.interval_to(Frequency::from_hz(880)), Cents::OCTAVE);
+    assert_eq!(Frequency::from_hz(880).interval_to(a4), Cents(-1200.0));
+
+    assert_eq!(a4 + Cents::OCTAVE, Frequency::from_hz(880));
+    assert_eq!(a4 - Cents::OCTAVE, Frequency::from_hz(220));
+    assert_eq!(a4 + Cents::SEMITONE, Frequency::from_hz(466));
+    assert_eq!(Cents(700.0) + Cents(500.0), Cents::OCTAVE);
+
+    assert_eq!("+14.3 ¢".parse::<Cents>().unwrap(), Cents(14.3));
+    assert_eq!("-5 cents".parse::<Cents>().unwrap(), Cents(-5.0));
+    assert_eq!("3ct".parse::<Cents>().unwrap(), Cents(3.0));
+    assert!("3 Hz".parse::<Cents>().is_err());
+    assert_eq!(Cents(-5.0).to_string(), "-5.00 ¢");
+}
+
+#[test]
+fn test_octave_bands() {
+    use crate::{BandFraction, FrequencyRange, OctaveBand};
+
+    let audible = FrequencyRange::new(Frequency::from_hz(20), Frequency::from_khz(20));
+    let thirds: Vec<f64> = BandFraction::ThirdOctave
+        .bands_in(audible)
+        .map(|band| band.nominal_hz())
+        .collect();
+    assert_eq!(
+        thirds,
+        [
+            25.0, 31.5, 40.0, 50.0, 63.0, 80.0, 100.0, 125.0, 160.0, 200.0, 250.0, 315.0, 400.0,
+            500.0, 630.0, 800.0, 1000.0, 1250.0, 1600.0, 2000.0, 2500.0, 3150.0, 4000.0, 5000.0,
+            6300.0, 8000.0, 10000.0, 12500.0, 16000.0, 20000.0
+        ]
+    );
+
+    // Every band contains its own center and adjacent bands share an edge
+    for band in BandFraction::ThirdOctave.bands_in(audible) {
+        assert!(band.contains(band.center()));
+        assert!((band.upper_edge_hz() - band.next().lower_edge_hz()).abs() < 1e-9);
+        assert_eq!(
+            BandFraction::ThirdOctave.band_for(band.center()),
+            Some(band)
+        );
+    }
+
+    let octave = OctaveBand::new(BandFraction::Octave, 0);
+    assert!((octave.lower_edge_hz() - 707.946).abs() < 0.001);
+    assert!((octave.upper_edge_hz() - 1412.538).abs() < 0.001);
+    assert_eq!(octave.previous().nominal_hz(), 500.0);
+    assert_eq!(octave.to_string(), "1000 Hz (octave)");
+
+    assert_eq!(BandFraction::Octave.band_for(Frequency::ZERO), None);
+    assert_eq!(
+        BandFraction::Octave
+            .bands_in(FrequencyRange::new(
+                Frequency::from_hz(10),
+                Frequency::from_hz(20)
+            ))
+            .map(|band| band.nominal_hz())
+            .collect::<Vec<_>>(),
+        [16.0]
+    );
+    assert_eq!(
+        BandFraction::Octave
+            .bands_in(FrequencyRange::new(Frequency::ZERO, Frequency::ZERO))
+            .count(),
+        0
+    );
+}
+
+#[test]
+fn test_rpm() {
+    use crate::{Error, Rounding, Rpm};
+
+    assert_eq!(Rpm::from(Frequency::from_hz(50)), Rpm(3000));
+    assert_eq!(Rpm(3000).to_frequency_exact(), Some(Frequency::from_hz(50)));
+    assert_eq!(Rpm(3001).to_frequency_exact(), None);
+
+    assert_eq!(Rpm(89).to_frequency(Rounding::Down), Frequency::from_hz(1));
+    assert_eq!(
+        Rpm(89).to_frequency(Rounding::Nearest),
+        Frequency::from_hz(1)
+    );
+    assert_eq!(
+        Rpm(90).to_frequency(Rounding::Nearest),
+        Frequency::from_hz(2)
+    );
+    assert_eq!(Rpm(61).to_frequency(Rounding::Up), Frequency::from_hz(2));
+    assert_eq!(Rpm(60).to_frequency(Rounding::Up), Frequency::from_hz(1));
+
+    assert_eq!(Rpm(1000) + Rpm(500), Rpm(1500));
+    assert_eq!(Rpm(1000) - Rpm(500), Rpm(500));
+    assert_eq!(Rpm(1000) * 3, Rpm(3000));
+    assert_eq!(Rpm(1000) / 4, Rpm(250));
+
+    assert_eq!("3000 rpm".parse::<Rpm>().unwrap(), Rpm(3000));
+    assert_eq!("1500RPM".parse::<Rpm>().unwrap(), Rpm(1500));
+    assert!("3000 Hz".parse::<Rpm>().is_err());
+    assert!("-5 rpm".parse::<Rpm>().is_err());
+    assert_eq!(
+        "1e30 rpm".parse::<Rpm>(),
+        Err(Error::InvalidValue("1e30 rpm".into()))
+    );
+    assert_eq!(
+        "-0 RPM".parse::<Rpm>(),
+        Err(Error::InvalidValue("-0 RPM".into()))
+    );
+    assert_eq!(
+        "18446744073709549568 rpm".parse::<Rpm>(),
+        Ok(Rpm(18_446_744_073_709_549_568))
+    );
+    assert_eq!(Rpm(3000).to_string(), "3000 rpm");
+}
+
+#[test]
+fn test_bpm() {
+    use crate::{Bpm, Error, Rounding};
+    use std::time::{Duration, Instant};
+
+    let tempo = "128.5 BPM".parse::<Bpm>().unwrap();
+    assert_eq!(tempo.as_millibpm(), 128_500);
+    assert_eq!(tempo.as_bpm(), 128.5);
+    assert_eq!(tempo.to_string(), "128.5 BPM");
+    assert_eq!(tempo.to_string().parse::<Bpm>().unwrap(), tempo);
+    assert_eq!(Bpm::from_millibpm(120_125).to_string(), "120.125 BPM");
+    assert_eq!(Bpm::from_bpm(90.0).to_string(), "90 BPM");
+    assert!("120 Hz".parse::<Bpm>().is_err());
+    assert!("-120 bpm".parse::<Bpm>().is_err());
+    assert_eq!(
+        "1e30 bpm".parse::<Bpm>(),
+        Err(Error::InvalidValue("1e30 bpm".into()))
+    );
+    assert!("18446744073709552 bpm".parse::<Bpm>().is_err());
+    assert_eq!(Bpm::checked_from_bpm(1e30), None);
+    assert_eq!(Bpm::checked_from_bpm(f64::NAN), None);
+    assert_eq!(Bpm::from_bpm(1e30), Bpm::from_millibpm(u64::MAX));
+    assert_eq!(
+        "18446744073709 bpm".parse::<Bpm>(),
+        Ok(Bpm::from_millibpm(18_446_744_073_709_000))
+    );
+
+    assert_eq!(Bpm::from(Frequency::from_hz(3)), Bpm::from_bpm(180.0));
+    assert_eq!(
+        Bpm::checked_from_frequency(Frequency::from_hz(3)),
+        Some(Bpm::from_bpm(180.0))
+    );
+    assert_eq!(Bpm::checked_from_frequency(Frequency(u64::MAX)), None);
+    assert_eq!(tempo.to_frequency(Rounding::Down), Frequency::from_hz(2));
+    assert_eq!(tempo.to_frequency(Rounding::Up), Frequency::from_hz(3));
+
+    assert_eq!(
+        Bpm::from_bpm(120.0).beat_duration(),
+        Duration::from_millis(500)
+    );
+    assert_eq!(Bpm::ZERO.beat_duration(), Duration::ZERO);
+    assert_eq!(Bpm::from_beat_interval(Duration::ZERO), None);
+
+    let start = Instant::now();
+    let taps = [0, 470, 940, 1410].map(|ms| start + Duration::from_millis(ms));
+    let tapped = Bpm::from_taps(taps).unwrap();
+    assert_eq!(tapped.as_millibpm(), 127_660);
+    assert_eq!(Bpm::from_taps([start]), None);
+    assert_eq!(Bpm::from_taps([start, start]), None);
+}
+
+#[test]
+fn test_uart_divisor() {
+    use crate::BaudRate;
+
+    // 1.8432 MHz crystals divide evenly into the standard rates
+    let clock = Frequency::from_hz(1_843_200);
+    for baud in [BaudRate::B9600, BaudRate::B57600, BaudRate::B115200] {
+        let setting = baud.uart_divisor(clock, 16).unwrap();
+        assert_eq!(setting.actual, baud);
+        assert_eq!(setting.error_ppm, 0.0);
+    }
+
+    let setting = BaudRate::B115200
+        .uart_divisor(Frequency::from_mhz(48), 16)
+        .unwrap();
+    assert_eq!(setting.divisor, 26);
+    assert_eq!(setting.actual, BaudRate(115_385));
+    assert!((setting.error_ppm - 1_602.56).abs() < 0.01);
+
+    // The divisor never drops below 1
+    let setting = BaudRate::B921600
+        .uart_divisor(Frequency::from_mhz(1), 16)
+        .unwrap();
+    assert_eq!(setting.divisor, 1);
+
+    assert!(BaudRate(0).uart_divisor(clock, 16).is_none());
+    assert!(BaudRate::B9600.uart_divisor(Frequency::ZERO, 16).is_none());
+    assert!(BaudRate::B9600.uart_divisor(clock, 0).is_none());
+
+    assert_eq!("9600 bd".parse::<BaudRate>().unwrap(), BaudRate::B9600);
+    assert_eq!(
+        "115200 baud".parse::<BaudRate>().unwrap(),
+        BaudRate::B115200
+    );
+    assert!("115200".parse::<BaudRate>().is_err());
+    assert_eq!(BaudRate::B9600.to_string(), "9600 baud");
+}
+
+#[test]
+fn test_best_divider() {
+    let source = Frequency::from_mhz(100);
+
+    let exact = Frequency::best_divider(source, Frequency::from_mhz(25), 16).unwrap();
+    assert_eq!(exact.divider, 4);
+    assert_eq!(exact.output, Frequency::from_mhz(25));
+    assert_eq!(exact.error_ppm, 0.0);
+
+    // 100 / 3 = 33.3 MHz is closer to 30 MHz than 100 / 4 = 25 MHz
+    let closest = Frequency::best_divider(source, Frequency::from_mhz(30), 16).unwrap();
+    assert_eq!(closest.divider, 3);
+    assert_eq!(closest.output, Frequency::from_hz(33_333_333));
+
+    // Limited by the maximum divider
+    let limited = Frequency::best_divider(source, Frequency::from_mhz(1), 16).unwrap();
+    assert_eq!(limited.divider, 16);
+    assert_eq!(limited.output, Frequency::from_hz(6_250_000));
+
+    // Targets above the source use a divider of 1
+    let above = Frequency::best_divider(source, Frequency::from_ghz(1), 16).unwrap();
+    assert_eq!(above.divider, 1);
+    assert!((above.error_ppm - -900_000.0).abs() < f64::EPSILON);
+
+    assert!(Frequency::best_divider(source, Frequency::ZERO, 16).is_none());
+    assert!(Frequency::best_divider(Frequency::ZERO, source, 16).is_none());
+    assert!(Frequency::best_divider(source, source, 0).is_none());
+}
+
+#[test]
+fn test_fractional_pll() {
+    use crate::{FrequencyRange, PllConstraints};
+
+    // ADF4351-like: 10 MHz reference, 12-bit modulus, 2.2-4.4 GHz VCO, no output divider
+    let adf = PllConstraints::new(
+        23..=65535,
+        12,
+        FrequencyRange::new(Frequency::from_mhz(2200), Frequency::from_mhz(4400)),
+    );
+
+    let settings = adf
+        .solve(Frequency::from_mhz(10), Frequency::from_hz(2_400_100_000))
+        .unwrap();
+    assert_eq!((settings.n, settings.frac, settings.den), (240, 1, 100));
+    assert_eq!(settings.output, Frequency::from_hz(2_400_100_000));
+    assert_eq!(settings.error_ppm, 0.0);
+
+    // Not representable with a 12-bit denominator, so the closest fraction is used
+    let settings = adf
+        .solve(Frequency::from_mhz(10), Frequency::from_hz(2_400_000_001))
+        .unwrap();
+    assert_eq!(settings.n, 240);
+    assert!(settings.den <= 4095);
+    assert!(settings.error_ppm.abs() < 0.001);
+
+    // Integer-N only
+    let integer = PllConstraints::new(
+        1..=100,
+        0,
+        FrequencyRange::new(Frequency::from_mhz(100), Frequency::from_mhz(1000)),
+    );
+    let settings = integer
+        .solve(Frequency::from_mhz(10), Frequency::from_mhz(254))
+        .unwrap();
+    assert_eq!((settings.n, settings.frac, settings.den), (25, 0, 1));
+    assert_eq!(settings.output, Frequency::from_mhz(250));
+
+    // Output dividers bring low targets into reach of the VCO
+    let divided = integer.clone().with_output_divider(1..=64);
+    let settings = divided
+        .solve(Frequency::from_mhz(10), Frequency::from_mhz(5))
+        .unwrap();
+    assert_eq!(settings.output, Frequency::from_mhz(5));
+    assert_eq!(settings.output_divider, 20);
+    assert_eq!(settings.vco, Frequency::from_mhz(100));
+
+    assert!(
+        integer
+            .solve(Frequency::from_mhz(10), Frequency::from_mhz(5))
+            .is_none()
+    );
+    assert!(
+        integer
+            .solve(Frequency::ZERO, Frequency::from_mhz(5))
+            .is_none()
+    );
+}
+
+#[test]
+fn test_clock_chain() {
+    use crate::{ClockChain, ClockStage, FrequencyRange};
+
+    let chain = ClockChain::new()
+        .stage(ClockStage::multiplier("PLL", [2, 3, 4]).with_max_output(Frequency::from_mhz(100)))
+        .stage(ClockStage::divider("AHB", [1, 2, 4]))
+        .stage(
+            ClockStage::divider("APB", [1, 2]).with_output_range(FrequencyRange::new(
+                Frequency::from_mhz(10),
+                Frequency::from_mhz(50),
+            )),
+        );
+
+    let source = Frequency::from_mhz(25);
+    assert_eq!(
+        chain.output(source, &[4, 1, 2]),
+        Some(Frequency::from_mhz(50))
+    );
+    assert_eq!(chain.output(source, &[4, 1, 1]), None); // APB out of range
+    assert_eq!(chain.output(source, &[5, 1, 2]), None); // Not a valid PLL setting
+    assert_eq!(chain.output(source, &[4, 1]), None); // Missing a stage
+
+    let plans = chain.achievable_outputs(source);
+    assert!(
+        plans
+            .iter()
+            .all(|plan| plan.output >= Frequency::from_mhz(10))
+    );
+    assert!(
+        plans
+            .iter()
+            .all(|plan| plan.output <= Frequency::from_mhz(50))
+    );
+    assert!(!plans.iter().any(|plan| plan.settings[0] == 5));
+
+    let plan = chain.solve(source, Frequency::from_mhz(19)).unwrap();
+    assert_eq!(plan.settings, [3, 2, 2]); // [3, 4, 1] is equally close, but found later
+    assert_eq!(plan.output, Frequency::from_hz(18_750_000));
+    assert_eq!(
+        plan.stage_outputs,
+        [
+            Frequency::from_mhz(75),
+            Frequency::from_hz(37_500_000),
+            Frequency::from_hz(18_750_000)
+        ]
+    );
+    assert!((plan.error_ppm - -13_157.89).abs() < 0.01);
+
+    // Exact fractions are kept through the chain
+    let odd = ClockChain::new()
+        .stage(ClockStage::divider("A", [3]))
+        .stage(ClockStage::multiplier("B", [3]));
+    assert_eq!(
+        odd.output(Frequency::from_hz(10), &[3, 3]),
+        Some(Frequency::from_hz(10))
+    );
+
+    assert!(ClockChain::new().solve(source, source).is_some());
+}
+
+#[test]
+fn test_oscillator() {
+    use crate::{Oscillator, Ppm, Tempco};
+    use std::time::Duration;
+
+    let ideal = Oscillator::new(Frequency::from_mhz(10));
+    assert_eq!(ideal.worst_case(10.0, -40.0..=85.0), Ppm::ZERO);
+    assert_eq!(
+        ideal.timing_error(Duration::from_secs(3600), 10.0, -40.0..=85.0),
+        Duration::ZERO
+    );
+
+    let crystal = Oscillator {
+        tolerance: Ppm(10.0),
+        aging_per_year: Ppm(2.0),
+        tempco: Tempco::Linear {
+            ppm_per_celsius: 0.5,
+        },
+        ..ideal
+    };
+    // 10 + 2 * 3 + 0.5 * 65
+    assert_eq!(crystal.worst_case(3.0, -40.0..=85.0), Ppm(48.5));
+    let window = crystal.window(3.0, -40.0..=85.0);
+    assert_eq!(window.min, Frequency::from_hz(9_999_515));
+    assert_eq!(window.max, Frequency::from_hz(10_000_485));
+
+    let tcxo = Oscillator {
+        tempco: Tempco::Bounded(Ppm(0.5)),
+        ..crystal
+    };
+    assert_eq!(tcxo.worst_case(0.0, -40.0..=85.0), Ppm(10.5));
+
+    // A parabola with its turnover inside the range but away from the reference
+    let shifted = Oscillator {
+        tolerance: Ppm::ZERO,
+        aging_per_year: Ppm::ZERO,
+        tempco: Tempco::Parabolic {
+            ppm_per_celsius_squared: -0.04,
+            turnover_celsius: 30.0,
+        },
+        ..ideal
+    };
+    // At 30 °C the frequency is 0.04 * 25 = 1 ppm above the 25 °C value
+    assert!((shifted.worst_case(0.0, 25.0..=30.0).0 - 1.0).abs() < 1e-9);
+
+    assert_eq!(
+        crystal.timing_error(Duration::from_secs(1_000_000), 3.0, -40.0..=85.0),
+        Duration::from_millis(48_500)
+    );
+}
+
+#[test]
+fn test_pwm_timing() {
+    use crate::{DutyCycle, PwmTiming};
+    use std::time::Duration;
+
+    assert_eq!(DutyCycle::from_ratio(2, 8), DutyCycle::from_ratio(1, 4));
+    assert_eq!(DutyCycle::from_ratio(1, 4).unwrap().as_ratio(), (1, 4));
+    assert_eq!(DutyCycle::from_ratio(5, 4), None);
+    assert_eq!(DutyCycle::from_ratio(0, 0), None);
+    assert_eq!(DutyCycle::from_percent(150.0), DutyCycle::FULL);
+    assert_eq!(DutyCycle::from_percent(-1.0), DutyCycle::ZERO);
+    assert_eq!(DutyCycle::from_percent(50.0), DutyCycle::HALF);
+    assert_eq!(DutyCycle::from_percent(33.3).as_percent(), 33.3);
+
+    // 3 kHz has a period of 333 333.3 ns, which does not divide evenly
+    let pwm = PwmTiming::new(Frequency::from_khz(3), DutyCycle::from_ratio(1, 3).unwrap()).unwrap();
+    assert_eq!(pwm.on_time, Duration::from_nanos(111_111));
+    assert_eq!(pwm.off_time, Duration::from_nanos(222_222));
+    assert_eq!(pwm.period(), Duration::from_nanos(333_333));
+
+    let pwm = PwmTiming::new(Frequency::from_hz(50), DutyCycle::FULL).unwrap();
+    assert_eq!(pwm.on_time, Duration::from_millis(20));
+    assert_eq!(pwm.off_time, Duration::ZERO);
+
+    assert_eq!(PwmTiming::new(Frequency::ZERO, DutyCycle::HALF), None);
+
+    let pwm =
+        PwmTiming::from_durations(Duration::from_micros(1), Duration::from_micros(3)).unwrap();
+    assert_eq!(pwm.carrier, Frequency::from_khz(250));
+    assert_eq!(pwm.duty_cycle(), DutyCycle::from_ratio(1, 4).unwrap());
+    assert_eq!(
+        PwmTiming::from_durations(Duration::ZERO, Duration::ZERO),
+        None
+    );
+}
+
+#[test]
+fn test_timer_config() {
+    use crate::timer_config;
+
+    // Fits in the counter without prescaling
+    let config = timer_config(Frequency::from_mhz(1), Frequency::from_hz(100), 16).unwrap();
+    assert_eq!((config.prescaler, config.reload), (0, 9_999));
+    assert_eq!(config.actual, Frequency::from_hz(100));
+
+    // Needs a prescaler to fit in 16 bits
+    let config = timer_config(Frequency::from_mhz(84), Frequency::from_hz(50), 16).unwrap();
+    let ticks = u64::from(config.prescaler + 1) * u64::from(config.reload + 1);
+    assert_eq!(ticks, 1_680_000);
+    assert!(config.reload <= 0xFFFF);
+    assert_eq!(config.error_ppm, 0.0);
+
+    // A 32-bit counter needs no prescaler
+    let config = timer_config(Frequency::from_mhz(84), Frequency::from_hz(50), 32).unwrap();
+    assert_eq!((config.prescaler, config.reload), (0, 1_679_999));
+
+    // Prime tick counts cannot be split, so the closest pair is used
+    let config = timer_config(Frequency::from_hz(1_000_003), Frequency::from_hz(1), 16).unwrap();
+    assert!(config.error_ppm.abs() < 1.0);
+
+    // Targets above the clock use the smallest dividers
+    let config = timer_config(Frequency::from_mhz(1), Frequency::from_mhz(2), 16).unwrap();
+    assert_eq!((config.prescaler, config.reload), (0, 0));
+
+    assert!(timer_config(Frequency::ZERO, Frequency::from_hz(1), 16).is_none());
+    assert!(timer_config(Frequency::from_mhz(1), Frequency::ZERO, 16).is_none());
+    assert!(timer_config(Frequency::from_mhz(1), Frequency::from_hz(1), 0).is_none());
+}
+
+#[test]
+fn test_wifi_channels() {
+    use crate::{ChannelWidth, WifiBand, WifiChannel};
+
+    let center = |band, number| WifiChannel::new(band, number).unwrap().center();
+    assert_eq!(center(WifiBand::Ghz2_4, 1), Frequency::from_mhz(2_412));
+    assert_eq!(center(WifiBand::Ghz2_4, 13), Frequency::from_mhz(2_472));
+    assert_eq!(center(WifiBand::Ghz2_4, 14), Frequency::from_mhz(2_484));
+    assert_eq!(center(WifiBand::Ghz5, 149), Frequency::from_mhz(5_745));
+    assert_eq!(center(WifiBand::Ghz6, 1), Frequency::from_mhz(5_955));
+    assert_eq!(center(WifiBand::Ghz6, 2), Frequency::from_mhz(5_935));
+
+    assert!(WifiChannel::new(WifiBand::Ghz2_4, 15).is_none());
+    assert!(WifiChannel::new(WifiBand::Ghz5, 38).is_none());
+    assert!(WifiChannel::new(WifiBand::Ghz6, 3).is_none());
+
+    for band in [WifiBand::Ghz2_4, WifiBand::Ghz5, WifiBand::Ghz6] {
+        for channel in band.channels() {
+            assert_eq!(WifiChannel::from_frequency(channel.center()), Some(channel));
+        }
+    }
+    assert!(WifiChannel::from_frequency(Frequency::from_mhz(2_413)).is_none());
+    assert!(WifiChannel::from_frequency(Frequency::from_mhz(5_500) + Frequency::HERTZ).is_none());
+
+    let range = |band, number, width| {
+        WifiChannel::new(band, number)
+            .unwrap()
+            .frequency_range(width)
+            .map(|range| (range.min.as_mhz(), range.max.as_mhz()))
+    };
+    assert_eq!(
+        range(WifiBand::Ghz2_4, 6, ChannelWidth::Mhz20),
+        Some((2_427, 2_447))
+    );
+    assert_eq!(range(WifiBand::Ghz2_4, 6, ChannelWidth::Mhz40), None);
+    assert_eq!(
+        range(WifiBand::Ghz5, 44, ChannelWidth::Mhz40),
+        Some((5_210, 5_250))
+    );
+    assert_eq!(
+        range(WifiBand::Ghz5, 64, ChannelWidth::Mhz160),
+        Some((5_170, 5_330))
+    );
+    assert_eq!(
+        range(WifiBand::Ghz5, 157, ChannelWidth::Mhz80),
+        Some((5_735, 5_815))
+    );
+    assert_eq!(range(WifiBand::Ghz5, 144, ChannelWidth::Mhz160), None);
+    assert_eq!(range(WifiBand::Ghz5, 36, ChannelWidth::Mhz320), None);
+    assert_eq!(
+        range(WifiBand::Ghz6, 37, ChannelWidth::Mhz320),
+        Some((5_945, 6_265))
+    );
+    assert_eq!(range(WifiBand::Ghz6, 233, ChannelWidth::Mhz40), None);
+
+    assert_eq!(
+        WifiChannel::new(WifiBand::Ghz5, 36).unwrap().to_string(),
+        "channel 36 (5 GHz)"
+    );
+}
+
+#[test]
+fn test_cellular_channels() {
+    use crate::{
+        Link, earfcn_to_frequency, frequency_to_earfcn, frequency_to_nr_arfcn, lte_band_range,
+        nr_arfcn_to_frequency,
+    };
+
+    // Band edges and TDD bands
+    assert_eq!(earfcn_to_frequency(1, 0), Some(Frequency::from_mhz(2_110)));
+    assert_eq!(
+        earfcn_to_frequency(1, 18_599),
+        Some(Frequency::from_khz(1_979_900))
+    );
+    assert_eq!(
+        earfcn_to_frequency(41, 40_620),
+        Some(Frequency::from_khz(2_593_000))
+    );
+    assert_eq!(
+        earfcn_to_frequency(66, 132_072),
+        Some(Frequency::from_mhz(1_720))
+    );
+    assert_eq!(earfcn_to_frequency(1, 600), None);
+    assert_eq!(earfcn_to_frequency(6, 2_700), None);
+
+    assert_eq!(
+        frequency_to_earfcn(20, Link::Downlink, Frequency::from_mhz(806)),
+        Some(6_300)
+    );
+    assert_eq!(
+        frequency_to_earfcn(38, Link::Uplink, Frequency::from_mhz(2_595)),
+        Some(38_000)
+    );
+    assert_eq!(
+        frequency_to_earfcn(20, Link::Downlink, Frequency::from_khz(806_050)),
+        None
+    );
+    assert_eq!(
+        frequency_to_earfcn(20, Link::Downlink, Frequency::from_mhz(821)),
+        None
+    );
+
+    let band = lte_band_range(1, Link::Uplink).unwrap();
+    assert_eq!((band.min.as_mhz(), band.max.as_mhz()), (1_920, 1_980));
+    assert!(lte_band_range(99, Link::Uplink).is_none());
+
+    // One sample per raster segment, including the edges
+    for arfcn in [0, 599_999, 600_000, 2_016_666, 2_016_667, 3_279_165] {
+        let freq = nr_arfcn_to_frequency(arfcn).unwrap();
+        assert_eq!(frequency_to_nr_arfcn(freq), Some(arfcn));
+    }
+    assert_eq!(
+        nr_arfcn_to_frequency(428_000),
+        Some(Frequency::from_mhz(2_140))
+    );
+    assert_eq!(
+        nr_arfcn_to_frequency(2_016_667),
+        Some(Frequency::from_khz(24_250_080))
+    );
+    assert_eq!(nr_arfcn_to_frequency(3_279_166), None);
+    assert_eq!(frequency_to_nr_arfcn(Frequency::from_khz(3_000_005)), None);
+    assert_eq!(frequency_to_nr_arfcn(Frequency::from_khz(24_250_050)), None);
+}
+
+#[test]
+fn test_gsm_arfcn() {
+    use crate::{GsmBand, arfcn_to_downlink, arfcn_to_uplink};
+
+    let uplink = |band, arfcn| arfcn_to_uplink(band, arfcn).map(|freq| freq.as_khz());
+    let downlink = |band, arfcn| arfcn_to_downlink(band, arfcn).map(|freq| freq.as_khz());
+
+    assert_eq!(uplink(GsmBand::Gsm450, 259), Some(450_600));
+    assert_eq!(uplink(GsmBand::Gsm480, 340), Some(485_800));
+    assert_eq!(uplink(GsmBand::Gsm850, 128), Some(824_200));
+    assert_eq!(downlink(GsmBand::Gsm850, 251), Some(893_800));
+    assert_eq!(uplink(GsmBand::PGsm900, 1), Some(890_200));
+    assert_eq!(uplink(GsmBand::PGsm900, 0), None);
+    assert_eq!(uplink(GsmBand::EGsm900, 0), Some(890_000));
+    assert_eq!(uplink(GsmBand::EGsm900, 975), Some(880_200));
+    assert_eq!(downlink(GsmBand::EGsm900, 1023), Some(934_800));
+    assert_eq!(uplink(GsmBand::EGsm900, 955), None);
+    assert_eq!(uplink(GsmBand::RGsm900, 955), Some(876_200));
+    assert_eq!(downlink(GsmBand::Dcs1800, 885), Some(1_879_800));
+    assert_eq!(downlink(GsmBand::Pcs1900, 810), Some(1_989_800));
+    assert_eq!(uplink(GsmBand::Pcs1900, 811), None);
+
+    assert_eq!(GsmBand::EGsm900.to_string(), "E-GSM 900");
+}
+
+#[test]
+fn test_itu_bands() {
+    use crate::{ItuBand, ItuRegion};
+
+    assert_eq!(Frequency::from_hz(3).itu_band(), None);
+    assert_eq!(Frequency::from_hz(4).itu_band(), Some(ItuBand::Elf));
+    assert_eq!(Frequency::from_khz(20).itu_band(), Some(ItuBand::Vlf));
+    assert_eq!(Frequency::from_khz(1_000).itu_band(), Some(ItuBand::Mf));
+    assert_eq!(Frequency::from_mhz(3).itu_band(), Some(ItuBand::Mf));
+    assert_eq!(Frequency::from_mhz(2_400).itu_band(), Some(ItuBand::Uhf));
+    assert_eq!(Frequency::from_ghz(60).itu_band(), Some(ItuBand::Ehf));
+    assert_eq!(Frequency::from_ghz(3_000).itu_band(), Some(ItuBand::Thf));
+    assert_eq!(Frequency::from_ghz(3_001).itu_band(), None);
+    assert_eq!(ItuBand::Vhf.number(), 8);
+    assert_eq!(ItuBand::Shf.to_string(), "SHF");
+
+    let name = |freq: Frequency, region| freq.ham_band(region).map(|band| band.name);
+    assert_eq!(
+        name(Frequency::from_hz(136_000), ItuRegion::Region1),
+        Some("2200m")
+    );
+    assert_eq!(name(Frequency::from_khz(1_805), ItuRegion::Region1), None);
+    assert_eq!(
+        name(Frequency::from_khz(1_805), ItuRegion::Region2),
+        Some("160m")
+    );
+    assert_eq!(
+        name(Frequency::from_khz(3_850), ItuRegion::Region3),
+        Some("80m")
+    );
+    assert_eq!(
+        name(Frequency::from_khz(5_357), ItuRegion::Region2),
+        Some("60m")
+    );
+    assert_eq!(name(Frequency::from_mhz(147), ItuRegion::Region1), None);
+    assert_eq!(
+        name(Frequency::from_mhz(147), ItuRegion::Region2),
+        Some("2m")
+    );
+    assert_eq!(
+        name(Frequency::from_mhz(223), ItuRegion::Region2),
+        Some("1.25m")
+    );
+    assert_eq!(
+        name(Frequency::from_mhz(915), ItuRegion::Region2),
+        Some("33cm")
+    );
+    assert_eq!(name(Frequency::from_mhz(915), ItuRegion::Region3), None);
+    assert_eq!(name(Frequency::from_mhz(100), ItuRegion::Region1), None);
+
+    for region in [ItuRegion::Region1, ItuRegion::Region2, ItuRegion::Region3] {
+        let bands = region.ham_bands();
+        assert!(
+            bands
+                .windows(2)
+                .all(|pair| pair[0].range.max < pair[1].range.min)
+        );
+    }
+}
+
+#[test]
+fn test_broadcast_channels() {
+    use crate::ItuRegion;
+
+    let khz = Frequency::from_khz;
+
+    assert!(khz(87_500).is_valid_fm_channel(ItuRegion::Region1));
+    assert!(khz(108_000).is_valid_fm_channel(ItuRegion::Region3));
+    assert!(!khz(108_100).is_valid_fm_channel(ItuRegion::Region1));
+    assert!(!khz(87_550).is_valid_fm_channel(ItuRegion::Region1));
+    assert!(khz(88_100).is_valid_fm_channel(ItuRegion::Region2));
+    assert!(!khz(88_000).is_valid_fm_channel(ItuRegion::Region2));
+    assert!(!khz(108_000).is_valid_fm_channel(ItuRegion::Region2));
+
+    assert_eq!(
+        khz(99_949).nearest_fm_channel(ItuRegion::Region1),
+        khz(99_900)
+    );
+    assert_eq!(
+        khz(99_950).nearest_fm_channel(ItuRegion::Region1),
+        khz(100_000)
+    );
+    assert_eq!(
+        khz(98_199).nearest_fm_channel(ItuRegion::Region2),
+        khz(98_100)
+    );
+    assert_eq!(
+        khz(80_000).nearest_fm_channel(ItuRegion::Region2),
+        khz(88_100)
+    );
+    assert_eq!(
+        khz(108_000).nearest_fm_channel(ItuRegion::Region2),
+        khz(107_900)
+    );
+
+    assert!(khz(1_008).is_valid_am_channel(ItuRegion::Region1));
+    assert!(!khz(1_010).is_valid_am_channel(ItuRegion::Region1));
+    assert!(khz(1_010).is_valid_am_channel(ItuRegion::Region2));
+    assert!(khz(1_700).is_valid_am_channel(ItuRegion::Region2));
+    assert!(!khz(1_611).is_valid_am_channel(ItuRegion::Region3));
+
+    assert_eq!(
+        khz(1_013).nearest_am_channel(ItuRegion::Region1),
+        khz(1_017)
+    );
+    assert_eq!(
+        khz(1_014).nearest_am_channel(ItuRegion::Region2),
+        khz(1_010)
+    );
+    assert_eq!(
+        khz(2_000).nearest_am_channel(ItuRegion::Region1),
+        khz(1_602)
+    );
+    assert_eq!(
+        Frequency::ZERO.nearest_am_channel(ItuRegion::Region2),
+        khz(530)
+    );
+}
+
+#[test]
+fn test_tv_channels() {
+    use crate::TvChannelPlan;
+
+    let mhz = |plan: TvChannelPlan, number| {
+        plan.channel(number)
+            .map(|range| (range.min.as_mhz(), range.max.as_mhz()))
+    };
+    assert_eq!(mhz(TvChannelPlan::Atsc, 2), Some((54, 60)));
+    assert_eq!(mhz(TvChannelPlan::Atsc, 5), Some((76, 82)));
+    assert_eq!(mhz(TvChannelPlan::Atsc, 13), Some((210, 216)));
+    assert_eq!(mhz(TvChannelPlan::Atsc, 36), Some((602, 608)));
+    assert_eq!(mhz(TvChannelPlan::Atsc, 1), None);
+    assert_eq!(mhz(TvChannelPlan::Atsc, 37), None);
+    assert_eq!(mhz(TvChannelPlan::Europe, 5), Some((174, 181)));
+    assert_eq!(mhz(TvChannelPlan::Europe, 21), Some((470, 478)));
+    assert_eq!(mhz(TvChannelPlan::Europe, 69), Some((854, 862)));
+    assert_eq!(mhz(TvChannelPlan::Europe, 20), None);
+
+    assert_eq!(
+        TvChannelPlan::Atsc.channel_for(Frequency::from_mhz(74)),
+        None
+    );
+    assert_eq!(
+        TvChannelPlan::Atsc.channel_for(Frequency::from_mhz(60)),
+        Some(3)
+    );
+    assert_eq!(
+        TvChannelPlan::Europe.channel_for(Frequency::from_mhz(474)),
+        Some(21)
+    );
+    assert_eq!(
+        TvChannelPlan::Europe.channel_for(Frequency::from_mhz(862)),
+        None
+    );
+
+    assert_eq!(TvChannelPlan::Atsc.channels().count(), 35);
+    assert_eq!(TvChannelPlan::Europe.channels().count(), 57);
+    for plan in [TvChannelPlan::Atsc, TvChannelPlan::Europe] {
+        for (number, range) in plan.channels() {
+            assert_eq!(plan.channel_for(range.center()), Some(number));
+        }
+    }
+}
+
+#[test]
+fn test_ism_bands() {
+    use crate::{FrequencyRange, ISM_BANDS, ItuRegion};
+
+    let name = |freq: Frequency| freq.ism_band().map(|band| band.name);
+    assert_eq!(name(Frequency::from_khz(6_780)), Some("6.78 MHz"));
+    assert_eq!(name(Frequency::from_khz(27_283)), Some("27.12 MHz"));
+    assert_eq!(name(Frequency::from_khz(433_920)), Some("433 MHz"));
+    assert_eq!(name(Frequency::from_mhz(2_450)), Some("2.4 GHz"));
+    assert_eq!(name(Frequency::from_mhz(5_800)), Some("5.8 GHz"));
+    assert_eq!(name(Frequency::from_ghz(245)), Some("245 GHz"));
+    assert_eq!(name(Frequency::from_mhz(2_501)), None);
+
+    let freq = Frequency::from_khz(433_920);
+    assert!(freq.ism_band_in(ItuRegion::Region1).is_some());
+    assert!(freq.ism_band_in(ItuRegion::Region2).is_none());
+    assert!(
+        Frequency::from_mhz(2_450)
+            .ism_band_in(ItuRegion::Region3)
+            .is_some()
+    );
+
+    let range = |min, max| FrequencyRange::new(Frequency::from_khz(min), Frequency::from_khz(max));
+    assert!(range(902_000, 928_000).fits_within_ism());
+    assert!(!range(927_000, 929_000).fits_within_ism());
+    assert!(!range(2_400_000, 5_800_000).fits_within_ism());
+
+    assert!(
+        ISM_BANDS
+            .windows(2)
+            .all(|pair| pair[0].range.max < pair[1].range.min)
+    );
+}
+
+#[test]
+fn test_doppler_shift() {
+    use crate::{FrequencyShift, SPEED_OF_LIGHT, SPEED_OF_SOUND};
+
+    let freq = Frequency::from_mhz(100);
+    assert_eq!(freq.doppler_shift(0.0), FrequencyShift::ZERO);
+    assert!((freq.doppler_shift(SPEED_OF_LIGHT / 1_000.0).0 - 100_000.0).abs() < 1e-6);
+    assert_eq!(freq.doppler_shift(-1_000.0), -freq.doppler_shift(1_000.0));
+
+    // A 1 kHz siren approaching at a tenth of the speed of sound
+    let siren = Frequency::from_khz(1).doppler_shift_in(SPEED_OF_SOUND / 10.0, SPEED_OF_SOUND);
+    assert!((siren.0 - 100.0).abs() < 1e-9);
+
+    // The relativistic shift is larger when approaching and smaller when receding
+    let approaching = freq.doppler_shift_relativistic(SPEED_OF_LIGHT / 10.0);
+    let receding = freq.doppler_shift_relativistic(-SPEED_OF_LIGHT / 10.0);
+    assert!((approaching.0 - 10_554_159.7).abs() < 0.1);
+    assert!((receding.0 - -9_546_596.6).abs() < 0.1);
+    assert!(
+        !freq
+            .doppler_shift_relativistic(SPEED_OF_LIGHT)
+            .0
+            .is_finite()
+    );
+
+    assert_eq!(
+        freq.observed_frequency(SPEED_OF_LIGHT / 1_000.0),
+        Frequency::from_khz(100_100)
+    );
+    assert_eq!(
+        Frequency::from_hz(10) + FrequencyShift(-20.0),
+        Frequency::ZERO
+    );
+    assert_eq!(
+        Frequency::from_hz(10) + FrequencyShift(f64::NAN),
+        Frequency::ZERO
+    );
+    assert_eq!(FrequencyShift(12.5).to_string(), "+12.50 Hz");
+}
+
+#[test]
+fn test_intermodulation_products() {
+    use crate::{intermodulation_products, mixer_products};
+
+    assert_eq!(
+        mixer_products(Frequency::from_hz(5), Frequency::from_hz(8)),
+        (Frequency::from_hz(13), Frequency::from_hz(3))
+    );
+    assert_eq!(
+        mixer_products(Frequency(u64::MAX), Frequency::HERTZ).0,
+        Frequency(u64::MAX)
+    );
+
+    let products: Vec<_> =
+        intermodulation_products(Frequency::from_hz(10), Frequency::from_hz(11), 2)
+            .map(|product| {
+                (
+                    product.to_string(),
+                    product.order,
+                    product.frequency.as_hz(),
+                )
+            })
+            .collect();
+    assert_eq!(
+        products,
+        [
+            ("f1".to_string(), 1, 10),
+            ("f2".to_string(), 1, 11),
+            ("2f1".to_string(), 2, 20),
+            ("f1 + f2".to_string(), 2, 21),
+            ("f1 - f2".to_string(), 2, 1),
+            ("2f2".to_string(), 2, 22),
+        ]
+    );
+
+    // Each order n contributes 2n products
+    let count = intermodulation_products(Frequency::from_hz(10), Frequency::from_hz(11), 5).count();
+    assert_eq!(count, 2 + 4 + 6 + 8 + 10);
+
+    let fifth = intermodulation_products(Frequency::from_mhz(100), Frequency::from_mhz(101), 5)
+        .find(|product| product.f1_coefficient == 3 && product.f2_coefficient == -2)
+        .unwrap();
+    assert_eq!(fifth.to_string(), "3f1 - 2f2");
+    assert_eq!(fifth.frequency, Frequency::from_mhz(98));
+
+    assert_eq!(
+        intermodulation_products(Frequency::from_hz(1), Frequency::from_hz(2), 0).count(),
+        0
+    );
+}
+
+#[test]
+fn test_rate_estimator() {
+    use crate::RateEstimator;
+    use std::time::{Duration, Instant};
+
+    let mut estimator = RateEstimator::sliding_window(Duration::from_secs(1));
+    assert_eq!(estimator.rate(), None);
+
+    let start = Instant::now();
+    estimator.record(start);
+    assert_eq!(estimator.rate(), None);
+    estimator.record(start);
+    assert_eq!(estimator.rate(), None);
+
+    for i in 1..=20 {
+        estimator.record(start + Duration::from_millis(100) * i);
+    }
+    assert_eq!(estimator.rate(), Some(Frequency::from_hz(10)));
+
+    // The rate speeds up; once the old events leave the window only the new rate remains
+    for _ in 0..100 {
+        estimator.record_interval(Duration::from_millis(20));
+    }
+    assert_eq!(estimator.rate(), Some(Frequency::from_hz(50)));
+
+    // Out of order instants do not move time backwards
+    estimator.record(start);
+    assert!(estimator.rate_hz().unwrap() > 50.0);
+
+    estimator.reset();
+    assert_eq!(estimator.rate(), None);
+
+    let mut estimator = RateEstimator::ewma(0.5);
+    estimator.record_interval(Duration::ZERO);
+    estimator.record_interval(Duration::from_millis(100));
+    assert_eq!(estimator.rate(), Some(Frequency::from_hz(10)));
+    estimator.record_interval(Duration::from_millis(300));
+    assert!((estimator.rate_hz().unwrap() - 5.0).abs() < 1e-9);
+
+    let mut estimator = RateEstimator::ewma(0.0);
+    estimator.record_interval(Duration::ZERO);
+    estimator.record_interval(Duration::from_millis(1));
+    estimator.record_interval(Duration::from_secs(1));
+    assert_eq!(estimator.rate(), Some(Frequency::from_khz(1)));
+}
+
+#[test]
+fn test_rate_limit() {
+    use std::time::Duration;
+
+    assert_eq!(Frequency::from_hz(1).interval(), Duration::from_secs(1));
+    assert_eq!(
+        Frequency::from_hz(7).interval(),
+        Duration::from_nanos(142_857_143)
+    );
+    assert_eq!(Frequency::from_ghz(2).interval(), Duration::from_nanos(1));
+    assert_eq!(Frequency::ZERO.interval(), Duration::MAX);
+
+    let limit = Frequency::from_hz(3);
+    assert_eq!(limit.max_events_in(Duration::ZERO), 0);
+    assert_eq!(limit.max_events_in(Duration::from_millis(999)), 2);
+    assert_eq!(limit.max_events_in(Duration::from_secs(1)), 3);
+    assert_eq!(Frequency::ZERO.max_events_in(Duration::MAX), 0);
+    assert_eq!(Frequency(u64::MAX).max_events_in(Duration::MAX), u64::MAX);
+    assert_eq!(
+        limit.max_events_with_burst(Duration::from_secs(1), u64::MAX),
+        u64::MAX
+    );
+
+    assert_eq!(limit.time_to_refill(0), Duration::ZERO);
+    assert_eq!(limit.time_to_refill(3), Duration::from_secs(1));
+    assert_eq!(limit.time_to_refill(1), limit.interval());
+    assert_eq!(Frequency::ZERO.time_to_refill(1), Duration::MAX);
+    assert_eq!(Frequency::ZERO.time_to_refill(0), Duration::ZERO);
+}
+
+#[test]
+fn test_frequency_stats() {
+    use crate::FrequencyStats;
+
+    let mut stats = FrequencyStats::new();
+    assert_eq!(stats.count(), 0);
+    assert_eq!(stats.min(), None);
+    assert_eq!(stats.mean(), None);
+    assert_eq!(stats.std_dev(), None);
+
+    stats.record(Frequency::from_hz(10));
+    assert_eq!(stats.mean(), Some(Frequency::from_hz(10)));
+    assert_eq!(stats.std_dev_hz(), None);
+
+    stats.extend([2, 4, 4, 4, 5, 5, 7, 9].map(Frequency::from_hz));
+    assert_eq!(stats.count(), 9);
+    assert_eq!(stats.min(), Some(Frequency::from_hz(2)));
+    assert_eq!(stats.max(), Some(Frequency::from_hz(10)));
+
+    // Sum is 50, so the exact mean 5.56 rounds to 6
+    assert_eq!(stats.mean(), Some(Frequency::from_hz(6)));
+    assert!((stats.std_dev_hz().unwrap() - 2.603_417).abs() < 1e-6);
+    assert_eq!(stats.std_dev(), Some(Frequency::from_hz(3)));
+
+    // The mean is exact even for values beyond f64 precision
+    let stats: FrequencyStats = [u64::MAX, u64::MAX - 2]
+        .map(Frequency)
+        .into_iter()
+        .collect();
+    assert_eq!(stats.mean(), Some(Frequency(u64::MAX - 1)));
+
+    let stats: FrequencyStats = [Frequency::ZERO, Frequency::ZERO].into_iter().collect();
+    assert_eq!(stats.std_dev_hz(), Some(0.0));
+    assert_eq!(stats.std_dev_ppm(), None);
+}
+
+#[test]
+fn test_frequency_histogram() {
+    use crate::{FrequencyHistogram, FrequencyRange};
+
+    let range = |min, max| FrequencyRange::new(Frequency::from_hz(min), Frequency::from_hz(max));
+    assert!(FrequencyHistogram::new(range(0, 100), 4).is_none());
+    assert!(FrequencyHistogram::new(range(10, 10), 4).is_none());
+    assert!(FrequencyHistogram::new(range(10, 100), 0).is_none());
+
+    let mut histogram = FrequencyHistogram::new(range(100, 1_600), 4).unwrap();
+    let edges: Vec<_> = histogram
+        .bins()
+        .map(|(band, _)| (band.min.as_hz(), band.max.as_hz()))
+        .collect();
+    assert_eq!(edges, [(100, 200), (200, 400), (400, 800), (800, 1_600)]);
+    assert_eq!(histogram.percentile(50.0), None);
+
+    for hz in [50, 100, 199, 200, 1_600, 1_601] {
+        histogram.record(Frequency::from_hz(hz));
+    }
+    let counts: Vec<_> = histogram.bins().map(|(_, count)| count).collect();
+    assert_eq!(counts, [2, 1, 0, 1]);
+    assert_eq!(histogram.underflow(), 1);
+    assert_eq!(histogram.overflow(), 1);
+    assert_eq!(histogram.count(), 6);
+
+    assert_eq!(histogram.percentile(0.0), Some(Frequency::from_hz(100)));
+    assert_eq!(histogram.percentile(100.0), Some(Frequency::from_hz(1_600)));
+    assert_eq!(
+        histogram.percentile(f64::NAN),
+        Some(Frequency::from_hz(100))
+    );
+
+    // Rank 2 of 6 is the first of two samples in the 100 Hz - 200 Hz bin, halfway up it
+    assert_eq!(histogram.percentile(30.0), Some(Frequency::from_hz(141)));
+    // Rank 4 is the only sample in the 200 Hz - 400 Hz bin
+    assert_eq!(histogram.percentile(60.0), Some(Frequency::from_hz(400)));
+}
+
+#[test]
+fn test_channel_grid() {
+    use crate::{ChannelGrid, FrequencyRange};
+
+    // Air band voice channels: 118 MHz to 136.975 MHz in 25 kHz steps
+    let air = ChannelGrid::new(Frequency::from_mhz(118), Frequency::from_khz(25), 760);
+    assert_eq!(
+        air.range(),
+        Some(FrequencyRange::new(
+            Frequency::from_mhz(118),
+            Frequency::from_khz(136_975)
+        ))
+    );
+    assert_eq!(
+        air.frequency_to_channel(Frequency::from_khz(121_500)),
+        Some(140)
+    );
+    assert_eq!(air.frequency_to_channel(Frequency::from_khz(121_510)), None);
+    assert!(air.contains(Frequency::from_khz(136_975)));
+    assert!(!air.contains(Frequency::from_mhz(137)));
+
+    // Snapping clamps to the grid and rounds ties up
+    assert_eq!(air.nearest_channel(Frequency::from_mhz(100)), Some(0));
+    assert_eq!(air.nearest_channel(Frequency::from_mhz(200)), Some(759));
+    assert_eq!(
+        air.nearest_channel(Frequency::from_hz(118_012_499)),
+        Some(0)
+    );
+    assert_eq!(
+        air.nearest_channel(Frequency::from_hz(118_012_500)),
+        Some(1)
+    );
+    assert_eq!(
+        air.snap(Frequency::from_khz(127_260)),
+        Some(Frequency::from_khz(127_250))
+    );
+
+    let marine = ChannelGrid::new(Frequency::from_khz(156_050), Frequency::from_khz(50), 28)
+        .with_first_channel(1);
+    assert_eq!(marine.channel_to_frequency(0), None);
+    assert_eq!(
+        marine.channel_to_frequency(28),
+        Some(Frequency::from_khz(157_400))
+    );
+    assert_eq!(marine.channel_to_frequency(29), None);
+    assert_eq!(marine.iter().count(), 28);
+    assert_eq!(
+        marine.iter().nth(15),
+        Some((16, Frequency::from_khz(156_800)))
+    );
+
+    let empty = ChannelGrid::new(Frequency::from_mhz(1), Frequency::from_khz(1), 0);
+    assert_eq!(empty.nearest_channel(Frequency::from_mhz(1)), None);
+    assert_eq!(empty.range(), None);
+    assert_eq!(empty.iter().count(), 0);
+}
+
+#[test]
+fn test_millihertz() {
+    use crate::{Error, Frequency, FrequencyMilliHz, Rounding};
+
+    let grid: FrequencyMilliHz = "50.02 Hz".parse().unwrap();
+    assert_eq!(grid, FrequencyMilliHz(50_020));
+    assert_eq!(grid.to_string(), "50.020 Hz");
+    assert!((grid.as_hz_f64() - 50.02).abs() < 1e-9);
+
+    assert_eq!("250 mHz".parse(), Ok(FrequencyMilliHz(250)));
+    assert_eq!("1.5 MHz".parse(), Ok(FrequencyMilliHz(1_500_000_000)));
+    assert_eq!("1.5 mhz".parse(), Ok(FrequencyMilliHz(1_500_000_000)));
+    assert_eq!("0.0125 kHz".parse(), Ok(FrequencyMilliHz(12_500)));
+    assert_eq!(".5 Hz".parse(), Ok(FrequencyMilliHz(500)));
+
+    // Values beyond millihertz precision round to the nearest millihertz
+    assert_eq!("0.0004 Hz".parse(), Ok(FrequencyMilliHz(0)));
+    assert_eq!("0.0005 Hz".parse(), Ok(FrequencyMilliHz(1)));
+    assert_eq!(
+        "0.1234567890123456789012345678 Hz".parse(),
+        Ok(FrequencyMilliHz(123))
+    );
+
+    // Errors read like those of `Frequency`, which parses the same notation
+    assert_eq!(
+        "-1 Hz".parse::<FrequencyMilliHz>(),
+        Err(Error::InvalidValue("-1 ".into()))
+    );
+    assert_eq!(
+        "1e3 Hz".parse::<FrequencyMilliHz>(),
+        Err(Error::InvalidValue("1e3 ".into()))
+    );
+    assert_eq!(
+        ". Hz".parse::<FrequencyMilliHz>(),
+        Err(Error::InvalidValue(". ".into()))
+    );
+    assert_eq!(
+        "99999999999 GHz".parse::<FrequencyMilliHz>(),
+        Err(Error::InvalidValue("99999999999 ".into()))
+    );
+    assert_eq!(
+        "50 rpm".parse::<FrequencyMilliHz>(),
+        Err(Error::UnknownUnit("50 rpm".into()))
+    );
+
+    assert_eq!(FrequencyMilliHz(999).to_string(), "999 mHz");
+    assert_eq!(FrequencyMilliHz(1_234_567).to_string(), "1.235 kHz");
+    assert_eq!(FrequencyMilliHz(2_400_000_000_000).to_string(), "2.400 GHz");
+
+    assert_eq!(
+        FrequencyMilliHz(1_500).to_frequency(Rounding::Down),
+        Frequency::from_hz(1)
+    );
+    assert_eq!(
+        FrequencyMilliHz(1_500).to_frequency(Rounding::Nearest),
+        Frequency::from_hz(2)
+    );
+    assert_eq!(FrequencyMilliHz(1_500).to_frequency_exact(), None);
+    assert_eq!(
+        FrequencyMilliHz::from_hz(60).to_frequency_exact(),
+        Some(Frequency::from_hz(60))
+    );
+
+    assert_eq!(
+        FrequencyMilliHz::try_from(Frequency::from_hz(50)),
+        Ok(FrequencyMilliHz(50_000))
+    );
+    assert!(FrequencyMilliHz::try_from(Frequency(u64::MAX)).is_err());
+
+    let sum = FrequencyMilliHz::HERTZ * 50 + FrequencyMilliHz::MILLIHERTZ * 20;
+    assert_eq!(sum, grid);
+    assert_eq!(grid - FrequencyMilliHz::HERTZ, FrequencyMilliHz(49_020));
+    assert_eq!(grid / 2, FrequencyMilliHz(25_010));
+}
+
+#[test]
+fn test_generic_storage() {
+    use crate::{Error, Frequency, FrequencyOf};
+
+    let small: FrequencyOf<u32> = "48 MHz".parse().unwrap();
+    assert_eq!(small, FrequencyOf(48_000_000u32));
+    assert_eq!(small.to_string(), "48.00 MHz");
+    assert_eq!(small.to_exact_string(), "48 MHz");
+    assert_eq!(small / 2 + FrequencyOf(1_000), FrequencyOf(24_001_000u32));
+    assert_eq!(small * 3 - small, FrequencyOf(96_000_000u32));
+
+    assert_eq!(
+        "5 GHz".parse::<FrequencyOf<u32>>(),
+        Err(Error::InvalidValue("5 ".into()))
+    );
+    assert_eq!(
+        "70 kHz".parse::<FrequencyOf<u16>>(),
+        Err(Error::InvalidValue("70 ".into()))
+    );
+    assert!("inf Hz".parse::<FrequencyOf<u128>>().is_err());
+
+    let wide: FrequencyOf<u128> = "20000000 GHz".parse().unwrap();
+    assert_eq!(wide, FrequencyOf(20_000_000_000_000_000u128));
+    assert_eq!(
+        wide.try_cast::<u64>(),
+        Some(FrequencyOf(20_000_000_000_000_000))
+    );
+    assert_eq!((wide * 1_000).try_cast::<u64>(), None);
+
+    assert_eq!(Frequency::from(small), Frequency::from_mhz(48));
+    assert_eq!(
+        FrequencyOf::<u128>::from(small),
+        FrequencyOf(48_000_000u128)
+    );
+    assert_eq!(
+        FrequencyOf::<u32>::try_from(Frequency::from_mhz(48)),
+        Ok(small)
+    );
+    assert_eq!(
+        FrequencyOf::<u16>::try_from(small),
+        Err(Error::InvalidValue("48.00 MHz".into()))
+    );
+    assert_eq!(
+        Frequency::try_from(wide),
+        Ok(Frequency::from_ghz(20_000_000))
+    );
+    assert!(Frequency::try_from(FrequencyOf(u128::MAX)).is_err());
+    assert_eq!(
+        FrequencyOf::<u128>::from(Frequency(u64::MAX)),
+        FrequencyOf(u128::from(u64::MAX))
+    );
+}
+
+#[test]
+fn test_literal_frequency() {
+    use crate::Frequency;
+
+    // Integer literals infer `u64`, since `Frequency` is not generic
+    assert_eq!(Frequency(440).to_string(), "440 Hz");
+    assert_eq!(format!("{}", Frequency(2_400_000_000)), "2.40 GHz");
+    assert_eq!(Frequency(440).to_exact_string(), "440 Hz");
+
+    assert_eq!(Frequency(440) + Frequency(1), Frequency::from_hz(441));
+    assert_eq!(Frequency(440) - Frequency(40), Frequency::from_hz(400));
+    assert_eq!(Frequency(440) * 2, Frequency::from_hz(880));
+    assert_eq!(Frequency(440) / 2, Frequency::from_hz(220));
+    assert_eq!(Frequency(440).try_add(Frequency(1)), Some(Frequency(441)));
+    assert_eq!(Frequency(440).try_div(0), None);
+}
+
+#[test]
+fn test_hz_conversions() {
+    use crate::{Frequency, FrequencyOf};
+    use std::num::{NonZeroU32, NonZeroU64};
+
+    fn total_hz<F: Into<u64>>(values: impl IntoIterator<Item = F>) -> u64 {
+        values.into_iter().map(Into::into).sum()
+    }
+
+    assert_eq!(Frequency::from(440_u64), Frequency::from_hz(440));
+    assert_eq!(u64::from(Frequency::from_khz(48)), 48_000);
+    assert_eq!(
+        Frequency::from(NonZeroU64::new(1_000).unwrap()),
+        Frequency::from_khz(1)
+    );
+    assert_eq!(
+        FrequencyOf::from(NonZeroU32::new(1_000).unwrap()),
+        FrequencyOf(1_000_u32)
+    );
+    assert_eq!(u128::from(FrequencyOf(7_u128)), 7);
+
+    let freq: Frequency = 2_400_000_000.into();
+    assert_eq!(freq, Frequency::from_mhz(2_400));
+    assert_eq!(
+        total_hz([Frequency::from_khz(1), Frequency::from_hz(1)]),
+        1_001
+    );
+}
+
+#[cfg(feature = "chrono")]
+#[test]
+fn test_chrono_ticks_from() {
+    use crate::Frequency;
+    use chrono::{DateTime, Duration};
+
+    let start = DateTime::UNIX_EPOCH + Duration::days(1);
+    let mut ticks = Frequency::from_hz(7).ticks_from(start);
+    assert_eq!(ticks.next(), Some(start));
+    assert_eq!(ticks.nth(6), Some(start + Duration::seconds(1)));
+    assert_eq!(ticks.nth(6_999), Some(start + Duration::seconds(1_001)));
+
+    let last = Frequency::from_ghz(3).ticks_from(start).nth(2).unwrap();
+    assert_eq!(last - start, Duration::nanoseconds(0));
+    let last = Frequency::from_ghz(3).ticks_from(start).nth(3).unwrap();
+    assert_eq!(last - start, Duration::nanoseconds(1));
+
+    assert_eq!(Frequency::ZERO.ticks_from(start).next(), None);
+    assert_eq!(
+        Frequency::from_hz(1)
+            .ticks_from(DateTime::<chrono::Utc>::MAX_UTC)
+            .count(),
+        1
+    );
+}
+
+#[test]
+fn test_plain_parser_matches_general_parser() {
+    use crate::{Frequency, FrequencyOf, math::parse_plain_hz, parse_frequency_general};
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    for s in [
+        "2400 MHz",
+        "48kHz",
+        " 2.437 GHz\t",
+        "+1.5 mhz",
+        "1. Hz",
+        ".5 kHz",
+        "0.0000005 GHz",
+        "0.5 Hz",
+        "1.9999999999 GHz",
+        "9999999999999999999 Hz",
+        "18446744073.709551615 GHz",
+        "0.123456789012345678 GHz",
+        "9999999999999999999 GHz",
+    ] {
+        let hz = parse_plain_hz(s).unwrap_or_else(|| panic!("{s:?} is plain"));
+        assert_eq!(
+            parse_frequency_general::<u128>(s),
+            Ok(FrequencyOf(hz)),
+            "{s:?}"
+        );
+    }
+
+    for s in [
+        "", "Hz", ". Hz", "+ 1 Hz", "1e3 Hz", "-1 Hz", "1 €", "1 hz hz", "1..0 Hz",
+    ] {
+        assert_eq!(parse_plain_hz(s), None, "{s:?}");
+    }
+    assert_eq!(parse_plain_hz("18446744073709551615 Hz"), None);
+    assert_eq!(parse_plain_hz("0.1234567890123456789 Hz"), None);
+
+    let alphabet = b"0123456789012345678901234.. +-eEkKmMgGhHzZhzhz\t";
+    let mut rng = StdRng::seed_from_u64(946);
+    for _ in 0..20_000 {
+        let len = rng.gen_range(0..14);
+        let s: String = (0..len)
+            .map(|_| char::from(alphabet[rng.gen_range(0..alphabet.len())]))
+            .collect();
+
+        if let Some(hz) = parse_plain_hz(&s) {
+            assert_eq!(
+                parse_frequency_general::<u128>(&s),
+                Ok(FrequencyOf(hz)),
+                "{s:?}"
+            );
+        }
+        assert_eq!(
+            s.parse::<Frequency>(),
+            parse_frequency_general::<u64>(&s).map(Frequency::from),
+            "{s:?}"
+        );
+    }
+}
+
+#[test]
+fn test_format_into() {
+    use crate::Frequency;
+
+    let max = Frequency::from_hz(u64::MAX);
+    let mut s = String::new();
+    max.format_into(&mut s).unwrap();
+    assert_eq!(s, "18446744073.71 GHz");
+    assert_eq!(s.len(), Frequency::MAX_DISPLAY_LEN);
+
+    s.clear();
+    max.format_exact_into(&mut s).unwrap();
+    assert_eq!(s, "18446744073.709551615 GHz");
+    assert_eq!(s.len(), Frequency::MAX_EXACT_LEN);
+
+    for (freq, exact) in [
+        (Frequency::from_hz(999), "999 Hz"),
+        (Frequency::from_khz(48), "48 kHz"),
+        (Frequency::from_hz(1_050), "1.05 kHz"),
+        (Frequency::from_hz(2_400_000_010), "2.40000001 GHz"),
+        (Frequency::from_hz(12_500_000), "12.5 MHz"),
+    ] {
+        let mut s = String::new();
+        freq.format_exact_into(&mut s).unwrap();
+        assert_eq!(s, exact);
+        assert_eq!(freq.to_exact_string(), exact);
+    }
+}
+
+#[cfg(feature = "heapless")]
+#[test]
+fn test_heapless_strings() {
+    use crate::Frequency;
+
+    let s = Frequency::from_hz(u64::MAX).to_heapless_string::<{ Frequency::MAX_DISPLAY_LEN }>();
+    assert_eq!(s, "18446744073.71 GHz");
+    let s = Frequency::from_hz(u64::MAX).to_heapless_exact_string::<{ Frequency::MAX_EXACT_LEN }>();
+    assert_eq!(s, "18446744073.709551615 GHz");
+    assert_eq!(
+        Frequency::from_khz(48).to_heapless_string::<32>(),
+        "48.00 kHz"
+    );
+
+    let mut small = heapless::String::<4>::new();
+    assert!(Frequency::from_khz(48).format_into(&mut small).is_err());
+}
+
+#[test]
+fn test_const_fns() {
+    use crate::Frequency;
+    use std::time::Duration;
+
+    const SYSCLK: Frequency = Frequency::from_mhz(168);
+    const AHB: Frequency = Frequency::from_hz(SYSCLK.as_hz() / 2);
+    const APB1: Frequency = Frequency::from_hz(SYSCLK.as_hz() / 4).min(Frequency::from_mhz(42));
+    const APB2: Frequency = Frequency::from_hz(SYSCLK.as_hz() / 2).max(Frequency::from_khz(500));
+    const ADC: Frequency = Frequency::from_ghz(1).clamp(Frequency::ZERO, Frequency::from_mhz(36));
+    const TABLE: [u64; 4] = [
+        SYSCLK.as_ghz(),
+        SYSCLK.as_mhz(),
+        SYSCLK.as_khz(),
+        AHB.as_hz(),
+    ];
+    const TICK: Duration = Frequency::from_mhz(1).as_duration();
+    const STOPPED: [bool; 2] = [Frequency::ZERO.is_zero(), SYSCLK.is_zero()];
+
+    assert_eq!(APB1, Frequency::from_mhz(42));
+    assert_eq!(APB2, Frequency::from_mhz(84));
+    assert_eq!(ADC, Frequency::from_mhz(36));
+    assert_eq!(TABLE, [0, 168, 168_000, 84_000_000]);
+    assert_eq!(TICK, Duration::from_micros(1));
+    assert_eq!(Frequency::ZERO.as_duration(), Duration::ZERO);
+    assert_eq!(STOPPED, [true, false]);
+    assert_eq!(
+        Frequency::from_khz(5).clamp(Frequency::from_khz(8), Frequency::from_khz(9)),
+        Frequency::from_khz(8)
+    );
+
+    let inverted = std::panic::catch_unwind(|| SYSCLK.clamp(AHB, APB1));
+    assert!(inverted.is_err());
+}
+
+#[test]
+fn test_parse_ignores_unit_case() {
+    use crate::{Error, Frequency};
+
+    assert_eq!("2.5 GHZ".parse(), Ok(Frequency::from_mhz(2_500)));
+    assert_eq!("48 KhZ".parse(), Ok(Frequency::from_khz(48)));
+    assert_eq!("1E3 Hz".parse(), Ok(Frequency::from_khz(1)));
+
+    // Errors keep the input in lowercase
+    assert_eq!(
+        "ABC".parse::<Frequency>(),
+        Err(Error::UnknownUnit("abc".into()))
+    );
+    assert_eq!(
+        "X MHz".parse::<Frequency>(),
+        Err(Error::InvalidValue("x ".into()))
+    );
+
+    // Suffixes are never split inside a multi-byte character
+    assert_eq!(
+        "1 €".parse::<Frequency>(),
+        Err(Error::UnknownUnit("1 €".into()))
+    );
+    assert_eq!(
+        "ühz".parse::<Frequency>(),
+        Err(Error::InvalidValue("ü".into()))
+    );
+}
+
+#[test]
+fn test_ticker() {
+    use crate::{Frequency, Ticker};
+    use std::time::{Duration, Instant};
+
+    assert!(Ticker::new(Frequency::ZERO).is_none());
+
+    let freq = Frequency::from_khz(1);
+    let start = Instant::now();
+    let mut ticker = Ticker::new(freq).unwrap();
+    assert_eq!(ticker.frequency(), freq);
+    assert_eq!(
+        ticker.deadline(1_500) - ticker.deadline(0),
+        Duration::from_millis(1_500)
+    );
+    assert_eq!(ticker.checked_deadline(1_500), Some(ticker.deadline(1_500)));
+    assert!(
+        Ticker::new(Frequency::HERTZ)
+            .unwrap()
+            .checked_deadline(u64::MAX)
+            .is_none()
+    );
+
+    for _ in 0..5 {
+        assert!(ticker.tick() >= 1);
+    }
+    assert!(ticker.ticks() >= 5);
+    assert!(start.elapsed() >= Duration::from_millis(5));
+
+    std::thread::sleep(Duration::from_millis(20));
+    let before = ticker.ticks();
+    assert!(ticker.tick() >= 15);
+    assert!(ticker.ticks() >= before + 15);
+
+    ticker.reset();
+    assert_eq!(ticker.ticks(), 0);
+    let due = ticker.next().unwrap();
+    assert!(Instant::now() >= due);
+
+    // 1/3 s periods are not rounded, so three ticks are exactly one second apart
+    let ticker = Ticker::new(Frequency::from_hz(3)).unwrap();
+    assert_eq!(
+        ticker.deadline(3) - ticker.deadline(0),
+        Duration::from_secs(1)
+    );
+    assert_eq!(
+        ticker.deadline(1) - ticker.deadline(0),
+        Duration::from_nanos(333_333_333)
+    );
+}
+
+#[test]
+fn test_period() {
+    use crate::{Frequency, Period, Rounding};
+    use std::time::Duration;
+
+    assert_eq!(Frequency::ZERO.period(Rounding::Nearest), None);
+    assert_eq!(
+        Frequency::from_hz(1).period(Rounding::Down),
+        Some(Period::from_picos(1_000_000_000_000))
+    );
+
+    let period = Frequency::from_hz(3).period(Rounding::Up).unwrap();
+    assert_eq!(period.as_picos(), 333_333_333_334);
+    assert_eq!(period.as_nanos(Rounding::Down), 333_333_333);
+    assert_eq!(period.as_nanos(Rounding::Up), 333_333_334);
+    assert_eq!(
+        period.as_duration(Rounding::Nearest),
+        Duration::from_nanos(333_333_333)
+    );
+    assert!((period.as_secs_f64() - 1.0 / 3.0).abs() < 1e-12);
+
+    let period = Frequency::from_mhz(1_500)
+        .period(Rounding::Nearest)
+        .unwrap();
+    assert_eq!(period.as_picos(), 667);
+    assert_eq!(period.as_nanos(Rounding::Down), 0);
+    assert_eq!(period.as_nanos(Rounding::Nearest), 1);
+    assert_eq!(period.to_string(), "667 ps");
+
+    let period = Frequency(u64::MAX).period(Rounding::Nearest).unwrap();
+    assert_eq!(period, Period::ZERO);
+    assert_eq!(
+        Frequency(u64::MAX).period(Rounding::Up),
+        Some(Period::from_picos(1))
+    );
+}
+
+#[cfg(feature = "time")]
+#[test]
+fn test_time_duration() {
+    use crate::{Frequency, Rounding};
+
+    assert_eq!(Frequency::ZERO.as_time_duration(), time::Duration::ZERO);
+    assert_eq!(
+        Frequency::from_hz(3).as_time_duration().whole_nanoseconds(),
+        333_333_333
+    );
+    assert_eq!(
+        Frequency::from_hz(6).as_time_duration().whole_nanoseconds(),
+        166_666_667
+    );
+    assert_eq!(
+        Frequency::from_mhz(1_500)
+            .as_time_duration()
+            .whole_nanoseconds(),
+        1
+    );
+    assert_eq!(
+        Frequency::from_ghz(3)
+            .as_time_duration()
+            .whole_nanoseconds(),
+        0
+    );
+    assert_eq!(
+        Frequency::from_ghz(3)
+            .as_time_duration_rounded(Rounding::Up)
+            .whole_nanoseconds(),
+        1
+    );
+
+    for hz in [1, 7, 50, 1_000, 31_250, 1_000_000] {
+        let freq = Frequency::from_hz(hz);
+        assert_eq!(
+            Frequency::from_time_duration(freq.as_time_duration()),
+            Ok(freq)
+        );
+    }
+
+    assert_eq!(
+        Frequency::from_time_duration(time::Duration::nanoseconds(3)),
+        Ok(Frequency::from_hz(333_333_333))
+    );
+    assert!(Frequency::from_time_duration(time::Duration::ZERO).is_err());
+    assert!(Frequency::from_time_duration(time::Duration::seconds(-1)).is_err());
+    assert!(Frequency::from_time_duration(time::Duration::seconds(3)).is_err());
+}
+
+#[test]
+fn test_try_from_duration() {
+    use crate::Frequency;
+    use std::time::Duration;
+
+    assert_eq!(
+        Frequency::try_from(Duration::from_nanos(1)),
+        Ok(Frequency::from_ghz(1))
+    );
+    assert_eq!(
+        Frequency::try_from(Duration::from_millis(1)),
+        Ok(Frequency::from_khz(1))
+    );
+    assert_eq!(
+        Frequency::try_from(Duration::from_secs(1)),
+        Ok(Frequency::from_hz(1))
+    );
+    assert_eq!(
+        Frequency::try_from(Duration::from_nanos(3)),
+        Ok(Frequency::from_hz(333_333_333))
+    );
+    assert_eq!(
+        Frequency::try_from(Duration::from_nanos(6)),
+        Ok(Frequency::from_hz(166_666_667))
+    );
+    assert_eq!(
+        Frequency::try_from(Duration::from_secs(2)),
+        Ok(Frequency::from_hz(1))
+    );
+
+    assert!(Frequency::try_from(Duration::ZERO).is_err());
+    assert!(Frequency::try_from(Duration::from_secs(2) + Duration::from_nanos(1)).is_err());
+    assert!(Frequency::try_from(Duration::MAX).is_err());
+
+    for hz in [1, 7, 50, 1_000, 31_250, 1_000_000] {
+        let freq = Frequency::from_hz(hz);
+        assert_eq!(Frequency::try_from(freq.as_duration()), Ok(freq));
+    }
+}
+
+#[test]
+fn test_non_zero_frequency() {
+    use crate::{Frequency, NonZeroFrequency};
+    use std::{num::NonZeroU64, time::Duration};
+
+    assert_eq!(size_of::<Option<NonZeroFrequency>>(), size_of::<u64>());
+
+    let tick = NonZeroFrequency::try_from(Frequency::from_khz(1)).unwrap();
+    assert_eq!(tick.get(), Frequency::from_khz(1));
+    assert_eq!(tick.as_hz().get(), 1_000);
+    assert_eq!(tick.period(), Duration::from_millis(1));
+    assert!((tick.period_secs_f64() - 0.001).abs() < f64::EPSILON);
+    assert_eq!(NonZeroFrequency::MAX.period(), Duration::ZERO);
+    assert_eq!(NonZeroFrequency::HERTZ.period(), Duration::from_secs(1));
+
+    let three = NonZeroU64::new(3).unwrap();
+    assert_eq!(tick.divide(three), Frequency::from_hz(333));
+    assert_eq!(NonZeroFrequency::HERTZ.divide(three), Frequency::ZERO);
+    assert_eq!(Frequency::from_mhz(1) / tick, 1_000);
+    assert_eq!(Frequency::ZERO / tick, 0);
+
+    assert_eq!(NonZeroFrequency::new(Frequency::ZERO), None);
+    assert!(NonZeroFrequency::try_from(Frequency::ZERO).is_err());
+    assert_eq!("1 kHz".parse::<NonZeroFrequency>(), Ok(tick));
+    assert!("0 GHz".parse::<NonZeroFrequency>().is_err());
+    assert!("nope".parse::<NonZeroFrequency>().is_err());
+    assert_eq!(tick.to_string(), "1.00 kHz");
+    assert_eq!(Frequency::from(tick), Frequency::from_khz(1));
+    assert_eq!(NonZeroFrequency::from(three).as_hz(), three);
+    assert_eq!(NonZeroFrequency::default(), NonZeroFrequency::HERTZ);
+}
+
+#[test]
+fn test_frequency_f64() {
+    use crate::{Error, Frequency, FrequencyF64, Rounding};
+    use std::time::Duration;
+
+    let tone: FrequencyF64 = "440.25 Hz".parse().unwrap();
+    assert_eq!(tone, FrequencyF64(440.25));
+    assert_eq!(tone.to_string(), "440.25 Hz");
+    assert_eq!("1.5 kHz".parse(), Ok(FrequencyF64(1_500.0)));
+    assert_eq!(
+        "-1 Hz".parse::<FrequencyF64>(),
+        Err(Error::InvalidValue("-1 ".into()))
+    );
+    assert_eq!(
+        "1 rpm".parse::<FrequencyF64>(),
+        Err(Error::UnknownUnit("1 rpm".into()))
+    );
+
+    assert_eq!(FrequencyF64::from_mhz(2.5).to_string(), "2.50 MHz");
+    assert_eq!(FrequencyF64::from_ghz(1.0).as_mhz(), 1_000.0);
+    assert_eq!(FrequencyF64::from_khz(0.5).as_hz(), 500.0);
+    assert_eq!(
+        (tone + tone - FrequencyF64::HERTZ) / 2.0,
+        FrequencyF64(439.75)
+    );
+
+    assert_eq!(FrequencyF64(4.0).as_duration(), Duration::from_millis(250));
+    assert_eq!(FrequencyF64::ZERO.as_duration(), Duration::ZERO);
+    assert_eq!(FrequencyF64(-1.0).as_duration(), Duration::ZERO);
+
+    assert_eq!(tone.to_frequency(Rounding::Down), Frequency::from_hz(440));
+    assert_eq!(
+        tone.to_frequency(Rounding::Nearest),
+        Frequency::from_hz(440)
+    );
+    assert_eq!(tone.to_frequency(Rounding::Up), Frequency::from_hz(441));
+    assert_eq!(
+        FrequencyF64(-5.0).to_frequency(Rounding::Nearest),
+        Frequency::ZERO
+    );
+    assert_eq!(
+        FrequencyF64(f64::NAN).to_frequency(Rounding::Nearest),
+        Frequency::ZERO
+    );
+    assert_eq!(
+        FrequencyF64::from_frequency(Frequency::from_khz(48)),
+        FrequencyF64(48_000.0)
+    );
+}
+
+#[test]
+fn test_rational_frequency() {
+    use crate::{Error, Frequency, RationalFrequency, Rounding};
+
+    let ntsc = RationalFrequency::new(60_000, 2_002).unwrap();
+    assert_eq!((ntsc.num(), ntsc.den()), (30_000, 1_001));
+    assert_eq!(RationalFrequency::new(1, 0), None);
+
+    assert_eq!("30000/1001 Hz".parse(), Ok(ntsc));
+    assert_eq!("30 / 1.001 Hz".parse(), Ok(ntsc));
+    assert_eq!("29.97 Hz".parse().ok(), RationalFrequency::new(2_997, 100));
+    assert_eq!(
+        "48 kHz".parse(),
+        Ok(RationalFrequency::from(Frequency::from_khz(48)))
+    );
+    assert_eq!(
+        "1/0 Hz".parse::<RationalFrequency>(),
+        Err(Error::InvalidValue("1/0".into()))
+    );
+    assert_eq!(
+        "-1/2 Hz".parse::<RationalFrequency>(),
+        Err(Error::InvalidValue("-1/2".into()))
+    );
+    assert_eq!(
+        "1/2".parse::<RationalFrequency>(),
+        Err(Error::UnknownUnit("1/2".into()))
+    );
+
+    assert_eq!(ntsc.to_string(), "30000/1001 Hz");
+    assert_eq!((ntsc * 1_001).to_string(), "30000 Hz");
+    assert_eq!(
+        (ntsc * 1_001).to_frequency_exact(),
+        Some(Frequency::from_hz(30_000))
+    );
+    assert_eq!(ntsc.to_frequency_exact(), None);
+    assert_eq!(ntsc.to_frequency(Rounding::Down), Frequency::from_hz(29));
+    assert_eq!(ntsc.to_frequency(Rounding::Nearest), Frequency::from_hz(30));
+    assert!((ntsc.to_frequency_f64().as_hz() - 29.970_03).abs() < 1e-5);
+
+    let third = RationalFrequency::new(1, 3).unwrap();
+    let half = RationalFrequency::new(1, 2).unwrap();
+    assert_eq!(third + half, RationalFrequency::new(5, 6).unwrap());
+    assert_eq!(half - third, RationalFrequency::new(1, 6).unwrap());
+    assert_eq!(half / 3, RationalFrequency::new(1, 6).unwrap());
+    assert_eq!(third.checked_sub(half), None);
+    assert_eq!(third.checked_div(0), None);
+    assert_eq!(
+        RationalFrequency::from(Frequency(u64::MAX)).checked_add(half),
+        None
+    );
+
+    assert!(third < half);
+    assert!(RationalFrequency::new(2_997, 100).unwrap() < ntsc);
+    assert_eq!(RationalFrequency::default(), RationalFrequency::ZERO);
+}
+
+#[test]
+fn test_frequency_builder() {
+    use crate::{Frequency, FrequencyBuilder};
+
+    assert_eq!(
+        Frequency::builder().ghz(2).mhz(437).khz(500).hz(1).build(),
+        Some(Frequency::from_hz(2_437_500_001))
+    );
+    assert_eq!(
+        Frequency::builder().khz(1).khz(2).mhz(1).build(),
+        Some(Frequency::from_hz(1_003_000))
+    );
+    assert_eq!(Frequency::builder().build(), Some(Frequency::ZERO));
+    assert_eq!(FrequencyBuilder::default(), Frequency::builder());
+
+    // Overflow in a part or in the sum is sticky
+    assert_eq!(Frequency::builder().ghz(u64::MAX).hz(1).build(), None);
+    assert_eq!(Frequency::builder().hz(u64::MAX).hz(1).build(), None);
+    assert_eq!(
+        Frequency::builder().hz(u64::MAX).build(),
+        Some(Frequency(u64::MAX))
+    );
+}
+
+#[test]
+fn test_range_iteration() {
+    use crate::{Frequency, FrequencyRange, FrequencyRangeIter};
+
+    let band = FrequencyRange::new(Frequency::from_mhz(100), Frequency::from_mhz(110));
+    let scan: Vec<_> = band.iter().step_by(2_500_000).collect();
+    assert_eq!(
+        scan,
+        [100_000, 102_500, 105_000, 107_500, 110_000].map(Frequency::from_khz)
+    );
+    assert_eq!(band.iter().size_hint(), (10_000_001, Some(10_000_001)));
+    assert_eq!(band.into_iter().last(), Some(Frequency::from_mhz(110)));
+
+    let tiny = FrequencyRange::new(Frequency::from_hz(1), Frequency::from_hz(3));
+    assert_eq!(
+        (&tiny).into_iter().rev().collect::<Vec<_>>(),
+        [3, 2, 1].map(Frequency::from_hz)
+    );
+
+    let mut both_ends = tiny.iter();
+    assert_eq!(both_ends.next(), Some(Frequency::from_hz(1)));
+    assert_eq!(both_ends.next_back(), Some(Frequency::from_hz(3)));
+    assert_eq!(both_ends.next(), Some(Frequency::from_hz(2)));
+    assert_eq!(both_ends.next_back(), None);
+    assert_eq!(both_ends.next(), None);
+
+    let half_open = FrequencyRangeIter::from(Frequency::from_hz(5)..Frequency::from_hz(8));
+    assert_eq!(
+        half_open.collect::<Vec<_>>(),
+        [5, 6, 7].map(Frequency::from_hz)
+    );
+    assert_eq!(
+        FrequencyRangeIter::from(Frequency::from_hz(5)..Frequency::from_hz(5)).next(),
+        None
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = FrequencyRangeIter::from(Frequency::from_hz(8)..=Frequency::from_hz(5));
+    assert_eq!(reversed.size_hint(), (0, Some(0)));
+
+    let mut top = FrequencyRangeIter::from(Frequency::ZERO..=Frequency(u64::MAX));
+    assert_eq!(top.size_hint(), (usize::MAX, None));
+    assert_eq!(top.next_back(), Some(Frequency(u64::MAX)));
+    assert_eq!(
+        top.nth(usize::MAX - 1),
+        Some(Frequency(usize::MAX as u64 - 1))
+    );
+    assert_eq!(top.next().is_some(), usize::BITS < 64);
+}
+
+#[test]
+fn test_range_arithmetic() {
+    use crate::{Frequency, FrequencyRange, FrequencyShift};
+
+    let range = FrequencyRange::new(Frequency::from_hz(100), Frequency::from_hz(200));
+
+    assert_eq!(
+        range.shift_by(FrequencyShift(50.4)),
+        FrequencyRange::new(Frequency::from_hz(150), Frequency::from_hz(250))
+    );
+    assert_eq!(
+        range.shift_by(FrequencyShift(-150.0)),
+        FrequencyRange::new(Frequency::ZERO, Frequency::from_hz(50))
+    );
+
+    assert_eq!(
+        range.widen_by(Frequency::from_hz(21)),
+        FrequencyRange::new(Frequency::from_hz(90), Frequency::from_hz(211))
+    );
+    assert_eq!(
+        range.widen_by(Frequency::from_hz(1_000)).min,
+        Frequency::ZERO
+    );
+    let top = FrequencyRange::new(Frequency(u64::MAX - 1), Frequency(u64::MAX));
+    assert_eq!(
+        top.widen_by(Frequency::from_hz(10)).max,
+        Frequency(u64::MAX)
+    );
+
+    let parts: Vec<_> = range.split_into(3).collect();
+    assert_eq!(
+        parts,
+        [
+            FrequencyRange::new(Frequency::from_hz(100), Frequency::from_hz(133)),
+            FrequencyRange::new(Frequency::from_hz(133), Frequency::from_hz(166)),
+            FrequencyRange::new(Frequency::from_hz(166), Frequency::from_hz(200)),
+        ]
+    );
+    assert_eq!(range.split_into(0).count(), 0);
+    assert_eq!(
+        FrequencyRange::new(Frequency::ZERO, Frequency(u64::MAX))
+            .split_into(2)
+            .last()
+            .unwrap()
+            .max,
+        Frequency(u64::MAX)
+    );
+
+    let steps: Vec<u64> = range
+        .step_channels(Frequency::from_hz(30))
+        .map(|freq| freq.0)
+        .collect();
+    assert_eq!(steps, [100, 130, 160, 190]);
+    assert_eq!(range.step_channels(Frequency::ZERO).count(), 0);
+    assert_eq!(top.step_channels(Frequency::from_hz(1)).count(), 2);
+}
+
+#[test]
+fn test_clock_constants() {
+    use crate::{Frequency, SampleRate, consts};
+
+    assert_eq!(consts::WATCH_CRYSTAL / 32_768, Frequency::HERTZ);
+    assert_eq!(consts::CRYSTAL_25MHZ, Frequency::from_mhz(25));
+    assert_eq!(consts::VIDEO_27MHZ, Frequency::from_mhz(27));
+    assert_eq!(consts::AUDIO_44K1, SampleRate::KHZ_44_1.as_frequency());
+    assert_eq!(consts::AUDIO_48K, SampleRate::KHZ_48.as_frequency());
+    assert_eq!(consts::NFC.to_string(), "13.56 MHz");
+
+    // The GPS carriers are integer multiples of the 10.23 MHz fundamental
+    assert_eq!(consts::GPS_CA_CHIP_RATE * 1_540, consts::GPS_L1);
+    assert_eq!(consts::GPS_CA_CHIP_RATE * 1_200, consts::GPS_L2);
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_hz() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde::hz")]
+        clock: Frequency,
+    }
+
+    let config = Config {
+        clock: Frequency::from_hz(2_400_000_001),
+    };
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(json, r#"{"clock":2400000001}"#);
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    let bytes = postcard::to_allocvec(&config).unwrap();
+    assert_eq!(postcard::from_bytes::<Config>(&bytes).unwrap(), config);
+
+    let old: Config = serde_json::from_str(r#"{"clock":"1.5 kHz"}"#).unwrap();
+    assert_eq!(old.clock, Frequency::from_hz(1_500));
+
+    assert!(serde_json::from_str::<Config>(r#"{"clock":-1}"#).is_err());
+    assert!(serde_json::from_str::<Config>(r#"{"clock":"fast"}"#).is_err());
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_compact() {
+    let freq = Frequency::from_hz(2_400_000_001);
+
+    // Compact formats store the exact value as an integer
+    let bytes = postcard::to_allocvec(&freq).unwrap();
+    assert_eq!(bytes, postcard::to_allocvec(&2_400_000_001u64).unwrap());
+    assert_eq!(postcard::from_bytes::<Frequency>(&bytes).unwrap(), freq);
+
+    // Human-readable formats keep using strings
+    assert_eq!(serde_json::to_string(&freq).unwrap(), "\"2.400000001 GHz\"");
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_with_modules() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Radio {
+        #[serde(with = "crate::serde::khz")]
+        step: Frequency,
+        #[serde(with = "crate::serde::mhz_float")]
+        tune: Frequency,
+        #[serde(with = "crate::serde::string")]
+        label: Frequency,
+    }
+
+    let radio = Radio {
+        step: Frequency::from_hz(12_000),
+        tune: Frequency::from_khz(145_500),
+        label: Frequency::from_mhz(2),
+    };
+    let json = serde_json::to_string(&radio).unwrap();
+    assert_eq!(json, r#"{"step":12,"tune":145.5,"label":"2.00 MHz"}"#);
+    assert_eq!(serde_json::from_str::<Radio>(&json).unwrap(), radio);
+
+    // Strings are kept in compact formats too
+    let bytes = postcard::to_allocvec(&radio).unwrap();
+    assert_eq!(postcard::from_bytes::<Radio>(&bytes).unwrap(), radio);
+
+    let fractional = Radio {
+        step: Frequency::from_hz(12_500),
+        ..radio
+    };
+    assert!(serde_json::to_string(&fractional).is_err());
+
+    let parsed: Radio = serde_json::from_str(r#"{"step":12.5,"tune":7,"label":"1 kHz"}"#).unwrap();
+    assert_eq!(parsed.step, Frequency::from_hz(12_500));
+    assert_eq!(parsed.tune, Frequency::from_mhz(7));
+    assert_eq!(parsed.label, Frequency::from_khz(1));
+
+    assert!(serde_json::from_str::<Radio>(r#"{"step":-1,"tune":7,"label":"1 kHz"}"#).is_err());
+    assert!(serde_json::from_str::<Radio>(r#"{"step":1,"tune":-7.5,"label":"1 kHz"}"#).is_err());
+    assert!(serde_json::from_str::<Radio>(r#"{"step":1,"tune":7,"label":1000}"#).is_err());
+}
+
+#[test]
+fn test_exact_string() {
+    use crate::FrequencyOf;
+
+    assert_eq!(Frequency::ZERO.to_exact_string(), "0 Hz");
+    assert_eq!(Frequency::from_hz(999).to_exact_string(), "999 Hz");
+    assert_eq!(Frequency::from_hz(1_500).to_exact_string(), "1.5 kHz");
+    assert_eq!(
+        Frequency::from_hz(12_345_678).to_exact_string(),
+        "12.345678 MHz"
+    );
+    assert_eq!(
+        Frequency::from_hz(1_000_001).to_exact_string(),
+        "1.000001 MHz"
+    );
+    assert_eq!(
+        Frequency(u64::MAX).to_exact_string(),
+        "18446744073.709551615 GHz"
+    );
+    assert_eq!(FrequencyOf(u32::MAX).to_exact_string(), "4.294967295 GHz");
+
+    // Plain decimals parse exactly, even beyond the precision of an f64
+    for hz in [1, 1_500, 2_400_000_001, u64::MAX - 1, u64::MAX] {
+        let freq = Frequency::from_hz(hz);
+        assert_eq!(freq.to_exact_string().parse(), Ok(freq));
+    }
+    assert_eq!("1.5e3 Hz".parse(), Ok(Frequency::from_hz(1_500)));
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_auxiliary_types() {
+    use crate::{
+        FrequencyF64, FrequencyMilliHz, FrequencyRange, FrequencyShift, RationalFrequency,
+        SampleRate,
+    };
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        band: FrequencyRange,
+        offset: FrequencyShift,
+        sample_rate: SampleRate,
+        tone: FrequencyF64,
+        grid: FrequencyMilliHz,
+        frame_rate: RationalFrequency,
+    }
+
+    let config = Config {
+        band: FrequencyRange::new(
+            Frequency::from_mhz(2_400),
+            Frequency::from_hz(2_483_500_000),
+        ),
+        offset: FrequencyShift(-12.5),
+        sample_rate: SampleRate::KHZ_44_1,
+        tone: FrequencyF64(440.5),
+        grid: FrequencyMilliHz(50_020),
+        frame_rate: RationalFrequency::new(30_000, 1_001).unwrap(),
+    };
+
+    let json = serde_json::to_string(&config).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"band":{"min":"2.4 GHz","max":"2.4835 GHz"},"offset":-12.5,"#,
+            r#""sample_rate":"44.1 kHz","tone":440.5,"grid":50020,"frame_rate":"30000/1001 Hz"}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+
+    let bytes = postcard::to_allocvec(&config).unwrap();
+    assert_eq!(postcard::from_bytes::<Config>(&bytes).unwrap(), config);
+
+    // Reversed bounds are swapped like in `FrequencyRange::new`
+    let band: FrequencyRange = serde_json::from_str(r#"{"max":"1 kHz","min":"2 kHz"}"#).unwrap();
+    assert_eq!(
+        band,
+        FrequencyRange::new(Frequency::from_khz(1), Frequency::from_khz(2))
+    );
+
+    assert!(serde_json::from_str::<FrequencyRange>(r#"{"min":"1 kHz"}"#).is_err());
+    assert!(
+        serde_json::from_str::<FrequencyRange>(r#"{"min":"1 kHz","max":"1 kHz","mid":"1 kHz"}"#)
+            .is_err()
+    );
+    assert!(serde_json::from_str::<RationalFrequency>(r#""1/0 Hz""#).is_err());
+}
+
+#[cfg(feature = "schemars")]
+#[test]
+fn test_json_schema() {
+    use regex::Regex;
+
+    let schema = serde_json::to_value(schemars::schema_for!(Frequency)).unwrap();
+    let [hertz, string] = schema["oneOf"].as_array().unwrap().as_slice() else {
+        panic!("expected two alternatives");
+    };
+    assert_eq!(hertz["type"], "integer");
+    assert_eq!(hertz["minimum"], 0.0);
+    assert_eq!(string["type"], "string");
+    assert_eq!(string["examples"][0], "2.4 GHz");
+
+    // The pattern accepts exactly what the parser accepts
+    let pattern = Regex::new(string["pattern"].as_str().unwrap()).unwrap();
+    for valid in [
+        "2.4 GHz", "100kHz", " 440 hz ", ".5 MHZ", "1e3 Hz", "+7Hz", "5. kHz",
+    ] {
+        assert!(pattern.is_match(valid), "{valid}");
+        assert!(valid.parse::<Frequency>().is_ok(), "{valid}");
+    }
+    for invalid in [
+        "-1 Hz", "1 THz", "Hz", "1.2.3 Hz", "inf Hz", "1 rpm", "1 GHz x",
+    ] {
+        assert!(!pattern.is_match(invalid), "{invalid}");
+        assert!(invalid.parse::<Frequency>().is_err(), "{invalid}");
+    }
+}
+
+#[cfg(feature = "schemars1")]
+#[test]
+fn test_json_schema_1() {
+    use regex::Regex;
+
+    let schema = schemars1::schema_for!(Frequency);
+    let one_of = schema.get("oneOf").unwrap();
+    assert_eq!(one_of[0]["type"], "integer");
+    assert_eq!(one_of[1]["type"], "string");
+    assert_eq!(one_of[1]["examples"][1], "100 kHz");
+
+    let pattern = Regex::new(one_of[1]["pattern"].as_str().unwrap()).unwrap();
+    assert!(pattern.is_match("2.4 GHz"));
+    assert!(!pattern.is_match("-2.4 GHz"));
+}
+
+#[cfg(feature = "serde_with")]
+#[test]
+fn test_serde_with_adapters() {
+    use crate::serde::{FrequencyAsHz, FrequencyAsKhz, FrequencyAsMhzF64, FrequencyAsString};
+    use serde::{Deserialize, Serialize};
+    use serde_with::serde_as;
+    use std::collections::BTreeMap;
+
+    #[serde_as]
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Plan {
+        #[serde_as(as = "Vec<FrequencyAsHz>")]
+        channels: Vec<Frequency>,
+        #[serde_as(as = "Option<FrequencyAsKhz>")]
+        step: Option<Frequency>,
+        #[serde_as(as = "BTreeMap<_, FrequencyAsMhzF64>")]
+        named: BTreeMap<String, Frequency>,
+        #[serde_as(as = "FrequencyAsString")]
+        label: Frequency,
+    }
+
+    let plan = Plan {
+        channels: vec![
+            Frequency::from_hz(2_412_000_001),
+            Frequency::from_mhz(2_417),
+        ],
+        step: Some(Frequency::from_khz(5_000)),
+        named: BTreeMap::from([("calling".to_string(), Frequency::from_khz(145_500))]),
+        label: Frequency::from_ghz(2),
+    };
+
+    let json = serde_json::to_string(&plan).unwrap();
+    assert_eq!(
+        json,
+        concat!(
+            r#"{"channels":[2412000001,2417000000],"step":5000,"#,
+            r#""named":{"calling":145.5},"label":"2.00 GHz"}"#
+        )
+    );
+    assert_eq!(serde_json::from_str::<Plan>(&json).unwrap(), plan);
+
+    let bytes = postcard::to_allocvec(&plan).unwrap();
+    assert_eq!(postcard::from_bytes::<Plan>(&bytes).unwrap(), plan);
+}
+
+#[test]
+fn test_frequency_unit() {
+    use crate::FrequencyUnit;
+
+    assert_eq!(
+        "khz".parse::<FrequencyUnit>().unwrap(),
+        FrequencyUnit::Kilohertz
+    );
+    assert_eq!(
+        " GHz ".parse::<FrequencyUnit>().unwrap(),
+        FrequencyUnit::Gigahertz
+    );
+    assert!("THz".parse::<FrequencyUnit>().is_err());
+    assert_eq!(FrequencyUnit::Megahertz.to_string(), "MHz");
+
+    assert_eq!(
+        FrequencyUnit::for_frequency(Frequency::ZERO),
+        FrequencyUnit::Hertz
+    );
+    assert_eq!(
+        FrequencyUnit::for_frequency(Frequency::from_hz(999)),
+        FrequencyUnit::Hertz
+    );
+    assert_eq!(
+        FrequencyUnit::for_frequency(Frequency::from_khz(1)),
+        FrequencyUnit::Kilohertz
+    );
+    assert_eq!(
+        FrequencyUnit::for_frequency(Frequency::from_ghz(3)),
+        FrequencyUnit::Gigahertz
+    );
+}
+
+#[cfg(feature = "serde")]
+#[test]
+fn test_serde_structured() {
+    use serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Channel {
+        #[serde(with = "crate::serde::structured")]
+        center: Frequency,
+    }
+
+    let channel = Channel {
+        center: Frequency::from_hz(2_400_000_001),
+    };
+    let json = serde_json::to_string(&channel).unwrap();
+    assert_eq!(json, r#"{"center":{"value":2.400000001,"unit":"GHz"}}"#);
+    assert_eq!(serde_json::from_str::<Channel>(&json).unwrap(), channel);
+
+    let small: Channel = serde_json::from_str(r#"{"center":{"unit":"hz","value":50}}"#).unwrap();
+    assert_eq!(small.center, Frequency::from_hz(50));
+
+    for invalid in [
+        r#"{"center":{"value":1,"unit":"THz"}}"#,
+        r#"{"center":{"value":-1,"unit":"Hz"}}"#,
+        r#"{"center":{"value":1}}"#,
+        r#"{"center":{"unit":"Hz"}}"#,
+        r#"{"center":{"value":1,"unit":"Hz","extra":0}}"#,
+        r#"{"center":{"value":1,"value":2,"unit":"Hz"}}"#,
+    ] {
+        assert!(
+            serde_json::from_str::<Channel>(invalid).is_err(),
+            "{invalid}"
+        );
+    }
+
+    let bytes = postcard::to_allocvec(&channel).unwrap();
+    assert_eq!(postcard::from_bytes::<Channel>(&bytes).unwrap(), channel);
+}
+
+#[test]
+fn test_binary_codec() {
+    use crate::VARINT_MAX_LEN;
+
+    let freq = Frequency::from_hz(2_400_000_001);
+    assert_eq!(freq.to_le_bytes(), 2_400_000_001u64.to_le_bytes());
+    assert_eq!(Frequency::from_le_bytes(freq.to_le_bytes()), freq);
+
+    let mut buf = [0; VARINT_MAX_LEN];
+    for hz in [0, 1, 127, 128, 300, 16_383, 16_384, 2_400_000_001, u64::MAX] {
+        let freq = Frequency::from_hz(hz);
+        let len = freq.encode_varint(&mut buf).unwrap();
+        assert_eq!(len, freq.varint_len());
+        assert_eq!(Frequency::decode_varint(&buf[..len]), Some((freq, len)));
+    }
+
+    assert_eq!(Frequency::ZERO.encode_varint(&mut buf), Some(1));
+    assert_eq!(buf[0], 0);
+    assert_eq!(Frequency::from_hz(300).encode_varint(&mut buf), Some(2));
+    assert_eq!(buf[..2], [0xac, 0x02]);
+    assert_eq!(Frequency::from_hz(u64::MAX).varint_len(), VARINT_MAX_LEN);
+
+    // Too short buffers are left untouched
+    let mut short = [0xff; 1];
+    assert_eq!(Frequency::from_hz(300).encode_varint(&mut short), None);
+    assert_eq!(short, [0xff]);
+
+    // Trailing bytes are not consumed
+    assert_eq!(
+        Frequency::decode_varint(&[0xac, 0x02, 0xff]),
+        Some((Frequency::from_hz(300), 2))
+    );
+
+    // Truncated and overflowing input
+    assert_eq!(Frequency::decode_varint(&[]), None);
+    assert_eq!(Frequency::decode_varint(&[0x80]), None);
+    assert_eq!(
+        Frequency::decode_varint(&[0xff; 9].iter().copied().chain([0x02]).collect::<Vec<_>>()),
+        None
+    );
+    assert_eq!(Frequency::decode_varint(&[0xff; 11]), None);
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn test_clap_derive() {
+    use clap::Parser;
+
+    #[derive(Parser)]
+    struct Args {
+        #[arg(long)]
+        frequency: Frequency,
+        #[arg(long, value_parser)]
+        step: Option<Frequency>,
+    }
+
+    let args = Args::try_parse_from(["app", "--frequency", "2.4GHz", "--step", "25 kHz"]).unwrap();
+    assert_eq!(args.frequency, Frequency::from_mhz(2_400));
+    assert_eq!(args.step, Some(Frequency::from_khz(25)));
+
+    assert!(Args::try_parse_from(["app", "--frequency", "fast"]).is_err());
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn test_clap_error_message() {
+    use clap::{Arg, Command, error::ErrorKind};
+
+    let cmd = Command::new("app").arg(
+        Arg::new("frequency")
+            .long("frequency")
+            .value_parser(clap::value_parser!(Frequency)),
+    );
+
+    let err = cmd
+        .try_get_matches_from(["app", "--frequency", "fast"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+
+    let message = err.to_string();
+    assert!(message.contains("'fast'"), "{message}");
+    assert!(message.contains("--frequency"), "{message}");
+    assert!(message.contains("2.4 GHz, 100 kHz or 440Hz"), "{message}");
+}
+
+#[cfg(feature = "clap")]
+#[test]
+fn test_clap_constrained_values() {
+    use crate::{FrequencyRange, FrequencyValueParser};
+    use clap::{Arg, Command, ValueHint, builder::TypedValueParser, error::ErrorKind};
+
+    let rates =
+        FrequencyValueParser::new().allowed([Frequency::from_hz(44_100), Frequency::from_khz(48)]);
+    let names: Vec<_> = rates
+        .possible_values()
+        .unwrap()
+        .map(|value| value.get_name().to_string())
+        .collect();
+    assert_eq!(names, ["44.1kHz", "48kHz"]);
+    assert!(FrequencyValueParser::new().possible_values().is_none());
+
+    // Free-form arguments leave completion to the value hint, which keeps shells off file names
+    let free = Command::new("app").arg(
+        Arg::new("freq")
+            .long("freq")
+            .value_hint(ValueHint::Other)
+            .value_parser(FrequencyValueParser::new()),
+    );
+    let arg = free.get_arguments().next().unwrap();
+    assert!(arg.get_possible_values().is_empty());
+    assert_eq!(arg.get_value_hint(), ValueHint::Other);
+    let help = free.clone().render_long_help().to_string();
+    assert!(!help.contains("possible values"), "{help}");
+
+    let mut constrained = Command::new("app").arg(Arg::new("rate").long("rate").value_parser(
+        FrequencyValueParser::new().allowed([Frequency::from_khz(48), Frequency::from_khz(96)]),
+    ));
+    let arg = constrained.get_arguments().next().unwrap();
+    let completions: Vec<_> = arg
+        .get_possible_values()
+        .iter()
+        .map(|value| value.get_name().to_string())
+        .collect();
+    assert_eq!(completions, ["48kHz", "96kHz"]);
+    let help = constrained.render_long_help().to_string();
+    assert!(help.contains("[possible values: 48kHz, 96kHz]"), "{help}");
+
+    let cmd = Command::new("app")
+        .arg(Arg::new("rate").long("rate").value_parser(rates))
+        .arg(
+            Arg::new("tune")
+                .long("tune")
+                .value_parser(FrequencyValueParser::new().range(FrequencyRange::new(
+                    Frequency::from_mhz(88),
+                    Frequency::from_mhz(108),
+                ))),
+        );
+
+    let matches = cmd
+        .clone()
+        .try_get_matches_from(["app", "--rate", "48000 Hz", "--tune", "100.1 MHz"])
+        .unwrap();
+    assert_eq!(
+        matches.get_one::<Frequency>("rate"),
+        Some(&Frequency::from_khz(48))
+    );
+    assert_eq!(
+        matches.get_one::<Frequency>("tune"),
+        Some(&Frequency::from_khz(100_100))
+    );
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["app", "--rate", "96kHz"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    assert!(err.to_string().contains("44.1kHz, 48kHz"), "{err}");
+
+    let err = cmd
+        .clone()
+        .try_get_matches_from(["app", "--tune", "2.4 GHz"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("from 88 MHz to 108 MHz"), "{err}");
+
+    let err = cmd
+        .try_get_matches_from(["app", "--rate", "fast"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+}
+
+#[cfg(feature = "rand")]
+#[test]
+fn test_rand_sampling() {
+    use rand::{Rng, SeedableRng, rngs::StdRng};
+
+    let mut rng = StdRng::seed_from_u64(7);
+    let (low, high) = (Frequency::from_mhz(80), Frequency::from_mhz(110));
+
+    for _ in 0..1_000 {
+        let freq = rng.gen_range(low..high);
+        assert!(low <= freq && freq < high);
+
+        let freq = rng.gen_range(low..=high);
+        assert!(low <= freq && freq <= high);
+    }
+
+    assert_eq!(rng.gen_range(low..=low), low);
+
+    let samples: Vec<Frequency> = (0..16).map(|_| rng.r#gen()).collect();
+    assert!(samples.iter().any(|freq| *freq != samples[0]));
+}
+
+#[cfg(feature = "arbitrary")]
+#[test]
+fn test_arbitrary_input() {
+    use crate::{Error, FrequencyInput, FrequencyRange};
+    use arbitrary::{Arbitrary, Unstructured};
+
+    let data: Vec<u8> = (0..4_096u32)
+        .map(|i| (i.wrapping_mul(2_654_435_761) >> 13) as u8)
+        .collect();
+    let mut u = Unstructured::new(&data);
+
+    let mut parsed = 0;
+    while !u.is_empty() {
+        let input = FrequencyInput::arbitrary(&mut u).unwrap();
+        match input.0.parse::<Frequency>() {
+            Ok(freq) => {
+                parsed += 1;
+                assert_eq!(freq.to_exact_string().parse::<Frequency>(), Ok(freq));
+            }
+            Err(err) => assert!(matches!(err, Error::InvalidValue(_)), "{input:?}: {err}"),
+        }
+    }
+    assert!(parsed > 0);
+
+    let mut u = Unstructured::new(&[1, 2, 3, 4, 5, 6, 7, 8, 0, 0, 0, 0, 0, 0, 0, 0]);
+    let range = FrequencyRange::arbitrary(&mut u).unwrap();
+    assert!(range.min <= range.max);
+}
+
+#[cfg(feature = "proptest")]
+#[test]
+fn test_proptest_strategies() {
+    use crate::{
+        FrequencyRange,
+        proptest::{frequency_in_range, frequency_string, frequency_string_with_value},
+    };
+    use proptest::prelude::*;
+
+    let band = FrequencyRange::new(Frequency::from_hz(10), Frequency::from_hz(12));
+
+    proptest!(|(freq in frequency_in_range(band))| {
+        prop_assert!(band.contains(freq));
+    });
+
+    proptest!(|((s, freq) in frequency_string_with_value())| {
+        prop_assert_eq!(s.parse::<Frequency>(), Ok(freq));
+    });
+
+    proptest!(|(s in frequency_string())| {
+        prop_assert!(s.parse::<Frequency>().is_ok());
+    });
+
+    proptest!(|(freq in any::<Frequency>())| {
+        prop_assert_eq!(freq.to_exact_string().parse::<Frequency>(), Ok(freq));
+    });
+}
+
+#[cfg(feature = "quickcheck")]
+#[test]
+fn test_quickcheck_shrinking() {
+    use crate::FrequencyShift;
+    use quickcheck::{Arbitrary, Gen, QuickCheck};
+
+    let shrunk: Vec<_> = Frequency::from_hz(2_437_512_345).shrink().take(4).collect();
+    assert_eq!(
+        shrunk,
+        [
+            Frequency::ZERO,
+            Frequency::from_ghz(2),
+            Frequency::from_mhz(2_437),
+            Frequency::from_khz(2_437_512),
+        ]
+    );
+    assert!(Frequency::ZERO.shrink().next().is_none());
+    assert!(
+        Frequency::from_hz(999)
+            .shrink()
+            .all(|freq| freq < Frequency::from_hz(999))
+    );
+
+    let shrunk: Vec<_> = FrequencyShift(-1_500.25).shrink().take(3).collect();
+    assert_eq!(
+        shrunk,
+        [
+            FrequencyShift(0.0),
+            FrequencyShift(-1_000.0),
+            FrequencyShift(-1_500.0)
+        ]
+    );
+
+    let mut g = Gen::new(100);
+    assert!((0..100).all(|_| FrequencyShift::arbitrary(&mut g).0.is_finite()));
+
+    fn round_trips(freq: Frequency) -> bool {
+        freq.to_exact_string().parse::<Frequency>() == Ok(freq)
+    }
+    QuickCheck::new().quickcheck(round_trips as fn(Frequency) -> bool);
+}
+
+#[cfg(feature = "bytemuck")]
+#[test]
+fn test_bytemuck_casts() {
+    use crate::{FrequencyF64, FrequencyMilliHz, FrequencyOf, SampleRate};
+    use bytemuck::TransparentWrapper;
+
+    let raw = [1_000u64, 2_400_000_000];
+    let freqs: &[Frequency] = bytemuck::cast_slice(&raw);
+    assert_eq!(freqs, [Frequency::from_khz(1), Frequency::from_mhz(2_400)]);
+    assert_eq!(bytemuck::cast_slice::<Frequency, u64>(freqs), raw);
+
+    let freqs = Frequency::wrap_slice(&raw);
+    assert_eq!(freqs[0], Frequency::from_khz(1));
+
+    let small: &[FrequencyOf<u32>] = bytemuck::cast_slice(&[48_000u32, 44_100]);
+    assert_eq!(small[1], FrequencyOf(44_100u32));
+
+    let rates: &[SampleRate] = bytemuck::cast_slice(&raw[..1]);
+    assert_eq!(rates[0].0, Frequency::from_khz(1));
+
+    assert_eq!(
+        bytemuck::cast::<u64, FrequencyMilliHz>(50_020).as_millihertz(),
+        50_020
+    );
+    assert_eq!(bytemuck::cast::<f64, FrequencyF64>(440.5).as_hz(), 440.5);
+    assert_eq!(
+        bytemuck::bytes_of(&Frequency::from_khz(1)),
+        1_000u64.to_ne_bytes()
+    );
+    assert_eq!(<Frequency as bytemuck::Zeroable>::zeroed(), Frequency::ZERO);
+}
+
+#[cfg(feature = "zerocopy")]
+#[test]
+fn test_zerocopy_layout() {
+    use crate::UnalignedFrequency;
+    use zerocopy::{BigEndian, FromBytes, IntoBytes, LittleEndian};
+
+    let freq = Frequency::from_khz(1);
+    assert_eq!(freq.as_bytes(), 1_000u64.to_ne_bytes());
+    assert_eq!(
+        Frequency::read_from_bytes(&1_000u64.to_ne_bytes()),
+        Ok(freq)
+    );
+
+    let freqs = <[Frequency]>::ref_from_bytes(aligned_words()).unwrap();
+    assert_eq!(freqs, [Frequency::from_hz(1), Frequency::from_hz(2)]);
+
+    let mut big = UnalignedFrequency::<BigEndian>::new(freq);
+    assert_eq!(big.as_bytes(), 1_000u64.to_be_bytes());
+    big.set(Frequency::from_mhz(2_400));
+    assert_eq!(Frequency::from(big), Frequency::from_mhz(2_400));
+
+    let little = UnalignedFrequency::<LittleEndian>::from(freq);
+    assert_eq!(little.as_bytes(), freq.to_le_bytes());
+    assert_eq!(core::mem::align_of::<UnalignedFrequency<LittleEndian>>(), 1);
+
+    // Unaligned frequencies can be read from any offset
+    let mut packet = [0u8; 9];
+    packet[1..].copy_from_slice(&freq.to_le_bytes());
+    let read = UnalignedFrequency::<LittleEndian>::ref_from_bytes(&packet[1..]).unwrap();
+    assert_eq!(read.get(), freq);
+
+    fn aligned_words() -> &'static [u8] {
+        static WORDS: [u64; 2] = [1, 2];
+        WORDS.as_bytes()
+    }
+}
+
+#[cfg(feature = "rkyv")]
+#[test]
+fn test_rkyv_archive() {
+    use crate::ArchivedFrequency;
+    use rkyv::{Archived, rancor::Error};
+
+    assert_eq!(
+        core::mem::size_of::<ArchivedFrequency>(),
+        core::mem::size_of::<Archived<u64>>()
+    );
+    assert_eq!(
+        core::mem::align_of::<ArchivedFrequency>(),
+        core::mem::align_of::<Archived<u64>>()
+    );
+
+    let freq = Frequency::from_hz(2_400_000_001);
+    let bytes = rkyv::to_bytes::<Error>(&freq).unwrap();
+    assert_eq!(
+        bytes.as_slice(),
+        rkyv::to_bytes::<Error>(&2_400_000_001u64)
+            .unwrap()
+            .as_slice()
+    );
+
+    let archived = rkyv::access::<ArchivedFrequency, Error>(&bytes).unwrap();
+    assert_eq!(*archived, freq);
+    assert_eq!(
+        rkyv::deserialize::<Frequency, Error>(archived).unwrap(),
+        freq
+    );
+
+    let sweep: Vec<Frequency> = (1..=100).map(Frequency::from_khz).collect();
+    let bytes = rkyv::to_bytes::<Error>(&sweep).unwrap();
+    let archived = rkyv::access::<Archived<Vec<Frequency>>, Error>(&bytes).unwrap();
+    assert_eq!(archived.len(), 100);
+    assert_eq!(archived[99].get(), Frequency::from_khz(100));
+    assert_eq!(
+        rkyv::deserialize::<Vec<Frequency>, Error>(archived).unwrap(),
+        sweep
+    );
+}
+
+#[cfg(feature = "borsh")]
+#[test]
+fn test_borsh_roundtrip() {
+    use crate::FrequencyOf;
+
+    let freq = Frequency::from_hz(2_400_000_001);
+    let bytes = borsh::to_vec(&freq).unwrap();
+    assert_eq!(bytes, 2_400_000_001u64.to_le_bytes());
+    assert_eq!(borsh::from_slice::<Frequency>(&bytes).unwrap(), freq);
+
+    let small = borsh::to_vec(&FrequencyOf(48_000u32)).unwrap();
+    assert_eq!(small, 48_000u32.to_le_bytes());
+    assert_eq!(
+        borsh::from_slice::<FrequencyOf<u32>>(&small).unwrap(),
+        FrequencyOf(48_000u32)
+    );
+
+    let plan = vec![Frequency::from_mhz(2_412), Frequency::from_mhz(2_437)];
+    let bytes = borsh::to_vec(&plan).unwrap();
+    assert_eq!(borsh::from_slice::<Vec<Frequency>>(&bytes).unwrap(), plan);
+
+    assert!(borsh::from_slice::<Frequency>(&[0; 7]).is_err());
+}
+
+#[cfg(feature = "bincode")]
+#[test]
+fn test_bincode_encoding() {
+    use bincode::{Decode, Encode};
+
+    #[derive(Debug, PartialEq, Encode, Decode)]
+    struct Channel {
+        number: u16,
+        center: Frequency,
+    }
+
+    fn check(config: impl bincode::config::Config) {
+        let bytes = bincode::encode_to_vec(Frequency::from_hz(300), config).unwrap();
+        assert_eq!(bytes, 300u64.to_le_bytes());
+
+        let channel = Channel {
+            number: 6,
+            center: Frequency::from_mhz(2_437),
+        };
+        let bytes = bincode::encode_to_vec(&channel, config).unwrap();
+        let (decoded, read): (Channel, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, channel);
+        assert_eq!(read, bytes.len());
+    }
+
+    check(bincode::config::standard());
+    check(bincode::config::standard().with_fixed_int_encoding());
+
+    let truncated =
+        bincode::decode_from_slice::<Frequency, _>(&[0; 7], bincode::config::standard());
+    assert!(truncated.is_err());
+}
+
+#[cfg(feature = "sqlx")]
+#[tokio::test]
+async fn test_sqlx_sqlite() {
+    use sqlx::{Connection, Row, SqliteConnection};
+
+    let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+    sqlx::query("CREATE TABLE radios (name TEXT, tune BIGINT)")
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    sqlx::query("INSERT INTO radios VALUES ('wifi', ?), ('legacy', '145.5 MHz')")
+        .bind(Frequency::from_hz(2_412_000_001))
+        .execute(&mut conn)
+        .await
+        .unwrap();
+
+    let rows = sqlx::query("SELECT tune FROM radios ORDER BY name")
+        .fetch_all(&mut conn)
+        .await
+        .unwrap();
+    let tunes: Vec<Frequency> = rows.iter().map(|row| row.get("tune")).collect();
+    assert_eq!(
+        tunes,
+        [
+            Frequency::from_khz(145_500),
+            Frequency::from_hz(2_412_000_001)
+        ]
+    );
+
+    let raw: i64 = sqlx::query_scalar("SELECT tune FROM radios WHERE name = 'wifi'")
+        .fetch_one(&mut conn)
+        .await
+        .unwrap();
+    assert_eq!(raw, 2_412_000_001);
+
+    // Negative numbers, unparseable text and values above i64::MAX are rejected
+    let negative = sqlx::query_scalar::<_, Frequency>("SELECT -1")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(negative.is_err());
+
+    let text = sqlx::query_scalar::<_, Frequency>("SELECT 'fast'")
+        .fetch_one(&mut conn)
+        .await;
+    assert!(text.is_err());
+
+    let huge = sqlx::query("INSERT INTO radios VALUES ('huge', ?)")
+        .bind(Frequency::from_hz(u64::MAX))
+        .execute(&mut conn)
+        .await;
+    assert!(huge.is_err());
+}
+
+#[cfg(feature = "diesel")]
+#[test]
+fn test_diesel_sqlite() {
+    use diesel::{prelude::*, sql_types::BigInt};
+
+    diesel::table! {
+        radios (id) {
+            id -> Integer,
+            tune -> BigInt,
+        }
+    }
+
+    #[derive(Debug, PartialEq, Queryable, Insertable)]
+    #[diesel(table_name = radios)]
+    struct Radio {
+        id: i32,
+        tune: Frequency,
+    }
+
+    let mut conn = SqliteConnection::establish(":memory:").unwrap();
+    diesel::sql_query("CREATE TABLE radios (id INTEGER PRIMARY KEY, tune BIGINT NOT NULL)")
+        .execute(&mut conn)
+        .unwrap();
+
+    let radio = Radio {
+        id: 1,
+        tune: Frequency::from_hz(2_412_000_001),
+    };
+    diesel::insert_into(radios::table)
+        .values(&radio)
+        .execute(&mut conn)
+        .unwrap();
+
+    let loaded: Radio = radios::table
+        .filter(radios::tune.eq(Frequency::from_hz(2_412_000_001)))
+        .first(&mut conn)
+        .unwrap();
+    assert_eq!(loaded, radio);
+
+    let raw: i64 = radios::table.select(radios::tune).first(&mut conn).unwrap();
+    assert_eq!(raw, 2_412_000_001);
+
+    let negative = diesel::select((-1i64).into_sql::<BigInt>()).get_result::<Frequency>(&mut conn);
+    assert!(negative.is_err());
+
+    let huge = diesel::insert_into(radios::table)
+        .values(&Radio {
+            id: 2,
+            tune: Frequency::from_hz(u64::MAX),
+        })
+        .execute(&mut conn);
+    assert!(huge.is_err());
+}
+
+#[cfg(feature = "rusqlite")]
+#[test]
+fn test_rusqlite_columns() {
+    use rusqlite::Connection;
+
+    let conn = Connection::open_in_memory().unwrap();
+    conn.execute_batch("CREATE TABLE radios (name TEXT, tune)")
+        .unwrap();
+    conn.execute(
+        "INSERT INTO radios VALUES ('wifi', ?1), ('legacy', '145.5 MHz')",
+        [Frequency::from_hz(2_412_000_001)],
+    )
+    .unwrap();
+
+    let mut stmt = conn
+        .prepare("SELECT tune FROM radios ORDER BY name")
+        .unwrap();
+    let tunes: Vec<Frequency> = stmt
+        .query_map([], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<_, _>>()
+        .unwrap();
+    assert_eq!(
+        tunes,
+        [
+            Frequency::from_khz(145_500),
+            Frequency::from_hz(2_412_000_001)
+        ]
+    );
+
+    let raw: i64 = conn
+        .query_row("SELECT tune FROM radios WHERE name = 'wifi'", [], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(raw, 2_412_000_001);
+
+    for invalid in ["SELECT -1", "SELECT 'fast'", "SELECT 1.5", "SELECT NULL"] {
+        let result = conn.query_row(invalid, [], |row| row.get::<_, Frequency>(0));
+        assert!(result.is_err(), "{invalid}");
+    }
+
+    let huge = conn.execute(
+        "INSERT INTO radios VALUES ('huge', ?1)",
+        [Frequency::from_hz(u64::MAX)],
+    );
+    assert!(huge.is_err());
+}
+
+#[cfg(feature = "postgres")]
+#[test]
+fn test_postgres_types() {
+    use bytes::BytesMut;
+    use postgres_types::{FromSql, ToSql, Type};
+
+    let mut buf = BytesMut::new();
+    Frequency::from_hz(2_412_000_001)
+        .to_sql_checked(&Type::INT8, &mut buf)
+        .unwrap();
+    assert_eq!(&buf[..], 2_412_000_001i64.to_be_bytes());
+    assert_eq!(
+        Frequency::from_sql(&Type::INT8, &buf).unwrap(),
+        Frequency::from_hz(2_412_000_001)
+    );
+
+    assert!(
+        Frequency::from_hz(1)
+            .to_sql_checked(&Type::TEXT, &mut buf)
+            .is_err()
+    );
+    assert!(
+        Frequency::from_hz(u64::MAX)
+            .to_sql_checked(&Type::INT8, &mut BytesMut::new())
+            .is_err()
+    );
+    assert!(Frequency::from_sql(&Type::INT8, &(-1i64).to_be_bytes()).is_err());
+
+    assert_eq!(
+        Frequency::from_sql(&Type::TEXT, b"145.5 MHz").unwrap(),
+        Frequency::from_khz(145_500)
+    );
+    assert!(Frequency::from_sql(&Type::VARCHAR, b"fast").is_err());
+    assert!(<Frequency as FromSql>::accepts(&Type::NUMERIC));
+    assert!(!<Frequency as FromSql>::accepts(&Type::FLOAT8));
+
+    fn numeric(weight: i16, sign: u16, digits: &[u16]) -> Vec<u8> {
+        let header = [digits.len() as u16, weight as u16, sign, 0];
+        header
+            .iter()
+            .chain(digits)
+            .flat_map(|field| field.to_be_bytes())
+            .collect()
+    }
+
+    // 2412000001 is 24|1200|0001 in base 10000
+    let raw = numeric(2, 0, &[24, 1200, 1]);
+    assert_eq!(
+        Frequency::from_sql(&Type::NUMERIC, &raw).unwrap(),
+        Frequency::from_hz(2_412_000_001)
+    );
+
+    // 50.5 rounds up, 0.0004 rounds down, 7 * 10000^2 has trailing zero groups left out
+    let raw = numeric(0, 0, &[50, 5000]);
+    assert_eq!(
+        Frequency::from_sql(&Type::NUMERIC, &raw).unwrap(),
+        Frequency::from_hz(51)
+    );
+    let raw = numeric(-1, 0, &[4]);
+    assert_eq!(
+        Frequency::from_sql(&Type::NUMERIC, &raw).unwrap(),
+        Frequency::ZERO
+    );
+    let raw = numeric(2, 0, &[7]);
+    assert_eq!(
+        Frequency::from_sql(&Type::NUMERIC, &raw).unwrap(),
+        Frequency::from_hz(700_000_000)
+    );
+    assert_eq!(
+        Frequency::from_sql(&Type::NUMERIC, &numeric(0, 0, &[])).unwrap(),
+        Frequency::ZERO
+    );
+
+    assert!(Frequency::from_sql(&Type::NUMERIC, &numeric(0, 0x4000, &[5])).is_err());
+    assert!(Frequency::from_sql(&Type::NUMERIC, &numeric(0, 0xC000, &[])).is_err());
+    assert!(Frequency::from_sql(&Type::NUMERIC, &[0, 1, 0]).is_err());
+}
+
+#[cfg(feature = "sea-orm")]
+#[tokio::test]
+async fn test_sea_orm_values() {
+    use sea_orm::{
+        ActiveValue::Set,
+        DatabaseBackend, EntityTrait, MockDatabase, MockExecResult, Value,
+        sea_query::{ColumnType, StringLen, ValueType},
+    };
+
+    mod radio {
+        use crate::Frequency;
+        use sea_orm::entity::prelude::*;
+
+        #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+        #[sea_orm(table_name = "radios")]
+        pub struct Model {
+            #[sea_orm(primary_key)]
+            pub id: i32,
+            pub tune: Frequency,
+            pub step: Option<Frequency>,
+        }
+
+        #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+        pub enum Relation {}
+
+        impl ActiveModelBehavior for ActiveModel {}
+    }
+
+    assert_eq!(
+        Value::from(Frequency::from_khz(145_500)),
+        Value::String(Some(Box::new("145.5 MHz".to_string())))
+    );
+    assert_eq!(
+        <Frequency as ValueType>::try_from(Value::from(Frequency(u64::MAX))).unwrap(),
+        Frequency(u64::MAX)
+    );
+    assert_eq!(
+        <Frequency as ValueType>::column_type(),
+        ColumnType::String(StringLen::None)
+    );
+    assert_eq!(
+        <Frequency as ValueType>::try_from(Value::String(Some(Box::new("2.4 GHz".to_string()))))
+            .unwrap(),
+        Frequency::from_mhz(2_400)
+    );
+    assert_eq!(
+        <Frequency as ValueType>::try_from(Value::BigInt(Some(1_000))).unwrap(),
+        Frequency::from_khz(1)
+    );
+    assert!(<Frequency as ValueType>::try_from(Value::BigInt(Some(-1))).is_err());
+
+    let model = radio::Model {
+        id: 1,
+        tune: Frequency::from_khz(145_500),
+        step: Some(Frequency(u64::MAX)),
+    };
+    let db = MockDatabase::new(DatabaseBackend::Postgres)
+        .append_query_results([[model.clone()]])
+        .append_exec_results([MockExecResult {
+            last_insert_id: 2,
+            rows_affected: 1,
+        }])
+        .into_connection();
+
+    let loaded = radio::Entity::find_by_id(1).one(&db).await.unwrap();
+    assert_eq!(loaded, Some(model));
+
+    radio::Entity::insert(radio::ActiveModel {
+        id: Set(2),
+        tune: Set(Frequency::from_mhz(433)),
+        step: Set(None),
+    })
+    .exec_without_returning(&db)
+    .await
+    .unwrap();
+
+    let log = db.into_transaction_log();
+    assert_eq!(
+        log[1].statements()[0].values.as_ref().unwrap().0,
+        [
+            Value::Int(Some(2)),
+            Value::String(Some(Box::new("433 MHz".to_string()))),
+            Value::String(None)
+        ]
+    );
+}
+
+#[cfg(feature = "bson")]
+#[test]
+fn test_bson_values() {
+    use bson::{Bson, doc};
+    use serde::{Deserialize, Serialize};
+
+    assert_eq!(
+        Bson::from(Frequency::from_mhz(433)),
+        Bson::Int64(433_000_000)
+    );
+    assert_eq!(
+        Bson::from(Frequency::from_hz(u64::MAX)),
+        Bson::String(Frequency::from_hz(u64::MAX).to_exact_string())
+    );
+
+    assert_eq!(
+        Frequency::try_from(Bson::Int32(440)),
+        Ok(Frequency::from_hz(440))
+    );
+    assert_eq!(
+        Frequency::try_from(Bson::Double(440.6)),
+        Ok(Frequency::from_hz(441))
+    );
+    assert_eq!(
+        Frequency::try_from(Bson::String("2.4 GHz".to_string())),
+        Ok(Frequency::from_mhz(2_400))
+    );
+    assert_eq!(
+        Frequency::try_from(Bson::String(Frequency::from_hz(u64::MAX).to_exact_string())),
+        Ok(Frequency::from_hz(u64::MAX))
+    );
+    assert!(Frequency::try_from(Bson::Int64(-1)).is_err());
+    assert!(Frequency::try_from(Bson::Double(f64::NAN)).is_err());
+    assert!(Frequency::try_from(Bson::Boolean(true)).is_err());
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Sensor {
+        #[serde(with = "crate::bson")]
+        sample: Frequency,
+        #[serde(with = "crate::bson")]
+        carrier: Frequency,
+    }
+
+    let sensor = Sensor {
+        sample: Frequency::from_khz(48),
+        carrier: Frequency::from_hz(u64::MAX),
+    };
+    let document = bson::to_document(&sensor).unwrap();
+    assert_eq!(document.get("sample"), Some(&Bson::Int64(48_000)));
+    assert!(matches!(document.get("carrier"), Some(Bson::String(_))));
+    assert_eq!(bson::from_document::<Sensor>(document).unwrap(), sensor);
+
+    let by_hand = doc! { "sample": 48_000, "carrier": "868 MHz" };
+    let sensor: Sensor = bson::from_document(by_hand).unwrap();
+    assert_eq!(sensor.sample, Frequency::from_khz(48));
+    assert_eq!(sensor.carrier, Frequency::from_mhz(868));
+
+    let bytes = bson::to_vec(&doc! { "sample": 1_i64, "carrier": "1 kHz" }).unwrap();
+    let sensor: Sensor = bson::from_slice(&bytes).unwrap();
+    assert_eq!(sensor.carrier, Frequency::from_khz(1));
+}
+
+#[cfg(feature = "prost")]
+#[test]
+fn test_prost_conversions() {
+    use crate::prost::{ProtoFrequency, proto_message};
+    use prost::Message;
+
+    let message = ProtoFrequency::from(Frequency::from_hz(2_412_000_001));
+    assert_eq!(message.hz, 2_412_000_001);
+    let bytes = message.encode_to_vec();
+    assert_eq!(bytes[0], 0x08);
+    let decoded = ProtoFrequency::decode(bytes.as_slice()).unwrap();
+    assert_eq!(Frequency::from(decoded), Frequency::from_hz(2_412_000_001));
+    assert_eq!(
+        Frequency::from(ProtoFrequency::default()),
+        Frequency::from_hz(0)
+    );
+
+    let period = prost_types::Duration::try_from(Frequency::from_hz(1)).unwrap();
+    assert_eq!((period.seconds, period.nanos), (1, 0));
+    let period = prost_types::Duration::try_from(Frequency::from_hz(3)).unwrap();
+    assert_eq!((period.seconds, period.nanos), (0, 333_333_333));
+    assert_eq!(Frequency::try_from(period), Ok(Frequency::from_hz(3)));
+    assert!(prost_types::Duration::try_from(Frequency::from_hz(0)).is_err());
+    assert!(prost_types::Duration::try_from(Frequency::from_ghz(2)).is_err());
+
+    let period = |seconds, nanos| prost_types::Duration { seconds, nanos };
+    assert_eq!(
+        Frequency::try_from(period(0, 1)),
+        Ok(Frequency::from_ghz(1))
+    );
+    assert_eq!(Frequency::try_from(period(2, 0)), Ok(Frequency::from_hz(1)));
+    assert!(Frequency::try_from(period(3, 0)).is_err());
+    assert!(Frequency::try_from(period(0, 0)).is_err());
+    assert!(Frequency::try_from(period(-1, 0)).is_err());
+    assert!(Frequency::try_from(period(0, -5)).is_err());
+
+    assert!(proto_message("Tune").contains("message Tune {"));
+}
+
+#[cfg(feature = "pyo3")]
+#[test]
+fn test_pyo3_conversions() {
+    use pyo3::{exceptions::PyTypeError, exceptions::PyValueError, prelude::*, types::PyDict};
+
+    Python::with_gil(|py| {
+        let eval = |code: &str| py.eval_bound(code, None, None).unwrap();
+
+        assert_eq!(
+            eval("2_400_000_001").extract::<Frequency>().unwrap(),
+            Frequency::from_hz(2_400_000_001)
+        );
+        assert_eq!(
+            eval("440.6").extract::<Frequency>().unwrap(),
+            Frequency::from_hz(441)
+        );
+        assert_eq!(
+            eval("'2.4 GHz'").extract::<Frequency>().unwrap(),
+            Frequency::from_mhz(2_400)
+        );
+
+        let err = eval("'2.4 parsecs'").extract::<Frequency>().unwrap_err();
+        assert!(err.is_instance_of::<PyValueError>(py));
+        assert!(eval("-1").extract::<Frequency>().is_err());
+        assert!(eval("float('nan')").extract::<Frequency>().is_err());
+        let err = eval("True").extract::<Frequency>().unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+        let err = eval("[1]").extract::<Frequency>().unwrap_err();
+        assert!(err.is_instance_of::<PyTypeError>(py));
+        assert!(err.to_string().contains("list"));
+
+        let locals = PyDict::new_bound(py);
+        locals
+            .set_item("freq", Frequency::from_khz(48).into_py(py))
+            .unwrap();
+        let doubled = py.eval_bound("freq * 2", None, Some(&locals)).unwrap();
+        assert_eq!(doubled.extract::<u64>().unwrap(), 96_000);
+        assert_eq!(
+            Frequency::from_mhz(1)
+                .to_object(py)
+                .extract::<Frequency>(py)
+                .unwrap(),
+            Frequency::from_mhz(1)
+        );
+    });
+}
+
+#[cfg(feature = "wasm")]
+#[test]
+fn test_wasm_facade() {
+    use crate::WasmFrequency;
+
+    // Only the paths that do not create JavaScript values can run outside of wasm
+    let freq = WasmFrequency::parse("2.4 GHz").unwrap();
+    assert_eq!(freq.as_hz(), 2_400_000_000);
+    assert!((freq.as_mhz() - 2_400.0).abs() < f64::EPSILON);
+    assert_eq!(freq.to_js_string(), "2.40 GHz");
+
+    let sum = freq.add(&WasmFrequency::from_hz(1)).unwrap();
+    assert_eq!(sum.to_exact_string(), "2.400000001 GHz");
+    assert_eq!(sum.sub(&freq).unwrap(), WasmFrequency::from_hz(1));
+    assert_eq!(freq.mul(1.5).unwrap().as_hz(), 3_600_000_000);
+    assert_eq!(WasmFrequency::from_hz(10).div(3.0).unwrap().as_hz(), 3);
+    assert!(freq.equals(&Frequency::from_mhz(2_400).into()));
+    assert_eq!(Frequency::from(freq), Frequency::from_mhz(2_400));
+}
+
+#[cfg(feature = "napi")]
+#[test]
+fn test_napi_bindings() {
+    use crate::{
+        NapiFrequency,
+        napi::{
+            napi_convert_frequency, napi_format_frequency, napi_format_frequency_exact,
+            napi_parse_frequency,
+        },
+    };
+    use napi::bindgen_prelude::BigInt;
+
+    assert_eq!(
+        napi_parse_frequency("2.4 GHz".to_string())
+            .unwrap()
+            .get_u64(),
+        (false, 2_400_000_000, true)
+    );
+    assert!(napi_parse_frequency("2.4 parsecs".to_string()).is_err());
+
+    assert_eq!(
+        napi_format_frequency(BigInt::from(2_400_000_001_u64)).unwrap(),
+        "2.40 GHz"
+    );
+    assert_eq!(
+        napi_format_frequency_exact(BigInt::from(2_400_000_001_u64)).unwrap(),
+        "2.400000001 GHz"
+    );
+    assert!(napi_format_frequency(BigInt::from(-1_i64)).is_err());
+    let too_large = BigInt {
+        sign_bit: false,
+        words: vec![0, 1],
+    };
+    assert!(napi_format_frequency(too_large).is_err());
+
+    let mhz = napi_convert_frequency(BigInt::from(433_920_000_u64), "MHz".to_string()).unwrap();
+    assert!((mhz - 433.92).abs() < 1e-9);
+    assert!(napi_convert_frequency(BigInt::from(1_u64), "parsecs".to_string()).is_err());
+
+    let freq = NapiFrequency::new("48 kHz".to_string()).unwrap();
+    assert_eq!(freq.as_hz().get_u64(), (false, 48_000, true));
+    assert!((freq.in_unit("khz".to_string()).unwrap() - 48.0).abs() < f64::EPSILON);
+    assert_eq!(freq.to_js_string(), "48.00 kHz");
+    assert_eq!(
+        NapiFrequency::from_hz(BigInt::from(48_000_u64)).unwrap(),
+        freq
+    );
+    assert_eq!(Frequency::from(freq), Frequency::from_khz(48));
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn test_ffi_functions() {
+    use crate::ffi::{
+        PF_ERR_INVALID_VALUE, PF_ERR_NULL, PF_ERR_UNKNOWN_UNIT, PF_ERR_UTF8, PF_OK, pf_format,
+        pf_format_exact, pf_parse,
+    };
+    use std::ffi::{CStr, CString, c_char};
+
+    let parse = |s: &[u8]| {
+        let s = CString::new(s).unwrap();
+        let mut hz = 7;
+        let code = unsafe { pf_parse(s.as_ptr(), &raw mut hz) };
+        (code, hz)
+    };
+    assert_eq!(parse(b"2.4 GHz"), (PF_OK, 2_400_000_000));
+    assert_eq!(parse(b"2.4 parsecs"), (PF_ERR_UNKNOWN_UNIT, 7));
+    assert_eq!(parse(b"-1 Hz"), (PF_ERR_INVALID_VALUE, 7));
+    assert_eq!(parse(b"\xff Hz"), (PF_ERR_UTF8, 7));
+    assert_eq!(unsafe { pf_parse(std::ptr::null(), &mut 0) }, PF_ERR_NULL);
+    assert_eq!(
+        unsafe { pf_parse(c"1 Hz".as_ptr(), std::ptr::null_mut()) },
+        PF_ERR_NULL
+    );
+
+    let mut buf = [0x7f as c_char; 16];
+    let len = unsafe { pf_format(2_400_000_001, buf.as_mut_ptr(), buf.len()) };
+    assert_eq!(len, 8);
+    assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }, c"2.40 GHz");
+
+    let len = unsafe { pf_format_exact(2_400_000_001, buf.as_mut_ptr(), 6) };
+    assert_eq!(len, "2.400000001 GHz".len());
+    assert_eq!(unsafe { CStr::from_ptr(buf.as_ptr()) }, c"2.400");
+
+    assert_eq!(
+        unsafe { pf_format_exact(48_000, std::ptr::null_mut(), 0) },
+        6
+    );
+}
+
+#[cfg(feature = "uom")]
+#[test]
+fn test_uom_conversions() {
+    use uom::si::{
+        f64,
+        frequency::{hertz, kilohertz, megahertz},
+        u64,
+    };
+
+    let freq = Frequency::from_hz(2_400_000_001);
+    let exact = u64::Frequency::from(freq);
+    assert_eq!(exact.get::<hertz>(), 2_400_000_001);
+    assert_eq!(Frequency::from(exact), freq);
+    assert_eq!(
+        Frequency::from(u64::Frequency::new::<kilohertz>(48)),
+        Frequency::from_khz(48)
+    );
+
+    let approx = f64::Frequency::from(Frequency::from_mhz(433));
+    assert!((approx.get::<megahertz>() - 433.0).abs() < 1e-9);
+    assert_eq!(
+        Frequency::try_from(f64::Frequency::new::<kilohertz>(44.1)),
+        Ok(Frequency::from_hz(44_100))
+    );
+    assert_eq!(
+        Frequency::try_from(f64::Frequency::new::<hertz>(440.6)),
+        Ok(Frequency::from_hz(441))
+    );
+    assert!(Frequency::try_from(f64::Frequency::new::<hertz>(-1.0)).is_err());
+    assert!(Frequency::try_from(f64::Frequency::new::<hertz>(f64::NAN)).is_err());
+    assert!(Frequency::try_from(f64::Frequency::new::<hertz>(1e20)).is_err());
+}
+
+#[cfg(feature = "measurements")]
+#[test]
+fn test_measurements_conversions() {
+    let freq = measurements::Frequency::from(Frequency::from_mhz(433));
+    assert!((freq.as_megahertz() - 433.0).abs() < 1e-9);
+    assert_eq!(Frequency::try_from(freq), Ok(Frequency::from_mhz(433)));
+
+    assert_eq!(
+        Frequency::try_from(measurements::Frequency::from_kilohertz(44.1)),
+        Ok(Frequency::from_hz(44_100))
+    );
+    assert_eq!(
+        Frequency::try_from(measurements::Frequency::from_hertz(440.6)),
+        Ok(Frequency::from_hz(441))
+    );
+    assert!(Frequency::try_from(measurements::Frequency::from_hertz(-1.0)).is_err());
+    assert!(Frequency::try_from(measurements::Frequency::from_hertz(f64::NAN)).is_err());
+
+    let period = measurements::Frequency::from(Frequency::from_khz(1)).as_period();
+    assert_eq!(period, std::time::Duration::from_millis(1));
+}
+
+#[cfg(feature = "fugit")]
+#[test]
+fn test_fugit_conversions() {
+    use fugit::{Duration, HertzU32, KilohertzU32, MegahertzU64, MicrosDurationU32};
+
+    let rate = HertzU32::try_from(Frequency::from_khz(48)).unwrap();
+    assert_eq!(rate, HertzU32::from_raw(48_000));
+    assert_eq!(Frequency::try_from(rate), Ok(Frequency::from_khz(48)));
+
+    assert_eq!(
+        KilohertzU32::try_from(Frequency::from_khz(48)),
+        Ok(KilohertzU32::from_raw(48))
+    );
+    assert!(KilohertzU32::try_from(Frequency::from_hz(48_001)).is_err());
+    assert!(HertzU32::try_from(Frequency::from_ghz(5)).is_err());
+    assert_eq!(
+        Frequency::try_from(MegahertzU64::from_raw(2_400)),
+        Ok(Frequency::from_mhz(2_400))
+    );
+    assert!(Frequency::try_from(MegahertzU64::from_raw(u64::MAX)).is_err());
+
+    // A rate of 1/3 Hz units is not a whole number of hertz unless divisible by three
+    assert!(Frequency::try_from(fugit::Rate::<u32, 1, 3>::from_raw(4)).is_err());
+    assert_eq!(
+        Frequency::try_from(fugit::Rate::<u32, 1, 3>::from_raw(6)),
+        Ok(Frequency::from_hz(2))
+    );
+
+    let period = MicrosDurationU32::try_from(Frequency::from_khz(1)).unwrap();
+    assert_eq!(period.ticks(), 1_000);
+    assert_eq!(Frequency::try_from(period), Ok(Frequency::from_khz(1)));
+    assert_eq!(
+        MicrosDurationU32::try_from(Frequency::from_hz(3)).map(|d| d.ticks()),
+        Ok(333_333)
+    );
+    assert!(MicrosDurationU32::try_from(Frequency::from_hz(0)).is_err());
+    assert!(MicrosDurationU32::try_from(Frequency::from_mhz(3)).is_err());
+    assert!(Frequency::try_from(MicrosDurationU32::from_ticks(0)).is_err());
+    assert!(Frequency::try_from(Duration::<u64, 1, 1>::from_ticks(3)).is_err());
+}
+
+#[cfg(feature = "embedded-time")]
+#[test]
+fn test_embedded_time_conversions() {
+    use embedded_time::rate::{Hertz, Kilohertz, Megahertz};
+
+    assert_eq!(
+        Hertz::<u32>::try_from(Frequency::from_khz(48)),
+        Ok(Hertz(48_000))
+    );
+    assert!(Hertz::<u32>::try_from(Frequency::from_ghz(5)).is_err());
+    assert_eq!(
+        Hertz::<u64>::from(Frequency::from_ghz(5)),
+        Hertz(5_000_000_000_u64)
+    );
+    assert_eq!(
+        Kilohertz::<u32>::try_from(Frequency::from_khz(48)),
+        Ok(Kilohertz(48))
+    );
+    assert!(Kilohertz::<u32>::try_from(Frequency::from_hz(48_001)).is_err());
+    assert_eq!(
+        Megahertz::<u32>::try_from(Frequency::from_mhz(16)),
+        Ok(Megahertz(16))
+    );
+
+    assert_eq!(Frequency::from(Hertz(48_000_u32)), Frequency::from_khz(48));
+    assert_eq!(
+        Frequency::from(Megahertz(u32::MAX)),
+        Frequency::from_mhz(u64::from(u32::MAX))
+    );
+    assert_eq!(
+        Frequency::from(Hertz(u64::MAX)),
+        Frequency::from_hz(u64::MAX)
+    );
+    assert_eq!(
+        Frequency::try_from(Kilohertz(48_u64)),
+        Ok(Frequency::from_khz(48))
+    );
+    assert!(Frequency::try_from(Kilohertz(u64::MAX)).is_err());
+}
+
+#[cfg(feature = "approx")]
+#[test]
+fn test_approx_comparisons() {
+    use crate::{FrequencyF64, Ppm};
+    use approx::{
+        abs_diff_eq, abs_diff_ne, assert_abs_diff_eq, assert_relative_eq, assert_ulps_eq,
+        relative_ne,
+    };
+
+    assert_abs_diff_eq!(Frequency::from_hz(1_000), Frequency::from_hz(1_000));
+    assert!(abs_diff_ne!(
+        Frequency::from_hz(1_000),
+        Frequency::from_hz(1_001)
+    ));
+    assert!(abs_diff_eq!(
+        Frequency::from_hz(1_003),
+        Frequency::from_hz(1_000),
+        epsilon = Frequency::from_hz(3)
+    ));
+
+    let measured = FrequencyF64::from_hz(0.1 + 0.2) * 1e3;
+    assert_ulps_eq!(measured, FrequencyF64::from_hz(300.0));
+    assert_abs_diff_eq!(
+        FrequencyF64::from_hz(440.4),
+        FrequencyF64::from_hz(440.0),
+        epsilon = 0.5
+    );
+    assert_relative_eq!(
+        FrequencyF64::from_mhz(25.000_4),
+        FrequencyF64::from_mhz(25.0),
+        max_relative = Ppm(20.0).as_fraction()
+    );
+    assert!(relative_ne!(
+        FrequencyF64::from_mhz(25.001),
+        FrequencyF64::from_mhz(25.0),
+        max_relative = Ppm(20.0).as_fraction()
+    ));
+}
+
+#[cfg(feature = "num-traits")]
+#[test]
+fn test_num_traits_surface() {
+    use num_traits::{
+        Bounded, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, FromPrimitive, NumCast,
+        SaturatingAdd, SaturatingSub, ToPrimitive,
+    };
+
+    fn checked_sum<T: CheckedAdd + num_traits::Zero + Copy>(values: &[T]) -> Option<T> {
+        values
+            .iter()
+            .try_fold(T::zero(), |sum, value| sum.checked_add(value))
+    }
+
+    fn midpoint<T: NumCast + ToPrimitive>(a: T, b: T) -> Option<T> {
+        T::from((a.to_f64()? + b.to_f64()?) / 2.0)
+    }
+
+    let max = Frequency::max_value();
+    assert_eq!(Frequency::min_value(), Frequency::ZERO);
+    assert_eq!(max, Frequency::from_hz(u64::MAX));
+
+    assert_eq!(
+        checked_sum(&[Frequency::from_khz(1), Frequency::from_hz(500)]),
+        Some(Frequency::from_hz(1_500))
+    );
+    assert_eq!(checked_sum(&[max, Frequency::HERTZ]), None);
+    assert_eq!(Frequency::ZERO.checked_sub(&Frequency::HERTZ), None);
+    assert_eq!(max.checked_mul(&Frequency::from_hz(2)), None);
+    assert_eq!(Frequency::HERTZ.checked_div(&Frequency::ZERO), None);
+    assert_eq!(
+        Frequency::from_khz(1).checked_div(&Frequency::from_hz(4)),
+        Some(Frequency::from_hz(250))
+    );
+
+    assert_eq!(max.saturating_add(&Frequency::HERTZ), max);
+    assert_eq!(
+        Frequency::ZERO.saturating_sub(&Frequency::HERTZ),
+        Frequency::ZERO
+    );
+
+    assert_eq!(Frequency::from_khz(1).to_u32(), Some(1_000));
+    assert_eq!(max.to_i64(), None);
+    assert_eq!(Frequency::from_khz(1).to_f64(), Some(1_000.0));
+    assert_eq!(Frequency::from_i64(-1), None);
+    assert_eq!(Frequency::from_f64(440.9), Some(Frequency::from_hz(440)));
+    assert_eq!(Frequency::from_u128(u128::MAX), None);
+    assert_eq!(
+        <Frequency as NumCast>::from(48_000_u32),
+        Some(Frequency::from_khz(48))
+    );
+    assert_eq!(
+        midpoint(Frequency::from_mhz(88), Frequency::from_mhz(108)),
+        Some(Frequency::from_mhz(98))
+    );
+}
+
+#[cfg(feature = "valuable")]
+#[test]
+fn test_valuable_fields() {
+    use valuable::{NamedValues, Valuable, Value, Visit};
+
+    #[derive(Default)]
+    struct Fields(Vec<(String, String)>);
+
+    impl Visit for Fields {
+        fn visit_value(&mut self, value: Value<'_>) {
+            if let Value::Structable(s) = value {
+                s.visit(self);
+            }
+        }
+
+        fn visit_named_fields(&mut self, named_values: &NamedValues<'_>) {
+            for (field, value) in named_values {
+                let value = match value {
+                    Value::U64(hz) => hz.to_string(),
+                    Value::String(s) => (*s).to_string(),
+                    other => format!("{other:?}"),
+                };
+                self.0.push((field.name().to_string(), value));
+            }
+        }
+    }
+
+    let freq = Frequency::from_hz(2_400_000_001);
+    let mut fields = Fields::default();
+    valuable::visit(&freq, &mut fields);
+    assert_eq!(
+        fields.0,
+        [
+            ("hz".to_string(), "2400000001".to_string()),
+            ("display".to_string(), "2.40 GHz".to_string())
+        ]
+    );
+
+    let Value::Structable(s) = freq.as_value() else {
+        panic!("expected a structable value");
+    };
+    assert_eq!(s.definition().name(), "Frequency");
+}
+
+#[cfg(feature = "metrics")]
+#[test]
+fn test_metrics_recording() {
+    use crate::metrics::{describe_gauge, describe_histogram};
+    use metrics::Unit;
+    use metrics_util::debugging::{DebugValue, DebuggingRecorder};
+
+    let recorder = DebuggingRecorder::new();
+    let snapshotter = recorder.snapshotter();
+
+    metrics::with_local_recorder(&recorder, || {
+        describe_gauge("cpu_freq", "Current CPU clock frequency");
+        describe_histogram("tone_freq", "Detected tone frequency");
+
+        Frequency::from_mhz(3_600).record_gauge("cpu_freq");
+        Frequency::from_hz(440).record_histogram("tone_freq");
+        Frequency::from_hz(880).record_histogram(String::from("tone_freq"));
+    });
+
+    let mut snapshot = snapshotter.snapshot().into_vec();
+    snapshot.sort_by(|a, b| a.0.key().name().cmp(b.0.key().name()));
+
+    let (key, unit, description, value) = &snapshot[0];
+    assert_eq!(key.key().name(), "cpu_freq");
+    assert_eq!(*unit, Some(Unit::CountPerSecond));
+    assert_eq!(
+        description.as_ref().map(AsRef::as_ref),
+        Some("Current CPU clock frequency")
+    );
+    assert_eq!(*value, DebugValue::Gauge(3_600_000_000.0.into()));
+
+    let (key, unit, _, value) = &snapshot[1];
+    assert_eq!(key.key().name(), "tone_freq");
+    assert_eq!(*unit, Some(Unit::CountPerSecond));
+    assert_eq!(
+        *value,
+        DebugValue::Histogram(vec![440.0.into(), 880.0.into()])
+    );
+}
+
+#[cfg(feature = "tokio")]
+#[tokio::test]
+async fn test_tokio_interval() {
+    use crate::Frequency;
+    use std::time::Duration;
+    use tokio::time::MissedTickBehavior;
+
+    let mut interval = Frequency::from_khz(1).to_interval();
+    assert_eq!(interval.period(), Duration::from_millis(1));
+    assert_eq!(interval.missed_tick_behavior(), MissedTickBehavior::Burst);
+    interval.tick().await;
+    interval.tick().await;
+
+    assert_eq!(
+        Frequency::from_hz(3).to_interval().period(),
+        Duration::from_nanos(333_333_333)
+    );
+    assert_eq!(
+        Frequency::from_hz(6).to_interval().period(),
+        Duration::from_nanos(166_666_667)
+    );
+    assert_eq!(
+        Frequency::from_ghz(3).to_interval().period(),
+        Duration::from_nanos(1)
+    );
+
+    assert!(Frequency::ZERO.try_to_interval().is_none());
+    assert_eq!(
+        Frequency::from_hz(4).try_to_interval().unwrap().period(),
+        Duration::from_millis(250)
+    );
+
+    let interval = Frequency::from_hz(50).to_interval_with(MissedTickBehavior::Delay);
+    assert_eq!(interval.period(), Duration::from_millis(20));
+    assert_eq!(interval.missed_tick_behavior(), MissedTickBehavior::Delay);
+
+    let zero = std::panic::catch_unwind(|| Frequency::ZERO.to_interval());
+    assert!(zero.is_err());
+}
+
+#[cfg(feature = "governor")]
+#[test]
+fn test_governor_quota() {
+    use crate::Frequency;
+    use governor::Quota;
+    use std::{num::NonZeroU32, time::Duration};
+
+    let quota = Quota::try_from(Frequency::from_hz(100)).unwrap();
+    assert_eq!(quota.replenish_interval(), Duration::from_millis(10));
+    assert_eq!(quota.burst_size().get(), 100);
+
+    let quota = Frequency::from_hz(3).to_quota_per_minute().unwrap();
+    assert_eq!(
+        quota.replenish_interval(),
+        Duration::from_nanos(333_333_334)
+    );
+    assert_eq!(quota.burst_size().get(), 180);
+
+    let burst = NonZeroU32::new(7).unwrap();
+    let quota = Frequency::from_ghz(2).to_quota_with_burst(burst).unwrap();
+    assert_eq!(quota.replenish_interval(), Duration::from_nanos(1));
+    assert_eq!(quota.burst_size(), burst);
+
+    assert!(Quota::try_from(Frequency::ZERO).is_err());
+    assert!(Frequency::ZERO.to_quota_with_burst(burst).is_none());
+    assert!(Frequency::from_ghz(5).to_quota_per_second().is_none());
+    assert!(Frequency::from_mhz(100).to_quota_per_minute().is_none());
+
+    for hz in [1, 3, 50, 1_000, 31_250] {
+        let freq = Frequency::from_hz(hz);
+        assert_eq!(
+            Frequency::try_from(Quota::try_from(freq).unwrap()),
+            Ok(freq)
+        );
+    }
+    assert_eq!(
+        Frequency::try_from(Quota::per_second(NonZeroU32::new(10).unwrap())),
+        Ok(Frequency::from_hz(10))
+    );
+    assert!(Frequency::try_from(Quota::per_minute(NonZeroU32::new(10).unwrap())).is_err());
+}
+
+#[test]
+fn test_checked_arithmetic() {
+    use crate::{FrequencyMilliHz, FrequencyOf, RationalFrequency, Rpm};
+
+    let max = Frequency(u64::MAX);
+    assert_eq!(Frequency::try_from_khz(3), Some(Frequency::from_hz(3_000)));
+    assert_eq!(
+        Frequency::try_from_mhz(18_446_744_073_709),
+        Some(Frequency::from_hz(18_446_744_073_709_000_000))
+    );
+    assert_eq!(Frequency::try_from_mhz(18_446_744_073_710), None);
+    assert_eq!(Frequency::try_from_ghz(u64::MAX), None);
+
+    assert_eq!(
+        Frequency::from_khz(1).try_add(Frequency::from_hz(500)),
+        Some(Frequency::from_hz(1_500))
+    );
+    assert_eq!(max.try_add(Frequency::HERTZ), None);
+    assert_eq!(FrequencyOf(u128::MAX).try_add(FrequencyOf(1)), None);
+    assert_eq!(FrequencyOf(u16::MAX).try_add(FrequencyOf(1)), None);
+    assert_eq!(
+        Frequency::from_khz(1).try_sub(Frequency::from_hz(1)),
+        Some(Frequency::from_hz(999))
+    );
+    assert_eq!(Frequency::ZERO.try_sub(Frequency::HERTZ), None);
+    assert_eq!(
+        Frequency::from_mhz(8).try_mul(21),
+        Some(Frequency::from_mhz(168))
+    );
+    assert_eq!(max.try_mul(2), None);
+    assert_eq!(FrequencyOf(u128::MAX).try_mul(2), None);
+    assert_eq!(
+        Frequency::from_mhz(168).try_div(4),
+        Some(Frequency::from_mhz(42))
+    );
+    assert_eq!(Frequency::HERTZ.try_div(0), None);
+    assert_eq!(FrequencyOf(48_000u32).try_div(0), None);
+
+    assert_eq!(
+        FrequencyMilliHz(u64::MAX).checked_add(FrequencyMilliHz::MILLIHERTZ),
+        None
+    );
+    assert_eq!(
+        FrequencyMilliHz::ZERO.checked_sub(FrequencyMilliHz::MILLIHERTZ),
+        None
+    );
+    assert_eq!(
+        FrequencyMilliHz::HERTZ.checked_mul(3),
+        Some(FrequencyMilliHz(3_000))
+    );
+    assert_eq!(FrequencyMilliHz::HERTZ.checked_div(0), None);
+
+    assert_eq!(
+        Rpm::checked_from_frequency(Frequency::from_hz(50)),
+        Some(Rpm(3_000))
+    );
+    assert_eq!(Rpm::checked_from_frequency(max), None);
+    assert_eq!(Rpm(60).checked_add(Rpm(u64::MAX)), None);
+    assert_eq!(Rpm(60).checked_sub(Rpm(120)), None);
+    assert_eq!(Rpm(60).checked_mul(2), Some(Rpm(120)));
+    assert_eq!(Rpm(60).checked_div(0), None);
+
+    assert_eq!(
+        RationalFrequency::from(Frequency::HERTZ).checked_div(0),
+        None
+    );
+}
+
+/// Proves that the core paths cannot panic
+///
+/// Each function is checked by the `no-panic` crate, which fails to link if the optimizer cannot
+/// remove every path to a panic. This needs optimizations across the whole crate, so the module
+/// only builds in optimized profiles and is meant to run with
+/// `cargo test --profile no-panic --lib no_panic`.
+#[cfg(not(debug_assertions))]
+mod no_panic {
+    use core::{fmt::Write, num::NonZeroU64, time::Duration};
+
+    use no_panic::no_panic;
+
+    use crate::{Frequency, FrequencyOf, NonZeroFrequency, Period, Rounding, math};
+
+    /// A fixed-size buffer like firmware would format into
+    struct Buffer {
+        bytes: [u8; 32],
+        len: usize,
+    }
+
+    impl Write for Buffer {
+        fn write_str(&mut self, s: &str) -> core::fmt::Result {
+            let end = self.len.checked_add(s.len()).ok_or(core::fmt::Error)?;
+            let dest = self.bytes.get_mut(self.len..end).ok_or(core::fmt::Error)?;
+            dest.copy_from_slice(s.as_bytes());
+            self.len = end;
+            Ok(())
+        }
+    }
+
+    #[no_panic]
+    fn parse_plain(s: &str) -> Option<u128> {
+        math::parse_plain_hz(s)
+    }
+
+    #[no_panic]
+    fn parse(s: &str) -> Option<Frequency> {
+        Frequency::parse_plain(s)
+    }
+
+    #[no_panic]
+    fn from_units(value: u64) -> [Option<Frequency>; 3] {
+        [
+            Frequency::try_from_khz(value),
+            Frequency::try_from_mhz(value),
+            Frequency::try_from_ghz(value),
+        ]
+    }
+
+    #[no_panic]
+    fn arithmetic(a: Frequency, b: Frequency, n: u64) -> [Option<Frequency>; 4] {
+        [a.try_add(b), a.try_sub(b), a.try_mul(n), a.try_div(n)]
+    }
+
+    #[no_panic]
+    fn narrow_arithmetic(
+        a: FrequencyOf<u32>,
+        b: FrequencyOf<u32>,
+    ) -> [Option<FrequencyOf<u32>>; 4] {
+        [a.try_add(b), a.try_sub(b), a.try_mul(b.0), a.try_div(b.0)]
+    }
+
+    #[no_panic]
+    fn periods(freq: Frequency, rounding: Rounding) -> (Duration, Option<Period>, Option<u64>) {
+        let period = freq.period(rounding);
+        let nanos = period.map(|period| period.as_nanos(rounding));
+        (freq.as_duration(), period, nanos)
+    }
+
+    #[no_panic]
+    fn non_zero(freq: Frequency, divider: NonZeroU64) -> Option<(u64, Duration, Frequency)> {
+        let non_zero = NonZeroFrequency::new(freq)?;
+        Some((freq / non_zero, non_zero.period(), non_zero.divide(divider)))
+    }
+
+    #[no_panic]
+    fn compare(freq: Frequency, min: Frequency, max: Frequency) -> Frequency {
+        freq.max(min).min(max)
+    }
+
+    #[no_panic]
+    fn format_exact(freq: Frequency, buffer: &mut Buffer) -> core::fmt::Result {
+        freq.format_exact_into(buffer)
+    }
+
+    #[cfg(feature = "std")]
+    #[no_panic]
+    fn unit_conversions(freq: Frequency) -> (Option<crate::Rpm>, Option<crate::Bpm>) {
+        (
+            crate::Rpm::checked_from_frequency(freq),
+            crate::Bpm::checked_from_frequency(freq),
+        )
+    }
+
+    #[test]
+    fn test_core_paths_cannot_panic() {
+        assert_eq!(parse_plain("2.4 GHz"), Some(2_400_000_000));
+        assert_eq!(parse("433.92 MHz"), Some(Frequency::from_khz(433_920)));
+        assert_eq!(parse("1e3 kHz"), None);
+        assert_eq!(from_units(u64::MAX), [None; 3]);
+        assert_eq!(
+            arithmetic(Frequency(u64::MAX), Frequency::HERTZ, 0),
+            [
+                None,
+                Some(Frequency(u64::MAX - 1)),
+                Some(Frequency::ZERO),
+                None
+            ]
+        );
+        assert_eq!(
+            narrow_arithmetic(FrequencyOf(u32::MAX), FrequencyOf(0)),
+            [
+                Some(FrequencyOf(u32::MAX)),
+                Some(FrequencyOf(u32::MAX)),
+                Some(FrequencyOf(0)),
+                None
+            ]
+        );
+        assert_eq!(
+            periods(Frequency::ZERO, Rounding::Up),
+            (Duration::ZERO, None, None)
+        );
+        assert_eq!(non_zero(Frequency::ZERO, NonZeroU64::MIN), None);
+        assert_eq!(
+            compare(
+                Frequency::from_hz(5),
+                Frequency::from_hz(10),
+                Frequency::from_hz(1)
+            ),
+            Frequency::from_hz(1)
+        );
+
+        for (freq, expected) in [
+            (Frequency(u64::MAX), "18446744073.709551615 GHz"),
+            (Frequency::from_hz(1_000_050), "1.00005 MHz"),
+            (Frequency::from_khz(48), "48 kHz"),
+            (Frequency::ZERO, "0 Hz"),
+        ] {
+            let mut buffer = Buffer {
+                bytes: [0; 32],
+                len: 0,
+            };
+            format_exact(freq, &mut buffer).unwrap();
+            assert_eq!(&buffer.bytes[..buffer.len], expected.as_bytes());
+        }
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_unit_conversions_cannot_panic() {
+        assert_eq!(unit_conversions(Frequency(u64::MAX)), (None, None));
+        assert_eq!(
+            unit_conversions(Frequency::from_hz(2)),
+            (
+                Some(crate::Rpm(120)),
+                Some(crate::Bpm::from_millibpm(120_000))
+            )
+        );
+    }
+}
+
+#[test]
+fn test_parse_many() {
+    use crate::{Error, parse_many};
+
+    let inputs = ["2412 MHz", " 48khz ", "1e3 Hz", "fast", "-1 Hz"];
+    let parsed: Vec<_> = parse_many(inputs).collect();
+    assert_eq!(
+        parsed,
+        [
+            Ok(Frequency::from_mhz(2_412)),
+            Ok(Frequency::from_khz(48)),
+            Ok(Frequency::from_khz(1)),
+            Err(Error::UnknownUnit("fast".into())),
+            Err(Error::InvalidValue("-1 ".into())),
+        ]
+    );
+
+    // Each result matches parsing the string on its own
+    for (input, result) in inputs.iter().zip(&parsed) {
+        assert_eq!(&input.parse::<Frequency>(), result);
+    }
+
+    let owned: Vec<String> = (1..=1_000).map(|khz| format!("{khz} kHz")).collect();
+    let total = parse_many(&owned)
+        .map(Result::unwrap)
+        .fold(0, |sum, freq| sum + freq.as_hz());
+    assert_eq!(total, 500_500_000);
+    assert_eq!(parse_many(Vec::<&str>::new()).count(), 0);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_par_parse_many() {
+    use crate::{par_parse_many, parse_many};
+    use rayon::prelude::*;
+
+    let owned: Vec<String> = (0..10_000)
+        .map(|i| match i % 4 {
+            0 => format!("{i} Hz"),
+            1 => format!("{}.{} kHz", i / 1_000, i % 1_000),
+            2 => format!("{i}e3 hz"),
+            _ => format!("{i} furlongs"),
+        })
+        .collect();
+
+    let sequential: Vec<_> = parse_many(&owned).collect();
+    let parallel: Vec<_> = par_parse_many(&owned).collect();
+    assert_eq!(parallel, sequential);
+    assert_eq!(
+        parallel.iter().filter(|result| result.is_err()).count(),
+        2_500
+    );
+
+    let dump = owned.join("\n");
+    let from_lines: Vec<_> = par_parse_many(dump.par_lines()).collect();
+    assert_eq!(from_lines, sequential);
+}
+
+/// Counts the allocations of the current thread, to check that deserializing does not allocate
+/// per element
+#[cfg(feature = "serde")]
+mod allocations {
+    use std::{
+        alloc::{GlobalAlloc, Layout, System},
+        cell::Cell,
+    };
+
+    use serde::Deserialize;
+
+    use crate::{Frequency, FrequencyRange, FrequencyUnit, RationalFrequency};
+
+    thread_local! {
+        static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    struct CountingAllocator;
+
+    fn count() {
+        // Allocations during thread teardown have nowhere to be counted
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+    }
+
+    // SAFETY: every call is forwarded to the system allocator unchanged
+    unsafe impl GlobalAlloc for CountingAllocator {
+        unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+            count();
+            unsafe { System.alloc(layout) }
+        }
+
+        unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+            unsafe { System.dealloc(ptr, layout) }
+        }
+
+        unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+            count();
+            unsafe { System.realloc(ptr, layout, new_size) }
+        }
+    }
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    /// Deserializes `json` from a string and from a reader, and returns the most allocations
+    /// either of them made
+    fn allocations<T: for<'de> Deserialize<'de>>(json: &str) -> usize {
+        let measure = |deserialize: &dyn Fn() -> T| {
+            let before = ALLOCATIONS.with(Cell::get);
+            let value = deserialize();
+            let allocations = ALLOCATIONS.with(Cell::get) - before;
+            drop(value);
+            allocations
+        };
+
+        let from_str = measure(&|| serde_json::from_str(json).unwrap());
+        let from_reader = measure(&|| serde_json::from_reader(json.as_bytes()).unwrap());
+        from_str.max(from_reader)
+    }
+
+    #[test]
+    fn test_serde_deserialize_does_not_allocate_per_element() {
+        #[derive(Deserialize)]
+        struct Channel {
+            #[serde(with = "crate::serde::structured")]
+            _center: Frequency,
+        }
+
+        // Growing the `Vec` itself takes a few dozen allocations at most
+        const ELEMENTS: usize = 10_000;
+        const MAX_ALLOCATIONS: usize = 64;
+
+        let array = |element: &str| format!("[{}]", vec![element; ELEMENTS].join(","));
+
+        let json = array(r#""2.400000001 GHz""#);
+        assert!(allocations::<Vec<Frequency>>(&json) < MAX_ALLOCATIONS);
+
+        // Escaped strings are unescaped into a reused scratch buffer
+        let json = array(r#""2.4\u0020GHz""#);
+        assert!(allocations::<Vec<Frequency>>(&json) < MAX_ALLOCATIONS);
+
+        let json = array(r#"{"min":"2.4 GHz","max":"2.5 GHz"}"#);
+        assert!(allocations::<Vec<FrequencyRange>>(&json) < MAX_ALLOCATIONS);
+
+        let json = array(r#"{"_center":{"value":2.4,"unit":"GHz"}}"#);
+        assert!(allocations::<Vec<Channel>>(&json) < MAX_ALLOCATIONS);
+
+        let json = array(r#""MHz""#);
+        assert!(allocations::<Vec<FrequencyUnit>>(&json) < MAX_ALLOCATIONS);
+
+        let json = array(r#""30000/1001 Hz""#);
+        assert!(allocations::<Vec<RationalFrequency>>(&json) < MAX_ALLOCATIONS);
+    }
+}
+
+#[cfg(feature = "defmt")]
+#[test]
+fn test_defmt_format() {
+    use crate::{Error, FrequencyOf, FrequencyUnit, NonZeroFrequency, Period, Rounding};
+
+    // Logging needs a target with a global logger, so this only checks which types can be logged
+    fn assert_format<T: defmt::Format>() {}
+
+    assert_format::<Frequency>();
+    assert_format::<FrequencyOf<u32>>();
+    assert_format::<NonZeroFrequency>();
+    assert_format::<Period>();
+    assert_format::<FrequencyUnit>();
+    assert_format::<Rounding>();
+    assert_format::<Error>();
 }
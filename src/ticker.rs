@@ -0,0 +1,134 @@
+use std::{
+    num::NonZeroU64,
+    thread,
+    time::{Duration, Instant},
+};
+
+use crate::{Frequency, GIGAHERTZ};
+
+/// Blocks the current thread until each tick of a frequency
+///
+/// Tick `n` is due at `start + n / frequency`, computed from the start rather than by adding up
+/// sleeps, so the time spent between ticks and the rounding of periods to nanoseconds never
+/// cause drift. When a tick is missed, the ticker catches up to the current tick instead of
+/// firing the missed ones in a burst, and reports how many ticks passed.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, Ticker};
+///
+/// let freq: Frequency = "200 Hz".parse().unwrap();
+/// let mut ticker = Ticker::new(freq).unwrap();
+///
+/// for _ in 0..3 {
+///     let elapsed = ticker.tick();
+///     // Advance a simulation by `elapsed` fixed steps
+///     assert!(elapsed >= 1);
+/// }
+/// assert!(ticker.ticks() >= 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Ticker {
+    start: Instant,
+    hz: NonZeroU64,
+    ticks: u64,
+}
+
+impl Ticker {
+    /// Creates a ticker whose first tick is due one period from now.
+    ///
+    /// Returns `None` for `0 Hz`, which never ticks.
+    #[must_use]
+    pub fn new(freq: Frequency) -> Option<Self> {
+        Some(Self {
+            start: Instant::now(),
+            hz: NonZeroU64::new(freq.0)?,
+            ticks: 0,
+        })
+    }
+
+    /// Returns the frequency the ticker ticks at.
+    #[must_use]
+    pub fn frequency(&self) -> Frequency {
+        Frequency(self.hz.get())
+    }
+
+    /// Returns the number of ticks so far, including missed ones.
+    #[must_use]
+    pub fn ticks(&self) -> u64 {
+        self.ticks
+    }
+
+    /// Returns when tick `n` is due, rounded down to the nanosecond.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the deadline is too far in the future for an `Instant`, such as tick
+    /// `u64::MAX` of a slow ticker. See [`Ticker::checked_deadline`] for a checked alternative.
+    #[must_use]
+    pub fn deadline(&self, n: u64) -> Instant {
+        self.checked_deadline(n)
+            .expect("deadline does not fit in an `Instant`")
+    }
+
+    /// Returns when tick `n` is due, rounded down to the nanosecond, or `None` if it is too far
+    /// in the future for an `Instant`.
+    #[must_use]
+    // The remainder is less than the frequency, so the nanoseconds are less than 10^9
+    #[allow(clippy::cast_possible_truncation)]
+    pub fn checked_deadline(&self, n: u64) -> Option<Instant> {
+        let hz = self.hz.get();
+        let nanos = u128::from(n % hz) * u128::from(GIGAHERTZ) / u128::from(hz);
+        self.start
+            .checked_add(Duration::from_secs(n / hz))?
+            .checked_add(Duration::from_nanos(nanos as u64))
+    }
+
+    /// Sleeps until the next tick is due, and returns how many ticks passed since the previous
+    /// call.
+    ///
+    /// Returns `1` when the ticker keeps up. When it falls behind, returns without sleeping and
+    /// skips ahead to the last tick that is due.
+    pub fn tick(&mut self) -> u64 {
+        let next = self.ticks + 1;
+        let now = Instant::now();
+        let deadline = self.deadline(next);
+
+        if now < deadline {
+            thread::sleep(deadline - now);
+            self.ticks = next;
+            return 1;
+        }
+
+        let due = self.ticks_due(now - self.start).max(next);
+        let passed = due - self.ticks;
+        self.ticks = due;
+        passed
+    }
+
+    /// Restarts the ticker, with its next tick due one period from now.
+    pub fn reset(&mut self) {
+        self.start = Instant::now();
+        self.ticks = 0;
+    }
+
+    /// Returns how many ticks are due `elapsed` after the start
+    // Saturating at u64::MAX is acceptable here
+    #[allow(clippy::cast_possible_truncation)]
+    fn ticks_due(&self, elapsed: Duration) -> u64 {
+        let ticks =
+            elapsed.as_nanos().saturating_mul(u128::from(self.hz.get())) / u128::from(GIGAHERTZ);
+        ticks.min(u128::from(u64::MAX)) as u64
+    }
+}
+
+impl Iterator for Ticker {
+    type Item = Instant;
+
+    /// Sleeps until the next tick, and returns when it was due.
+    fn next(&mut self) -> Option<Self::Item> {
+        self.tick();
+        Some(self.deadline(self.ticks))
+    }
+}
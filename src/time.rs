@@ -1,7 +1,9 @@
-use crate::Frequency;
+use crate::{Error, Frequency, Result, Rounding};
 
 impl Frequency {
-    /// Converts the frequency to a `time::Duration`.
+    /// Converts the frequency to a `time::Duration`, rounded to the nearest nanosecond.
+    ///
+    /// Returns `time::Duration::ZERO` for `0 Hz`.
     ///
     /// # Examples
     /// ```rust
@@ -14,32 +16,62 @@ impl Frequency {
     /// let freq = Frequency::from_mhz(1);
     /// let duration = freq.as_time_duration();
     /// assert_eq!(duration.whole_nanoseconds(), 1_000);
+    ///
+    /// // The 666.67 ps period of 1.5 GHz rounds up to 1 ns
+    /// let freq = Frequency::from_mhz(1_500);
+    /// assert_eq!(freq.as_time_duration().whole_nanoseconds(), 1);
     /// ```
     #[must_use]
-    #[allow(clippy::cast_possible_truncation, clippy::cast_possible_wrap)]
     pub fn as_time_duration(&self) -> time::Duration {
-        if self.0 == 0 {
-            return time::Duration::ZERO;
-        }
-
-        let nanoseconds_per_second: u64 = crate::GIGAHERTZ;
+        self.as_time_duration_rounded(Rounding::Nearest)
+    }
 
-        // Calculate the period in nanoseconds.
-        // To avoid potential overflow if self.0 is small, we perform the division last.
-        if nanoseconds_per_second >= self.0 {
-            time::Duration::nanoseconds((nanoseconds_per_second / self.0) as i64)
-        } else {
-            // If frequency is higher than 1 GHz, the period is less than 1 ns.
-            // We need to handle this carefully. We can calculate the reciprocal
-            // as a fraction and then convert to nanoseconds, potentially losing
-            // some precision for extremely high frequencies.
-            let picoseconds_per_second: u128 = 1_000_000_000_000;
-            let frequency: u128 = u128::from(self.0);
-            let period_in_picoseconds = picoseconds_per_second / frequency;
+    /// Converts the frequency to a `time::Duration`, rounding to whole nanoseconds as requested.
+    ///
+    /// The period is computed in picoseconds first, see [`Frequency::period`], and rounds the
+    /// same as the exact period would. Returns `time::Duration::ZERO` for `0 Hz`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parse_frequency::{Frequency, Rounding};
+    ///
+    /// let freq = Frequency::from_hz(3);
+    /// assert_eq!(freq.as_time_duration_rounded(Rounding::Down).whole_nanoseconds(), 333_333_333);
+    /// assert_eq!(freq.as_time_duration_rounded(Rounding::Up).whole_nanoseconds(), 333_333_334);
+    /// ```
+    #[must_use]
+    // Periods are at most 10^9 ns, so wrapping cannot occur
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn as_time_duration_rounded(&self, rounding: Rounding) -> time::Duration {
+        // Rounding the picoseconds down keeps every nanosecond rounding exact, while rounding
+        // them to the nearest picosecond first could round a tie the wrong way
+        let picos = match rounding {
+            Rounding::Nearest => Rounding::Down,
+            rounding => rounding,
+        };
+        self.period(picos).map_or(time::Duration::ZERO, |period| {
+            time::Duration::nanoseconds(period.as_nanos(rounding) as i64)
+        })
+    }
 
-            // Convert picoseconds to nanoseconds (integer division will truncate)
-            let period_in_nanoseconds = period_in_picoseconds / 1_000;
-            time::Duration::nanoseconds(period_in_nanoseconds as i64)
-        }
+    /// Converts a period to its frequency, rounded to the nearest hertz with ties rounding up.
+    ///
+    /// Fails for zero and negative durations, and for durations longer than 2 seconds since
+    /// their frequency rounds to `0 Hz`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let period = time::Duration::microseconds(125);
+    /// assert_eq!(Frequency::from_time_duration(period), Ok(Frequency::from_khz(8)));
+    ///
+    /// assert!(Frequency::from_time_duration(time::Duration::ZERO).is_err());
+    /// assert!(Frequency::from_time_duration(-period).is_err());
+    /// ```
+    pub fn from_time_duration(period: time::Duration) -> Result<Self> {
+        std::time::Duration::try_from(period)
+            .map_err(|_| Error::invalid_value(period))
+            .and_then(Self::try_from)
     }
 }
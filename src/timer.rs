@@ -0,0 +1,90 @@
+use crate::{Frequency, Rounding, math::error_ppm};
+
+/// Number of distinct prescaler values of a 16-bit prescaler register
+const PRESCALER_STEPS: u128 = 1 << 16;
+
+/// Register values found by [`timer_config`]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TimerConfig {
+    /// Value to program into the prescaler register (the clock is divided by `prescaler + 1`)
+    pub prescaler: u32,
+
+    /// Value to program into the auto-reload register (the counter wraps after `reload + 1`
+    /// ticks)
+    pub reload: u32,
+
+    /// The update frequency actually achieved, rounded to the nearest hertz
+    pub actual: Frequency,
+
+    /// The relative error of the actual update frequency in parts per million (positive if too
+    /// fast)
+    pub error_ppm: f64,
+}
+
+/// Searches prescaler/auto-reload pairs for a timer that overflows at `target`.
+///
+/// The timer is modelled after STM32 general purpose timers: a 16-bit prescaler followed by a
+/// counter of `counter_bits` bits (at most 32), giving an update frequency of
+/// `timer_clock / ((prescaler + 1) * (reload + 1))`. The pair with the smallest error is
+/// returned. Ties go to the smallest prescaler, which keeps the counter resolution as high as
+/// possible.
+///
+/// All calculations are done with exact integer arithmetic. Returns `None` if the clock, target
+/// or counter width is zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, timer_config};
+///
+/// // A 1 kHz tick from an 84 MHz timer clock
+/// let config = timer_config(Frequency::from_mhz(84), Frequency::from_khz(1), 16).unwrap();
+/// assert_eq!((config.prescaler + 1) * (config.reload + 1), 84_000);
+/// assert_eq!(config.actual, Frequency::from_khz(1));
+/// assert_eq!(config.error_ppm, 0.0);
+/// ```
+#[must_use]
+// Prescaler and reload values are bounded by their register widths
+#[allow(clippy::cast_possible_truncation)]
+pub fn timer_config(
+    timer_clock: Frequency,
+    target: Frequency,
+    counter_bits: u8,
+) -> Option<TimerConfig> {
+    let (clock, target_hz) = (u128::from(timer_clock.0), u128::from(target.0));
+
+    if clock == 0 || target_hz == 0 || counter_bits == 0 {
+        return None;
+    }
+
+    let reload_steps = 1u128 << counter_bits.min(32);
+
+    // Smaller prescalers cannot reach the target without overflowing the counter
+    let first = Rounding::Up
+        .div(clock, target_hz * reload_steps)?
+        .clamp(1, PRESCALER_STEPS);
+
+    // |clock / (p * r) - target| = |clock - target * p * r| / (p * r)
+    let distance = |ticks: u128| clock.abs_diff(target_hz * ticks);
+
+    let (prescaler, reload) = (first..=PRESCALER_STEPS)
+        .map(|prescaler| {
+            let reload = Rounding::Nearest
+                .div(clock, target_hz * prescaler)
+                .unwrap_or(1)
+                .clamp(1, reload_steps);
+            (prescaler, reload)
+        })
+        .min_by(|&(pa, ra), &(pb, rb)| {
+            // Compare the distances by cross-multiplying with the other tick count
+            (distance(pa * ra) * pb * rb).cmp(&(distance(pb * rb) * pa * ra))
+        })?;
+
+    let ticks = prescaler * reload;
+    Some(TimerConfig {
+        prescaler: (prescaler - 1) as u32,
+        reload: (reload - 1) as u32,
+        actual: Frequency(Rounding::Nearest.div(clock, ticks)? as u64),
+        error_ppm: error_ppm(clock, ticks, target_hz),
+    })
+}
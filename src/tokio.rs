@@ -0,0 +1,78 @@
+use tokio::time::{Interval, MissedTickBehavior};
+
+use crate::{Frequency, Rounding};
+
+impl Frequency {
+    /// Creates a `tokio::time::Interval` that ticks at this frequency.
+    ///
+    /// The period is rounded to the nearest nanosecond, and frequencies above `1 GHz` tick every
+    /// nanosecond. Like `tokio::time::interval`, the first tick completes immediately and missed
+    /// ticks are caught up in a burst; see [`Frequency::to_interval_with`] to change that.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `0 Hz`, which never ticks, and when called outside of a Tokio runtime. See
+    /// [`Frequency::try_to_interval`] for an alternative that returns `None` for `0 Hz`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let freq: Frequency = "50 Hz".parse().unwrap();
+    /// let mut interval = freq.to_interval();
+    ///
+    /// for _ in 0..3 {
+    ///     interval.tick().await;
+    /// }
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_interval(&self) -> Interval {
+        self.try_to_interval()
+            .expect("0 Hz has no period to tick at")
+    }
+
+    /// Creates a `tokio::time::Interval` that ticks at this frequency like
+    /// [`Frequency::to_interval`], or returns `None` for `0 Hz`.
+    ///
+    /// # Panics
+    ///
+    /// Panics when called outside of a Tokio runtime.
+    #[must_use]
+    pub fn try_to_interval(&self) -> Option<Interval> {
+        let period = self.period(Rounding::Down)?.as_duration(Rounding::Nearest);
+
+        Some(tokio::time::interval(
+            period.max(std::time::Duration::from_nanos(1)),
+        ))
+    }
+
+    /// Creates a `tokio::time::Interval` that ticks at this frequency, handling missed ticks
+    /// with `behavior`.
+    ///
+    /// # Panics
+    ///
+    /// Panics for `0 Hz`, which never ticks, and when called outside of a Tokio runtime.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    /// use tokio::time::MissedTickBehavior;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let interval = Frequency::from_hz(10).to_interval_with(MissedTickBehavior::Skip);
+    /// assert_eq!(interval.missed_tick_behavior(), MissedTickBehavior::Skip);
+    /// # }
+    /// ```
+    #[must_use]
+    pub fn to_interval_with(&self, behavior: MissedTickBehavior) -> Interval {
+        let mut interval = self.to_interval();
+        interval.set_missed_tick_behavior(behavior);
+        interval
+    }
+}
@@ -0,0 +1,92 @@
+use std::fmt::Display;
+
+use crate::{Frequency, FrequencyRange, MEGAHERTZ};
+
+/// A run of adjacent TV channels as `(first channel, last channel, lower edge of the first
+/// channel in MHz, channel width in MHz)`
+type Segment = (u16, u16, u64, u64);
+
+/// North American channels: VHF low 2-6 (with the 72-76 MHz gap), VHF high 7-13 and UHF 14-36
+const ATSC: [Segment; 4] = [
+    (2, 4, 54, 6),
+    (5, 6, 76, 6),
+    (7, 13, 174, 6),
+    (14, 36, 470, 6),
+];
+
+/// European channels per the GE06 plan: VHF band III 5-12 (7 MHz) and UHF 21-69 (8 MHz)
+const EUROPE: [Segment; 2] = [(5, 12, 174, 7), (21, 69, 470, 8)];
+
+/// A terrestrial TV channel plan
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, TvChannelPlan};
+///
+/// // "UHF channel 32" in Europe
+/// let channel = TvChannelPlan::Europe.channel(32).unwrap();
+/// assert_eq!(channel.min, Frequency::from_mhz(558));
+/// assert_eq!(channel.max, Frequency::from_mhz(566));
+/// assert_eq!(channel.center(), Frequency::from_mhz(562));
+///
+/// assert_eq!(TvChannelPlan::Atsc.channel_for(Frequency::from_mhz(521)), Some(22));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum TvChannelPlan {
+    /// ATSC channels in North America (6 MHz), VHF 2-13 and UHF 14-36
+    Atsc,
+
+    /// DVB-T channels in Europe and the rest of ITU region 1, VHF 5-12 (7 MHz) and UHF 21-69
+    /// (8 MHz)
+    Europe,
+}
+
+impl TvChannelPlan {
+    fn segments(self) -> &'static [Segment] {
+        match self {
+            TvChannelPlan::Atsc => &ATSC,
+            TvChannelPlan::Europe => &EUROPE,
+        }
+    }
+
+    /// Returns the frequencies occupied by channel `number`, or `None` if the plan has no such
+    /// channel.
+    #[must_use]
+    pub fn channel(self, number: u16) -> Option<FrequencyRange> {
+        self.segments()
+            .iter()
+            .find(|(first, last, ..)| (*first..=*last).contains(&number))
+            .map(|&(first, _, start, width)| {
+                let min = (start + u64::from(number - first) * width) * MEGAHERTZ;
+                FrequencyRange::new(Frequency(min), Frequency(min + width * MEGAHERTZ))
+            })
+    }
+
+    /// Returns the channel that `freq` falls into. A frequency on the boundary between two
+    /// channels belongs to the upper one.
+    #[must_use]
+    pub fn channel_for(self, freq: Frequency) -> Option<u16> {
+        self.channels()
+            .find(|(_, range)| range.min <= freq && freq < range.max)
+            .map(|(number, _)| number)
+    }
+
+    /// Returns an iterator over every channel number in the plan and the frequencies it
+    /// occupies, in ascending order.
+    pub fn channels(self) -> impl Iterator<Item = (u16, FrequencyRange)> {
+        self.segments()
+            .iter()
+            .flat_map(|&(first, last, ..)| first..=last)
+            .filter_map(move |number| Some((number, self.channel(number)?)))
+    }
+}
+
+impl Display for TvChannelPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TvChannelPlan::Atsc => write!(f, "ATSC"),
+            TvChannelPlan::Europe => write!(f, "Europe"),
+        }
+    }
+}
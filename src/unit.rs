@@ -0,0 +1,94 @@
+use core::{fmt::Display, str::FromStr};
+
+use crate::{Error, Frequency, GIGAHERTZ, KILOHERTZ, MEGAHERTZ, Result};
+
+/// A unit of frequency
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, FrequencyUnit};
+///
+/// let unit: FrequencyUnit = "MHz".parse().unwrap();
+/// assert_eq!(unit, FrequencyUnit::Megahertz);
+/// assert_eq!(unit.multiplier(), 1_000_000);
+///
+/// assert_eq!(FrequencyUnit::for_frequency(Frequency::from_khz(1_500)), FrequencyUnit::Megahertz);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default, Hash)]
+pub enum FrequencyUnit {
+    /// Hertz (Hz)
+    #[default]
+    Hertz,
+
+    /// Kilohertz (kHz)
+    Kilohertz,
+
+    /// Megahertz (MHz)
+    Megahertz,
+
+    /// Gigahertz (GHz)
+    Gigahertz,
+}
+
+impl FrequencyUnit {
+    /// All units, from small to large
+    pub const ALL: [Self; 4] = [
+        Self::Hertz,
+        Self::Kilohertz,
+        Self::Megahertz,
+        Self::Gigahertz,
+    ];
+
+    /// Returns the number of hertz in one of this unit.
+    #[must_use]
+    pub fn multiplier(&self) -> u64 {
+        match self {
+            Self::Hertz => 1,
+            Self::Kilohertz => KILOHERTZ,
+            Self::Megahertz => MEGAHERTZ,
+            Self::Gigahertz => GIGAHERTZ,
+        }
+    }
+
+    /// Returns the symbol of the unit, such as `"kHz"`.
+    #[must_use]
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            Self::Hertz => "Hz",
+            Self::Kilohertz => "kHz",
+            Self::Megahertz => "MHz",
+            Self::Gigahertz => "GHz",
+        }
+    }
+
+    /// Returns the largest unit that `freq` reaches, the unit [`Frequency`]'s `Display`
+    /// implementation uses.
+    #[must_use]
+    pub fn for_frequency(freq: Frequency) -> Self {
+        Self::ALL
+            .into_iter()
+            .rev()
+            .find(|unit| freq.0 >= unit.multiplier())
+            .unwrap_or(Self::Hertz)
+    }
+}
+
+impl Display for FrequencyUnit {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.symbol())
+    }
+}
+
+impl FromStr for FrequencyUnit {
+    type Err = Error;
+
+    /// Parses a unit symbol, ignoring case and surrounding whitespace.
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        Self::ALL
+            .into_iter()
+            .find(|unit| unit.symbol().eq_ignore_ascii_case(s))
+            .ok_or_else(|| Error::unknown_unit(s))
+    }
+}
@@ -0,0 +1,37 @@
+use uom::si::{self, frequency::hertz};
+
+use crate::{Error, Frequency, FrequencyF64, Rounding};
+
+impl From<Frequency> for si::u64::Frequency {
+    fn from(freq: Frequency) -> Self {
+        Self::new::<hertz>(freq.0)
+    }
+}
+
+impl From<si::u64::Frequency> for Frequency {
+    fn from(freq: si::u64::Frequency) -> Self {
+        Self(freq.get::<hertz>())
+    }
+}
+
+impl From<Frequency> for si::f64::Frequency {
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    fn from(freq: Frequency) -> Self {
+        Self::new::<hertz>(freq.0 as f64)
+    }
+}
+
+impl TryFrom<si::f64::Frequency> for Frequency {
+    type Error = Error;
+
+    /// Converts to the nearest whole number of hertz.
+    ///
+    /// Fails for negative, NaN and out of range frequencies.
+    fn try_from(freq: si::f64::Frequency) -> Result<Self, Self::Error> {
+        let hz = freq.get::<hertz>();
+        FrequencyF64::from_hz(hz)
+            .try_to_frequency(Rounding::Nearest)
+            .ok_or_else(|| Error::invalid_value(format_args!("{hz} Hz")))
+    }
+}
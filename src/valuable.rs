@@ -0,0 +1,27 @@
+use valuable::{Fields, NamedField, NamedValues, StructDef, Structable, Valuable, Value, Visit};
+
+use crate::Frequency;
+
+static FIELDS: &[NamedField<'static>] = &[NamedField::new("hz"), NamedField::new("display")];
+
+impl Valuable for Frequency {
+    /// Records the frequency as a structure with an integer `hz` field, for numeric filtering,
+    /// and a `display` field such as `"2.40 GHz"`, for people.
+    fn as_value(&self) -> Value<'_> {
+        Value::Structable(self)
+    }
+
+    fn visit(&self, visit: &mut dyn Visit) {
+        let display = self.to_string();
+        visit.visit_named_fields(&NamedValues::new(
+            FIELDS,
+            &[Value::U64(self.0), Value::String(&display)],
+        ));
+    }
+}
+
+impl Structable for Frequency {
+    fn definition(&self) -> StructDef<'_> {
+        StructDef::new_static("Frequency", Fields::Named(FIELDS))
+    }
+}
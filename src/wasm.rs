@@ -0,0 +1,187 @@
+use std::fmt;
+
+use wasm_bindgen::prelude::*;
+
+use crate::{Frequency, FrequencyF64, Rounding};
+
+/// A [`Frequency`] exported to JavaScript as the `Frequency` class
+///
+/// It parses and formats exactly like the Rust type, so web front ends of SDR and audio tools
+/// agree with their backends. Hertz are exchanged as `BigInt` where precision matters and as
+/// `number` in the unit accessors meant for display.
+///
+/// ```js
+/// import { Frequency } from "parse-frequency";
+///
+/// const freq = new Frequency("2.4 GHz");
+/// freq.asHz(); // 2400000000n
+/// freq.add(Frequency.fromHz(1n)).toExactString(); // "2.400000001 GHz"
+/// ```
+#[wasm_bindgen(js_name = Frequency)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct WasmFrequency(Frequency);
+
+#[wasm_bindgen(js_class = Frequency)]
+impl WasmFrequency {
+    /// Creates a frequency from a string such as `"2.4 GHz"`, a `number` of hertz rounded to
+    /// the nearest hertz, or a `BigInt` number of hertz.
+    ///
+    /// # Errors
+    ///
+    /// Throws for invalid strings, negative or out of range numbers, and other types.
+    #[wasm_bindgen(constructor)]
+    pub fn new(value: &JsValue) -> Result<WasmFrequency, JsError> {
+        if let Some(s) = value.as_string() {
+            return Self::parse(&s);
+        }
+
+        if let Some(hz) = value.as_f64() {
+            return Self::from_hz_number(hz);
+        }
+
+        if value.is_bigint() {
+            return u64::try_from(value.clone())
+                .map(Self::from_hz)
+                .map_err(|_| JsError::new("frequency out of range"));
+        }
+
+        Err(JsError::new(
+            "expected a frequency string, a number or a BigInt",
+        ))
+    }
+
+    /// Parses a frequency string such as `"2.4 GHz"`.
+    ///
+    /// # Errors
+    ///
+    /// Throws the parse error message for invalid strings.
+    pub fn parse(s: &str) -> Result<WasmFrequency, JsError> {
+        s.parse()
+            .map(Self)
+            .map_err(|e| JsError::new(&e.to_string()))
+    }
+
+    /// Creates a frequency from a `BigInt` number of hertz.
+    #[must_use]
+    #[wasm_bindgen(js_name = fromHz)]
+    pub fn from_hz(hz: u64) -> WasmFrequency {
+        Self(Frequency(hz))
+    }
+
+    /// Returns the frequency as a `BigInt` number of hertz.
+    #[must_use]
+    #[wasm_bindgen(js_name = asHz)]
+    pub fn as_hz(&self) -> u64 {
+        self.0.0
+    }
+
+    /// Returns the frequency in kilohertz, with a fractional part.
+    #[must_use]
+    #[wasm_bindgen(js_name = asKhz)]
+    pub fn as_khz(&self) -> f64 {
+        FrequencyF64::from_frequency(self.0).as_khz()
+    }
+
+    /// Returns the frequency in megahertz, with a fractional part.
+    #[must_use]
+    #[wasm_bindgen(js_name = asMhz)]
+    pub fn as_mhz(&self) -> f64 {
+        FrequencyF64::from_frequency(self.0).as_mhz()
+    }
+
+    /// Returns the frequency in gigahertz, with a fractional part.
+    #[must_use]
+    #[wasm_bindgen(js_name = asGhz)]
+    pub fn as_ghz(&self) -> f64 {
+        FrequencyF64::from_frequency(self.0).as_ghz()
+    }
+
+    /// Formats the frequency like `"2.40 GHz"`.
+    #[must_use]
+    #[wasm_bindgen(js_name = toString)]
+    pub fn to_js_string(&self) -> String {
+        self.0.to_string()
+    }
+
+    /// Formats the frequency exactly, like `"2.400000001 GHz"`.
+    #[must_use]
+    #[wasm_bindgen(js_name = toExactString)]
+    pub fn to_exact_string(&self) -> String {
+        self.0.to_exact_string()
+    }
+
+    /// Adds two frequencies.
+    ///
+    /// # Errors
+    ///
+    /// Throws when the sum overflows.
+    pub fn add(&self, other: &WasmFrequency) -> Result<WasmFrequency, JsError> {
+        self.0
+            .0
+            .checked_add(other.0.0)
+            .map(Self::from_hz)
+            .ok_or_else(|| JsError::new("frequency overflow"))
+    }
+
+    /// Subtracts `other` from this frequency.
+    ///
+    /// # Errors
+    ///
+    /// Throws when `other` is larger.
+    pub fn sub(&self, other: &WasmFrequency) -> Result<WasmFrequency, JsError> {
+        self.0
+            .0
+            .checked_sub(other.0.0)
+            .map(Self::from_hz)
+            .ok_or_else(|| JsError::new("frequency underflow"))
+    }
+
+    /// Multiplies the frequency by `factor`, rounded to the nearest hertz.
+    ///
+    /// # Errors
+    ///
+    /// Throws when the result is negative or out of range.
+    pub fn mul(&self, factor: f64) -> Result<WasmFrequency, JsError> {
+        Self::from_hz_number(FrequencyF64::from_frequency(self.0).as_hz() * factor)
+    }
+
+    /// Divides the frequency by `divisor`, rounded to the nearest hertz.
+    ///
+    /// # Errors
+    ///
+    /// Throws when the result is negative or out of range, including division by zero.
+    pub fn div(&self, divisor: f64) -> Result<WasmFrequency, JsError> {
+        Self::from_hz_number(FrequencyF64::from_frequency(self.0).as_hz() / divisor)
+    }
+
+    /// Returns whether both frequencies are the same number of hertz.
+    #[must_use]
+    pub fn equals(&self, other: &WasmFrequency) -> bool {
+        self == other
+    }
+
+    fn from_hz_number(hz: f64) -> Result<WasmFrequency, JsError> {
+        FrequencyF64::from_hz(hz)
+            .try_to_frequency(Rounding::Nearest)
+            .map(Self)
+            .ok_or_else(|| JsError::new(&format!("invalid frequency: {hz} Hz")))
+    }
+}
+
+impl From<Frequency> for WasmFrequency {
+    fn from(freq: Frequency) -> Self {
+        Self(freq)
+    }
+}
+
+impl From<WasmFrequency> for Frequency {
+    fn from(freq: WasmFrequency) -> Self {
+        freq.0
+    }
+}
+
+impl fmt::Display for WasmFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt(f)
+    }
+}
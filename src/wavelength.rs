@@ -0,0 +1,82 @@
+use crate::Frequency;
+
+/// Speed of light in vacuum, in metres per second
+pub const SPEED_OF_LIGHT: f64 = 299_792_458.0;
+
+/// Speed of sound in dry air at 20 °C, in metres per second
+pub const SPEED_OF_SOUND: f64 = 343.0;
+
+impl Frequency {
+    /// Returns the wavelength in metres of an electromagnetic wave at this frequency in vacuum.
+    ///
+    /// This is equivalent to `self.wavelength_in(SPEED_OF_LIGHT)`.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::Frequency;
+    ///
+    /// let freq = Frequency::from_mhz(300);
+    /// assert!((freq.wavelength() - 0.999_308).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    pub fn wavelength(&self) -> f64 {
+        self.wavelength_in(crate::SPEED_OF_LIGHT)
+    }
+
+    /// Returns the wavelength in metres (`λ = v / f`) for a wave propagating at `speed` metres
+    /// per second.
+    ///
+    /// A frequency of `0 Hz` has an infinite wavelength.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, SPEED_OF_LIGHT, SPEED_OF_SOUND};
+    ///
+    /// // 343 Hz in air has a wavelength of 1 m
+    /// let freq = Frequency::from_hz(343);
+    /// assert!((freq.wavelength_in(SPEED_OF_SOUND) - 1.0).abs() < 1e-9);
+    ///
+    /// // Coax with a velocity factor of 0.66
+    /// let freq = Frequency::from_mhz(100);
+    /// let lambda = freq.wavelength_in(SPEED_OF_LIGHT * 0.66);
+    /// assert!((lambda - 1.978_630).abs() < 1e-6);
+    /// ```
+    #[must_use]
+    // Precision loss is acceptable here
+    #[allow(clippy::cast_precision_loss)]
+    pub fn wavelength_in(&self, speed: f64) -> f64 {
+        if self.0 == 0 {
+            f64::INFINITY
+        } else {
+            speed / self.0 as f64
+        }
+    }
+
+    /// Creates a frequency from a wavelength in metres and a propagation speed in metres per
+    /// second (`f = v / λ`), rounding to the nearest hertz.
+    ///
+    /// Non-positive or non-finite inputs result in [`Frequency::ZERO`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::{Frequency, SPEED_OF_LIGHT};
+    ///
+    /// let freq = Frequency::from_wavelength(2.0, SPEED_OF_LIGHT);
+    /// assert_eq!(freq, Frequency::from_hz(149_896_229));
+    /// ```
+    #[must_use]
+    pub fn from_wavelength(meters: f64, speed: f64) -> Self {
+        let hz = speed / meters;
+        if !hz.is_finite() || hz <= 0.0 {
+            return Self::ZERO;
+        }
+
+        // It is OK to lose sign and precision here
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let hz = hz.round() as u64;
+        Self(hz)
+    }
+}
@@ -0,0 +1,253 @@
+use std::fmt::Display;
+
+use crate::{Frequency, FrequencyRange, MEGAHERTZ};
+
+/// Spacing between adjacent Wi-Fi channel numbers in hertz
+const CHANNEL_SPACING: u64 = 5 * MEGAHERTZ;
+
+/// A Wi-Fi frequency band
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum WifiBand {
+    /// The 2.4 GHz band, channels 1 to 14
+    Ghz2_4,
+
+    /// The 5 GHz band, channels 32 to 177
+    Ghz5,
+
+    /// The 6 GHz band (Wi-Fi 6E and later), channels 1 to 233 and channel 2
+    Ghz6,
+}
+
+impl WifiBand {
+    /// Returns the frequency of channel number `0` in this band, from which all other channels
+    /// are spaced 5 MHz apart.
+    fn base(self) -> Frequency {
+        match self {
+            WifiBand::Ghz2_4 => Frequency::from_mhz(2_407),
+            WifiBand::Ghz5 => Frequency::from_mhz(5_000),
+            WifiBand::Ghz6 => Frequency::from_mhz(5_950),
+        }
+    }
+
+    /// Returns `true` if `number` is a valid 20 MHz channel in this band.
+    fn is_valid(self, number: u16) -> bool {
+        match self {
+            WifiBand::Ghz2_4 => (1..=14).contains(&number),
+            WifiBand::Ghz5 => match number {
+                32..=64 | 100..=144 => number.is_multiple_of(4),
+                149..=177 => number % 4 == 1,
+                _ => false,
+            },
+            WifiBand::Ghz6 => number == 2 || ((1..=233).contains(&number) && number % 4 == 1),
+        }
+    }
+
+    /// Returns an iterator over every 20 MHz channel in this band, in ascending order.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use parse_frequency::WifiBand;
+    ///
+    /// assert_eq!(WifiBand::Ghz2_4.channels().count(), 14);
+    /// assert_eq!(WifiBand::Ghz6.channels().count(), 60);
+    /// ```
+    pub fn channels(self) -> impl Iterator<Item = WifiChannel> {
+        let mut numbers: Vec<u16> = (1..=233).filter(|&n| self.is_valid(n)).collect();
+
+        // Channel 2 of the 6 GHz band sits below channel 1
+        if self == WifiBand::Ghz6 {
+            numbers.sort_by_key(|&n| {
+                WifiChannel {
+                    band: self,
+                    number: n,
+                }
+                .center()
+            });
+        }
+
+        numbers
+            .into_iter()
+            .map(move |number| WifiChannel { band: self, number })
+    }
+}
+
+impl Display for WifiBand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WifiBand::Ghz2_4 => write!(f, "2.4 GHz"),
+            WifiBand::Ghz5 => write!(f, "5 GHz"),
+            WifiBand::Ghz6 => write!(f, "6 GHz"),
+        }
+    }
+}
+
+/// The bandwidth of a (possibly bonded) Wi-Fi channel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum ChannelWidth {
+    /// A single 20 MHz channel
+    #[default]
+    Mhz20,
+
+    /// Two bonded channels
+    Mhz40,
+
+    /// Four bonded channels
+    Mhz80,
+
+    /// Eight bonded channels
+    Mhz160,
+
+    /// Sixteen bonded channels (6 GHz band only)
+    Mhz320,
+}
+
+impl ChannelWidth {
+    /// Returns the bandwidth as a frequency.
+    #[must_use]
+    pub fn as_frequency(self) -> Frequency {
+        Frequency::from_mhz(20 * u64::from(self.channels()))
+    }
+
+    /// Returns the number of 20 MHz channels bonded together.
+    fn channels(self) -> u16 {
+        match self {
+            ChannelWidth::Mhz20 => 1,
+            ChannelWidth::Mhz40 => 2,
+            ChannelWidth::Mhz80 => 4,
+            ChannelWidth::Mhz160 => 8,
+            ChannelWidth::Mhz320 => 16,
+        }
+    }
+}
+
+/// A 20 MHz Wi-Fi channel, identified by its band and channel number
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{ChannelWidth, Frequency, WifiBand, WifiChannel};
+///
+/// let channel = WifiChannel::new(WifiBand::Ghz5, 36).unwrap();
+/// assert_eq!(channel.center(), Frequency::from_mhz(5_180));
+/// assert_eq!(WifiChannel::from_frequency(Frequency::from_mhz(5_180)), Some(channel));
+///
+/// // Channel 36 as the primary channel of an 80 MHz channel (36 to 48)
+/// let range = channel.frequency_range(ChannelWidth::Mhz80).unwrap();
+/// assert_eq!(range.min, Frequency::from_mhz(5_170));
+/// assert_eq!(range.max, Frequency::from_mhz(5_250));
+/// assert_eq!(range.center(), Frequency::from_mhz(5_210));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WifiChannel {
+    band: WifiBand,
+    number: u16,
+}
+
+impl WifiChannel {
+    /// Creates a channel from its band and 20 MHz channel number.
+    ///
+    /// Returns `None` if `number` is not a valid 20 MHz channel in `band`.
+    #[must_use]
+    pub fn new(band: WifiBand, number: u16) -> Option<Self> {
+        band.is_valid(number).then_some(Self { band, number })
+    }
+
+    /// Finds the channel whose center frequency is exactly `freq`.
+    #[must_use]
+    pub fn from_frequency(freq: Frequency) -> Option<Self> {
+        if freq == Frequency::from_mhz(2_484) {
+            return Self::new(WifiBand::Ghz2_4, 14);
+        }
+
+        [WifiBand::Ghz2_4, WifiBand::Ghz5, WifiBand::Ghz6]
+            .into_iter()
+            .find_map(|band| {
+                let offset = freq.0.checked_sub(band.base().0)?;
+                if !offset.is_multiple_of(CHANNEL_SPACING) {
+                    return None;
+                }
+
+                let number = u16::try_from(offset / CHANNEL_SPACING).ok()?;
+                Self::new(band, number).filter(|channel| channel.center() == freq)
+            })
+            .or_else(|| {
+                (freq == Frequency::from_mhz(5_935)).then_some(Self {
+                    band: WifiBand::Ghz6,
+                    number: 2,
+                })
+            })
+    }
+
+    /// Returns the band this channel is in.
+    #[must_use]
+    pub fn band(&self) -> WifiBand {
+        self.band
+    }
+
+    /// Returns the channel number.
+    #[must_use]
+    pub fn number(&self) -> u16 {
+        self.number
+    }
+
+    /// Returns the center frequency of this 20 MHz channel.
+    #[must_use]
+    pub fn center(&self) -> Frequency {
+        match (self.band, self.number) {
+            (WifiBand::Ghz2_4, 14) => Frequency::from_mhz(2_484),
+            (WifiBand::Ghz6, 2) => Frequency::from_mhz(5_935),
+            (band, number) => Frequency(band.base().0 + u64::from(number) * CHANNEL_SPACING),
+        }
+    }
+
+    /// Returns the frequencies occupied when this channel is the primary channel of a channel
+    /// of the given `width`.
+    ///
+    /// Wide channels in the 5 GHz and 6 GHz bands are made of fixed, aligned blocks of adjacent
+    /// 20 MHz channels, so the result only depends on which block this channel falls into.
+    ///
+    /// Returns `None` if the band does not support `width` at this channel. Only 20 MHz
+    /// channels are supported in the 2.4 GHz band, whose 40 MHz channels have no fixed
+    /// alignment.
+    #[must_use]
+    pub fn frequency_range(&self, width: ChannelWidth) -> Option<FrequencyRange> {
+        let half = Frequency::from_mhz(10);
+
+        if width == ChannelWidth::Mhz20 {
+            let center = self.center();
+            return Some(FrequencyRange::new(center - half, center + half));
+        }
+
+        let base = match (self.band, self.number) {
+            (WifiBand::Ghz2_4, _) | (WifiBand::Ghz5, 32) | (WifiBand::Ghz6, 2) => return None,
+            (WifiBand::Ghz5, 149..) => 149,
+            (WifiBand::Ghz5, _) => 36,
+            (WifiBand::Ghz6, _) => 1,
+        };
+
+        let size = width.channels();
+        let first = base + (self.number - base) / 4 / size * size * 4;
+        let last = first + (size - 1) * 4;
+
+        // Every channel in the block has to exist
+        if !(first..=last)
+            .step_by(4)
+            .all(|number| self.band.is_valid(number))
+        {
+            return None;
+        }
+
+        let (first, last) = (
+            Self::new(self.band, first)?.center(),
+            Self::new(self.band, last)?.center(),
+        );
+        Some(FrequencyRange::new(first - half, last + half))
+    }
+}
+
+impl Display for WifiChannel {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "channel {} ({})", self.number, self.band)
+    }
+}
@@ -0,0 +1,73 @@
+use zerocopy::{
+    ByteOrder, FromBytes, Immutable, IntoBytes, KnownLayout, Unaligned, byteorder::U64,
+};
+
+use crate::Frequency;
+
+/// A frequency stored as 8 bytes in a fixed byte order, with an alignment of 1
+///
+/// [`Frequency`] is aligned like a `u64` and stored in native byte order. This wrapper can be
+/// placed anywhere in a packed packet struct, and reads the same on every platform.
+///
+/// # Examples
+///
+/// ```rust
+/// use parse_frequency::{Frequency, UnalignedFrequency};
+/// use zerocopy::{BigEndian, FromBytes, Immutable, KnownLayout, Unaligned};
+///
+/// #[derive(FromBytes, Immutable, KnownLayout, Unaligned)]
+/// #[repr(C)]
+/// struct Header {
+///     channel: u8,
+///     center: UnalignedFrequency<BigEndian>,
+/// }
+///
+/// let bytes = [7, 0, 0, 0, 0, 0x8f, 0x0d, 0x18, 0x00];
+/// let header = Header::ref_from_bytes(&bytes).unwrap();
+/// assert_eq!(header.channel, 7);
+/// assert_eq!(header.center.get(), Frequency::from_mhz(2_400));
+/// ```
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Default,
+    FromBytes,
+    IntoBytes,
+    Immutable,
+    KnownLayout,
+    Unaligned,
+)]
+#[repr(transparent)]
+pub struct UnalignedFrequency<O: ByteOrder>(U64<O>);
+
+impl<O: ByteOrder> UnalignedFrequency<O> {
+    #[must_use]
+    pub fn new(freq: Frequency) -> Self {
+        Self(U64::new(freq.0))
+    }
+
+    #[must_use]
+    pub fn get(&self) -> Frequency {
+        Frequency(self.0.get())
+    }
+
+    pub fn set(&mut self, freq: Frequency) {
+        self.0.set(freq.0);
+    }
+}
+
+impl<O: ByteOrder> From<Frequency> for UnalignedFrequency<O> {
+    fn from(freq: Frequency) -> Self {
+        Self::new(freq)
+    }
+}
+
+impl<O: ByteOrder> From<UnalignedFrequency<O>> for Frequency {
+    fn from(freq: UnalignedFrequency<O>) -> Self {
+        freq.get()
+    }
+}
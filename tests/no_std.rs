@@ -0,0 +1,63 @@
+//! Exercises the `core`-only API against the library as compiled with the features under test,
+//! so `cargo test --test no_std` and `cargo test --no-default-features --test no_std` check that
+//! it behaves the same with and without `std`.
+
+use parse_frequency::{
+    BaudRate, Error, ErrorText, Frequency, FrequencyMilliHz, RationalFrequency, Rounding,
+    VARINT_MAX_LEN,
+};
+
+#[test]
+fn test_error_text_is_kept() {
+    assert_eq!(
+        "12 parsecs".parse::<Frequency>(),
+        Err(Error::UnknownUnit("12 parsecs".into()))
+    );
+    assert_eq!(
+        "x GHz".parse::<Frequency>(),
+        Err(Error::InvalidValue("x ".into()))
+    );
+
+    let err = "-1 Hz".parse::<Frequency>().unwrap_err();
+    assert_eq!(err.to_string(), "Invalid value: -1 ");
+}
+
+#[test]
+fn test_error_text_truncates_long_input() {
+    let input = "1 ".to_owned() + &"ü".repeat(ErrorText::CAPACITY);
+    let Err(Error::UnknownUnit(text)) = input.parse::<Frequency>() else {
+        panic!("expected an unknown unit error");
+    };
+
+    assert!(text.is_truncated());
+    assert!(text.len() <= ErrorText::CAPACITY);
+    assert!(text.ends_with('…'));
+    assert!(input.starts_with(text.trim_end_matches('…')));
+
+    let exact = "a".repeat(ErrorText::CAPACITY);
+    let text = ErrorText::from(exact.as_str());
+    assert!(!text.is_truncated());
+    assert_eq!(text, exact.as_str());
+}
+
+#[test]
+fn test_core_helpers() {
+    let mut buf = [0; VARINT_MAX_LEN];
+    let len = Frequency::from_mhz(433).encode_varint(&mut buf).unwrap();
+    assert_eq!(
+        Frequency::decode_varint(&buf[..len]),
+        Some((Frequency::from_mhz(433), len))
+    );
+
+    let grid: FrequencyMilliHz = "50.02 Hz".parse().unwrap();
+    assert_eq!(grid.to_frequency(Rounding::Nearest), Frequency::from_hz(50));
+
+    let ntsc: RationalFrequency = "30000/1001 Hz".parse().unwrap();
+    assert_eq!(ntsc.to_frequency(Rounding::Down), Frequency::from_hz(29));
+
+    assert_eq!("9600 baud".parse(), Ok(BaudRate::B9600));
+    assert_eq!(
+        "9600.5 baud".parse::<BaudRate>(),
+        Err(Error::InvalidValue("9600.5 baud".into()))
+    );
+}